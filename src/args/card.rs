@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `vauchi card` arguments.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum CardCommands {
+    /// Show your contact card
+    Show {
+        /// Only show these fields (comma-separated labels, case-insensitive)
+        #[arg(long, value_delimiter = ',')]
+        fields: Vec<String>,
+
+        /// Partially redact field values (for screen-sharing)
+        #[arg(long)]
+        mask: bool,
+
+        /// Show how long ago each field was last changed
+        #[arg(long)]
+        age: bool,
+
+        /// Flag fields unchanged for longer than this (e.g. 180d, 1y; default 1y)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            requires = "age",
+            value_parser = crate::commands::card::parse_duration
+        )]
+        stale_after: Option<u64>,
+
+        /// Print a QR code for each field that has an action (mailto:, tel:, ...)
+        #[arg(long, conflicts_with = "mask")]
+        qr_each: bool,
+    },
+
+    /// Show a scannable QR code for one field (e.g. a mailto: link)
+    Qr {
+        /// Field label
+        label: String,
+
+        /// Also save the QR code as an SVG image
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        /// Overwrite the output file if it already exists
+        #[arg(long, requires = "output")]
+        force: bool,
+    },
+
+    /// Add a field to your card
+    ///
+    /// For social fields, omit label and value to interactively select a
+    /// network from the registry and enter a username. With
+    /// --prompt-missing, any omitted argument is asked for.
+    Add {
+        /// Field type (email, phone, website, address, social, other, or auto to detect it)
+        #[arg(value_name = "TYPE", required_unless_present = "prompt_missing")]
+        field_type: Option<String>,
+
+        /// Field label (e.g., "work", "personal", "mobile"; optional for social)
+        label: Option<String>,
+
+        /// Field value (optional for social — prompts interactively)
+        value: Option<String>,
+
+        /// Read the field value from stdin (keeps newlines and quotes, drops
+        /// the final newline)
+        #[arg(long, conflicts_with = "value")]
+        stdin: bool,
+
+        /// Read the field value from the system clipboard (trimmed)
+        #[arg(long, conflicts_with_all = ["value", "stdin"])]
+        from_clipboard: bool,
+
+        /// Check that email domains and website hosts resolve (needs network)
+        #[arg(long)]
+        validate_dns: bool,
+
+        /// Fail instead of warning when --validate-dns lookup fails
+        #[arg(long, requires = "validate_dns")]
+        strict: bool,
+
+        /// Overwrite the value of an existing field with the same label
+        #[arg(long)]
+        replace: bool,
+
+        /// Initial visibility of the field for existing contacts
+        #[arg(long, value_enum, default_value = "everyone")]
+        visibility: crate::commands::card::InitialVisibility,
+
+        /// Stop sharing the field after this date (YYYY-MM-DD)
+        #[arg(
+            long,
+            value_name = "DATE",
+            value_parser = crate::commands::field_expiry::parse_expiry_date
+        )]
+        expires: Option<u64>,
+
+        /// Short tag shown next to the field's icon (e.g. biz; local only)
+        #[arg(long, value_name = "SHORT", value_parser = crate::commands::field_tags::parse_tag)]
+        tag: Option<String>,
+
+        /// Store phone numbers in E.164 form (e.g. +41791234567)
+        #[arg(long)]
+        normalize: bool,
+
+        /// Region for phone numbers without a country code (e.g. CH; default from the locale)
+        #[arg(long, value_name = "REGION", requires = "normalize")]
+        region: Option<String>,
+
+        /// Ask for the field type, label and value when they are omitted
+        #[arg(long)]
+        prompt_missing: bool,
+
+        /// Answers to the --prompt-missing prompts, in order (for scripts)
+        #[arg(
+            long = "values",
+            value_name = "ANSWER",
+            num_args = 1..,
+            requires = "prompt_missing",
+            hide = true
+        )]
+        prompt_values: Vec<String>,
+    },
+
+    /// Remove a field from your card
+    Remove {
+        /// Field label to remove
+        label: String,
+    },
+
+    /// Remove fields whose --expires date has passed
+    PruneExpired,
+
+    /// Report field values that can be tidied (whitespace, URL scheme, ...)
+    Lint {
+        /// Apply the changes and send them to your contacts
+        #[arg(long)]
+        fix: bool,
+    },
+
+    /// Move a field one position up or down on your card
+    Reorder {
+        /// Field label to move
+        label: String,
+
+        /// Move the field one position up
+        #[arg(long, conflicts_with = "down", required_unless_present = "down")]
+        up: bool,
+
+        /// Move the field one position down
+        #[arg(long)]
+        down: bool,
+    },
+
+    /// Edit a field value
+    Edit {
+        /// Field label to edit
+        label: String,
+
+        /// New value
+        value: String,
+    },
+
+    /// Edit your display name
+    EditName {
+        /// New display name
+        name: String,
+    },
+}
@@ -0,0 +1,466 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `vauchi contacts` arguments.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum ContactCommands {
+    /// List all contacts
+    List {
+        /// Start offset for pagination
+        #[arg(long, default_value = "0")]
+        offset: usize,
+
+        /// Maximum number of contacts to show (0 = all)
+        #[arg(long, default_value = "0")]
+        limit: usize,
+
+        /// Show archived contacts
+        #[arg(long)]
+        archived: bool,
+
+        /// Print only the number of contacts that would be listed
+        #[arg(long)]
+        count_only: bool,
+    },
+
+    /// Show contact details
+    Show {
+        /// Contact ID or name
+        id: String,
+
+        /// Also print cryptographic identity data (developer aid)
+        #[arg(long)]
+        raw_keys: bool,
+
+        /// Also list the contact's activity history (exchange, updates)
+        #[arg(long)]
+        history: bool,
+    },
+
+    /// Search contacts by name
+    Search {
+        /// Search query
+        query: String,
+    },
+
+    /// Remove a contact
+    Remove {
+        /// Contact ID
+        id: String,
+
+        /// Show what would be removed without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Restore a removed contact from the trash
+    Restore {
+        /// Contact ID, ID prefix or name
+        id: String,
+    },
+
+    /// Manage removed contacts awaiting permanent removal
+    #[command(subcommand)]
+    Trash(ContactTrashCommands),
+
+    /// Queue your full current card for one contact (e.g. after a missed update)
+    ResendCard {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Mark contact fingerprint as verified
+    Verify {
+        /// Contact ID
+        #[arg(required_unless_present_any = ["list", "batch"])]
+        id: Option<String>,
+
+        /// List contacts whose fingerprints are not verified yet
+        #[arg(long, conflicts_with = "id")]
+        list: bool,
+
+        /// Verify contacts from a file of `<contact> <fingerprint>` lines,
+        /// marking only those whose fingerprint matches (separate with a tab
+        /// to keep spaces in the fingerprint)
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["id", "list"])]
+        batch: Option<PathBuf>,
+    },
+
+    /// Hide a field from a contact
+    Hide {
+        /// Contact ID or name
+        contact: String,
+        /// Field label to hide
+        field: String,
+    },
+
+    /// Show a field to a contact (make visible)
+    Unhide {
+        /// Contact ID or name
+        contact: String,
+        /// Field label to unhide
+        field: String,
+    },
+
+    /// Show visibility rules for a contact
+    Visibility {
+        /// Contact ID or name
+        contact: String,
+    },
+
+    /// Show which fields a contact shares with you, and which they removed
+    ShowShared {
+        /// Contact ID or name
+        contact: String,
+    },
+
+    /// Open a contact field in external app
+    Open {
+        /// Contact ID or name
+        contact: String,
+        /// Field label to open (optional - interactive if not specified)
+        field: Option<String>,
+        /// Copy the value instead of opening an app (automatic without a display)
+        #[arg(long, requires = "field")]
+        copy: bool,
+    },
+
+    /// Mark a contact as trusted for recovery
+    Trust {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Remove recovery trust from a contact
+    Untrust {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Hide a contact from the default contact list
+    HideContact {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Unhide a previously hidden contact
+    UnhideContact {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// List hidden contacts
+    ListHidden,
+
+    /// Block a contact (stops updates in both directions)
+    Block {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Unblock a previously blocked contact
+    Unblock {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// List all blocked contacts
+    ListBlocked,
+
+    /// Mark a contact as a favorite
+    Favorite {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Remove a contact from favorites
+    Unfavorite {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Call out a contact's card updates during sync
+    Watch {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Stop watching a contact
+    Unwatch {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Move a contact from one label to another in one step
+    MoveLabel {
+        /// Contact ID or name
+        contact: String,
+
+        /// Label to take the contact out of
+        from: String,
+
+        /// Label to put the contact in
+        to: String,
+    },
+
+    /// Export a contact as vCard, or several contacts as a printable QR sheet
+    ///
+    /// With --since and no contact, every contact added in that period is
+    /// written to one file: `vauchi contacts export --since 1d -o recent.vcf`.
+    /// With --group-by-label, each label gets its own file:
+    /// `vauchi contacts export --group-by-label --output-dir rosters`.
+    Export {
+        /// Contact ID or name (optional with --qr-sheet, --output or --group-by-label)
+        #[arg(required_unless_present_any = ["qr_sheet", "output_file", "group_by_label"])]
+        id: Option<String>,
+
+        /// Output file path (e.g., contact.vcf)
+        #[arg(required_unless_present_any = ["qr_sheet", "output_file", "group_by_label"])]
+        output: Option<PathBuf>,
+
+        /// File for the contacts selected by --since
+        #[arg(
+            long = "output",
+            short = 'o',
+            value_name = "PATH",
+            requires = "since",
+            conflicts_with_all = ["id", "output", "qr_sheet", "group_by_label"]
+        )]
+        output_file: Option<PathBuf>,
+
+        /// Write an HTML page with one vCard QR code per contact instead
+        #[arg(long, value_name = "PATH", conflicts_with = "output")]
+        qr_sheet: Option<PathBuf>,
+
+        /// Only include members of this label on the QR sheet
+        #[arg(long, requires = "qr_sheet", conflicts_with = "id")]
+        label: Option<String>,
+
+        /// Write one file per label, plus "ungrouped" for contacts without one
+        #[arg(
+            long,
+            requires = "output_dir",
+            conflicts_with_all = ["id", "output", "qr_sheet", "anonymize"]
+        )]
+        group_by_label: bool,
+
+        /// Directory for the --group-by-label files (created if missing)
+        #[arg(long, value_name = "DIR", requires = "group_by_label")]
+        output_dir: Option<PathBuf>,
+
+        /// Replace field values with placeholders and truncate the UID, for
+        /// sharing the card's structure
+        #[arg(long, conflicts_with_all = ["qr_sheet", "since"])]
+        anonymize: bool,
+
+        /// Only export contacts added within this period (e.g. 1d, 2w)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            conflicts_with = "output",
+            value_parser = crate::commands::card::parse_duration
+        )]
+        since: Option<u64>,
+
+        /// File format (jcard is the JSON form of vCard, RFC 7095)
+        #[arg(long, value_enum, default_value = "vcard", conflicts_with = "qr_sheet")]
+        format: crate::commands::contacts::ContactExportFormat,
+
+        /// vCard version to write (4 for modern address books)
+        #[arg(long, value_enum, default_value = "3", conflicts_with = "qr_sheet")]
+        vcard_version: crate::commands::contacts::VcardVersion,
+
+        /// Overwrite the output file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Export contacts' public keys and fingerprints for out-of-band audit
+    ExportKeys {
+        /// Output file path
+        output: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "json")]
+        format: crate::commands::contacts::KeyExportFormat,
+
+        /// Overwrite the output file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Print the verification and recovery-trust network of your contacts
+    Graph {
+        /// Output format
+        #[arg(long, value_enum, default_value = "dot")]
+        format: crate::commands::contacts::GraphFormat,
+    },
+
+    /// Import contacts from a vCard file (.vcf)
+    ///
+    /// Supports vCard 2.1, 3.0, and 4.0. Multi-contact files are handled.
+    /// Malformed contacts are skipped with a warning.
+    ImportVcf {
+        /// Path to the .vcf file to import
+        file: PathBuf,
+    },
+
+    /// Import the contacts from a full backup without switching identity
+    ///
+    /// Contacts are added as unverified placeholders; names that already
+    /// exist are skipped.
+    ImportBackup {
+        /// Path to a full backup (`vauchi export --full`)
+        file: PathBuf,
+        /// Backup password (prompted interactively if omitted)
+        #[arg(long, env = "VAUCHI_BACKUP_PASSWORD", hide = true)]
+        password: Option<String>,
+    },
+
+    /// Add a personal note to a contact
+    AddNote {
+        /// Contact ID or name
+        id: String,
+
+        /// Note text
+        note: String,
+    },
+
+    /// Show personal note for a contact
+    ShowNote {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Edit personal note for a contact
+    EditNote {
+        /// Contact ID or name
+        id: String,
+
+        /// New note text
+        note: String,
+    },
+
+    /// Delete personal note for a contact
+    DeleteNote {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Merge two contacts into one
+    ///
+    /// The first contact is the primary (keeps its name). Unique fields
+    /// from the second contact are added, then the second contact is removed.
+    Merge {
+        /// Primary contact (ID or name) — keeps this contact's name
+        contact1: String,
+        /// Secondary contact (ID or name) — unique fields added, then removed
+        contact2: String,
+    },
+
+    /// List potential duplicate contacts
+    ///
+    /// Shows contacts with high similarity scores. Previously dismissed
+    /// false positives are excluded.
+    Duplicates,
+
+    /// Find likely duplicate contacts and suggest merges
+    ///
+    /// Contacts sharing a public key are flagged as hard duplicates;
+    /// similar names or overlapping fields are listed as soft candidates.
+    Dedupe,
+
+    /// Dismiss a duplicate pair as a false positive
+    DismissDuplicate {
+        /// First contact (ID or name)
+        contact1: String,
+        /// Second contact (ID or name)
+        contact2: String,
+    },
+
+    /// Undo dismissal of a duplicate pair
+    UndismissDuplicate {
+        /// First contact (ID or name)
+        contact1: String,
+        /// Second contact (ID or name)
+        contact2: String,
+    },
+
+    /// Show or set the contact limit
+    ///
+    /// Without --set, shows current limit and usage.
+    /// With --set N, updates the maximum number of contacts.
+    Limit {
+        /// Set the contact limit to this value
+        #[arg(long)]
+        set: Option<usize>,
+    },
+
+    /// List upcoming contact birthdays, soonest first
+    ///
+    /// Reads `birthday` fields (YYYY-MM-DD, or --MM-DD without a year).
+    Birthdays {
+        /// How far ahead to look (e.g. 7d, 2w; default 30d)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            default_value = "30d",
+            value_parser = crate::commands::card::parse_duration
+        )]
+        within: u64,
+    },
+
+    /// Remove unverified contacts exchanged longer ago than a threshold
+    ///
+    /// Recovery-trusted and favorite contacts are never removed.
+    PruneUnverified {
+        /// Only remove contacts exchanged at least this long ago (default 90d)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            default_value = "90d",
+            value_parser = crate::commands::card::parse_duration
+        )]
+        older_than: u64,
+
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
+    /// Delete an imported contact
+    Delete {
+        /// Contact ID or name
+        id: String,
+        /// Skip undo window, delete immediately
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// Archive an exchanged contact
+    Archive {
+        /// Contact ID or name
+        id: String,
+    },
+
+    /// Unarchive a previously archived contact
+    Unarchive {
+        /// Contact ID or name
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum ContactTrashCommands {
+    /// List removed contacts that can still be restored
+    List,
+
+    /// Permanently remove every contact in the trash
+    Empty,
+}
@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `vauchi device` arguments.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum DeviceCommands {
+    /// List linked devices (active ones unless --revoked or --all)
+    List {
+        /// Show only revoked devices
+        #[arg(long, conflicts_with = "all")]
+        revoked: bool,
+
+        /// Show active and revoked devices
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Show info about the current device
+    Info,
+
+    /// Generate QR code to link a new device
+    Link {
+        #[command(flatten)]
+        transport: LinkTransportArgs,
+    },
+
+    /// Join an existing identity (on new device)
+    Join {
+        /// QR data from existing device
+        #[arg(required_unless_present = "file")]
+        qr_data: Option<String>,
+
+        #[command(flatten)]
+        transport: LinkTransportArgs,
+
+        /// Device name (skips interactive prompt)
+        #[arg(long)]
+        device_name: Option<String>,
+
+        /// Skip confirmation prompts
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
+    /// Complete device linking (on existing device)
+    Complete {
+        /// Request data from new device
+        #[arg(required_unless_present = "file")]
+        request: Option<String>,
+
+        #[command(flatten)]
+        transport: LinkTransportArgs,
+
+        /// Skip confirmation prompt (for scripted/E2E use)
+        #[arg(long, short = 'y')]
+        yes: bool,
+
+        /// Transfer ratchet sessions: the new device replaces this one
+        /// (decommission this device afterwards)
+        #[arg(long)]
+        replace: bool,
+    },
+
+    /// Decommission this device after a replacement handover
+    Decommission {
+        /// Skip confirmation prompt (for scripted/E2E use)
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
+    /// Finish device join (on new device)
+    Finish {
+        /// Response data from existing device
+        #[arg(required_unless_present = "file")]
+        response: Option<String>,
+
+        #[command(flatten)]
+        transport: LinkTransportArgs,
+    },
+
+    /// Revoke a linked device
+    Revoke {
+        /// Device ID prefix
+        device_id: String,
+
+        /// Why the device is revoked (e.g. lost, sold, compromised)
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Skip confirmation prompt (for scripted/E2E use)
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
+    /// Show past device revocations with reasons
+    History,
+
+    /// Device replacement wizard
+    #[command(subcommand)]
+    Replace(DeviceReplaceCommands),
+}
+
+/// How device-link blobs travel between the two devices.
+#[derive(clap::Args)]
+pub(crate) struct LinkTransportArgs {
+    /// Carry link data on screen (qr) or in a file for air-gapped setups
+    #[arg(long, value_enum, default_value = "qr")]
+    pub transport: crate::commands::device::LinkTransport,
+
+    /// Link data file: each step reads the previous step's blob from it
+    /// and writes its own blob back
+    #[arg(long, required_if_eq("transport", "file"))]
+    pub file: Option<PathBuf>,
+}
+
+impl LinkTransportArgs {
+    /// Returns the link data file when the file transport is selected.
+    pub fn file(&self) -> anyhow::Result<Option<&std::path::Path>> {
+        use crate::commands::device::LinkTransport;
+
+        match (self.transport, &self.file) {
+            (LinkTransport::File, Some(path)) => Ok(Some(path)),
+            (LinkTransport::File, None) => anyhow::bail!("--transport file requires --file"),
+            (LinkTransport::Qr, Some(_)) => anyhow::bail!("--file requires --transport file"),
+            (LinkTransport::Qr, None) => Ok(None),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub(crate) enum DeviceReplaceCommands {
+    /// Set up transfer from this (old) device to a new one
+    Setup,
+
+    /// Receive transfer on this (new) device
+    Transfer,
+
+    /// Post-restore guidance after importing a backup
+    PostRestore,
+}
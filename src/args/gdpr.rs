@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `vauchi gdpr` arguments.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum GdprCommands {
+    /// Export all personal data as JSON (optionally encrypted)
+    Export {
+        /// Output file path
+        output: PathBuf,
+        /// Encrypt export (prompts for password interactively)
+        #[arg(long)]
+        encrypt: bool,
+        /// Password for encryption (prefer --encrypt for interactive prompt;
+        /// kept for non-interactive/scripted use via env var VAUCHI_EXPORT_PASSWORD)
+        #[arg(long, env = "VAUCHI_EXPORT_PASSWORD", hide = true)]
+        password: Option<String>,
+        /// Include the linked-device registry
+        #[arg(long)]
+        include_devices: bool,
+        /// Include visibility labels and their members
+        #[arg(long)]
+        include_labels: bool,
+        /// Overwrite the output file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Schedule identity deletion (7-day grace period by default)
+    ScheduleDeletion {
+        /// Grace period before the deletion can be executed, e.g. 14d (minimum 1d)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            value_parser = crate::commands::gdpr::parse_grace
+        )]
+        grace: Option<u64>,
+    },
+
+    /// Cancel a scheduled identity deletion
+    CancelDeletion,
+
+    /// Execute a scheduled identity deletion (after grace period)
+    ExecuteDeletion,
+
+    /// Emergency immediate deletion — no grace period
+    PanicShred,
+
+    /// Show current deletion status
+    DeletionStatus {
+        /// Write the deletion state to this file instead of the terminal
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// File format for --output
+        #[arg(long, value_enum, default_value = "json", requires = "output")]
+        format: crate::commands::gdpr::ReportFormat,
+
+        /// Overwrite the --output file if it already exists
+        #[arg(long, requires = "output")]
+        force: bool,
+    },
+
+    /// Show consent records
+    ConsentStatus {
+        /// Only show records newer than this (e.g. 30d, 12h)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            value_parser = crate::commands::card::parse_duration
+        )]
+        since: Option<u64>,
+
+        /// Only show records older than this (e.g. 7d)
+        #[arg(
+            long,
+            value_name = "DURATION",
+            value_parser = crate::commands::card::parse_duration
+        )]
+        until: Option<u64>,
+
+        /// Only show one consent type's history
+        #[arg(long = "type", value_name = "CONSENT_TYPE")]
+        consent_type: Option<String>,
+
+        /// Write the consent records to this file instead of the terminal
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// File format for --output
+        #[arg(long, value_enum, default_value = "json", requires = "output")]
+        format: crate::commands::gdpr::ReportFormat,
+
+        /// Overwrite the --output file if it already exists
+        #[arg(long, requires = "output")]
+        force: bool,
+    },
+
+    /// Grant consent for a type (data_processing, contact_sharing, recovery_vouching)
+    GrantConsent {
+        /// Consent type
+        consent_type: String,
+    },
+
+    /// Revoke consent for a type
+    RevokeConsent {
+        /// Consent type
+        consent_type: String,
+    },
+}
@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `vauchi labels` arguments.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum LabelCommands {
+    /// List all labels
+    List {
+        /// Print only the number of labels
+        #[arg(long)]
+        count_only: bool,
+    },
+
+    /// Create a new label
+    Create {
+        /// Label name
+        name: String,
+    },
+
+    /// Show label details
+    Show {
+        /// Label name or ID prefix
+        label: String,
+    },
+
+    /// Rename a label
+    Rename {
+        /// Label name or ID prefix
+        label: String,
+        /// New name
+        new_name: String,
+    },
+
+    /// Delete a label
+    Delete {
+        /// Label name or ID prefix
+        label: String,
+
+        /// Show what would be deleted without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Add contacts to a label
+    AddContact {
+        /// Label name or ID prefix
+        label: String,
+        /// Contact names or ID prefixes
+        #[arg(required = true)]
+        contacts: Vec<String>,
+        /// Stop at the first contact that fails instead of continuing
+        #[arg(long)]
+        fail_fast: bool,
+    },
+
+    /// Remove contacts from a label
+    RemoveContact {
+        /// Label name or ID prefix
+        label: String,
+        /// Contact names or ID prefixes
+        #[arg(required = true)]
+        contacts: Vec<String>,
+        /// Stop at the first contact that fails instead of continuing
+        #[arg(long)]
+        fail_fast: bool,
+    },
+
+    /// Show a field to contacts in a label
+    ShowField {
+        /// Label name or ID prefix
+        label: String,
+        /// Field label
+        field: String,
+    },
+
+    /// Hide a field from contacts in a label
+    HideField {
+        /// Label name or ID prefix
+        label: String,
+        /// Field label
+        field: String,
+    },
+
+    /// Report contacts whose per-contact rules hide a field their label shows
+    Check,
+
+    /// Manage rules that add contacts to labels based on their card fields
+    #[command(subcommand)]
+    Rule(LabelRuleCommands),
+
+    /// Add contacts matching the label rules to their labels
+    ///
+    /// Rules added with `--auto` also run on contacts added by `exchange`
+    /// or `sync`.
+    AutoApply,
+
+    /// Export label names and field visibility (not members) to JSON
+    Export {
+        /// Output file path
+        output: PathBuf,
+
+        /// Overwrite the output file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Import label definitions from a `labels export` file
+    Import {
+        /// Input file path
+        input: PathBuf,
+        /// Replace the field visibility of labels that already exist
+        #[arg(long)]
+        overwrite: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum LabelRuleCommands {
+    /// Add a rule: contacts with a matching field join the label
+    Add {
+        /// Label name or ID prefix
+        label: String,
+
+        /// Match contacts with a field value containing this text (case-insensitive)
+        ///
+        /// Text starting with `@` matches the end of email addresses.
+        #[arg(long, value_name = "TEXT")]
+        field_contains: String,
+
+        /// Only match fields of this type (email, phone, website, ...)
+        #[arg(long, value_name = "TYPE")]
+        field_type: Option<String>,
+
+        /// Also apply the rule to contacts added by exchange or sync
+        #[arg(long)]
+        auto: bool,
+    },
+
+    /// List the label rules
+    List,
+
+    /// Remove a label rule
+    Remove {
+        /// Rule number, as shown by `labels rule list`
+        number: usize,
+    },
+}
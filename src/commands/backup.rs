@@ -7,32 +7,97 @@
 //! Export and import backups (identity-only or full).
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use dialoguer::{Input, Password};
+use vauchi_core::contact_card::vcard::export_vcard;
 use vauchi_core::{Identity, IdentityBackup, Vauchi, VauchiConfig};
 
-use crate::commands::common::open_vauchi;
+use crate::commands::common::{
+    atomic_write_checked, atomic_write_restricted, check_overwrite, open_vauchi,
+};
 use crate::config::CliConfig;
 use crate::display;
 
+/// File suffix of the identity part of a split backup.
+const IDENTITY_SUFFIX: &str = "identity";
+/// File suffix of the contacts part of a split backup.
+const CONTACTS_SUFFIX: &str = "contacts";
+
+/// Returns the given backup password, or prompts for it.
+///
+/// `confirm` asks twice, for passwords that protect a new backup.
+fn backup_password(password: Option<&str>, confirm: bool) -> Result<String> {
+    if let Some(p) = password {
+        return Ok(p.to_string());
+    }
+    let prompt = Password::new().with_prompt("Enter backup password");
+    let prompt = if confirm {
+        prompt.with_confirmation("Confirm password", "Passwords don't match")
+    } else {
+        prompt
+    };
+    Ok(prompt.interact()?)
+}
+
+/// Appends `.{suffix}` to `base` (e.g. `backup` -> `backup.identity`).
+fn split_path(base: &Path, suffix: &str) -> PathBuf {
+    let mut path = base.as_os_str().to_owned();
+    path.push(".");
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Re-reads an identity backup and restores it with `password`, checking
+/// that it yields `expected`.
+fn verify_identity_backup(path: &Path, password: &str, expected: &Identity) -> Result<()> {
+    let backup = IdentityBackup::new(fs::read(path)?);
+    let restored =
+        Identity::import_backup(&backup, password, crate::clock::shared().unix_seconds())?;
+    if restored.signing_public_key() != expected.signing_public_key() {
+        bail!("it restores a different identity");
+    }
+    Ok(())
+}
+
 /// Exports an identity backup.
-pub fn export(config: &CliConfig, output: &Path) -> Result<()> {
+///
+/// With `verify`, the backup is restored from a temporary file before it
+/// replaces `output`; if that fails, `output` is left untouched and an
+/// error is returned.
+pub fn export(
+    config: &CliConfig,
+    output: &Path,
+    password: Option<&str>,
+    verify: bool,
+) -> Result<()> {
     let wb = open_vauchi(config)?;
 
     let identity = wb
         .identity()
         .ok_or_else(|| anyhow::anyhow!("No identity found"))?;
 
-    let password: String = Password::new()
-        .with_prompt("Enter backup password")
-        .with_confirmation("Confirm password", "Passwords don't match")
-        .interact()?;
+    let password = backup_password(password, true)?;
 
     let backup = identity.export_backup(&password)?;
 
-    fs::write(output, backup.as_bytes())?;
+    atomic_write_checked(output, backup.as_bytes(), |written| {
+        if verify {
+            // An unrestorable backup must not replace a good one.
+            verify_identity_backup(written, &password, identity).map_err(|e| {
+                anyhow::anyhow!(
+                    "Backup verification failed ({}); {:?} was not written",
+                    e,
+                    output
+                )
+            })?;
+        }
+        Ok(())
+    })?;
+    if verify {
+        display::success("Backup verified: it restores with this password");
+    }
 
     display::success(&format!("Backup saved to {:?}", output));
     display::warning("Keep this file and password safe. You'll need both to restore.");
@@ -41,7 +106,7 @@ pub fn export(config: &CliConfig, output: &Path) -> Result<()> {
 }
 
 /// Imports an identity from backup.
-pub fn import(config: &CliConfig, input: &Path) -> Result<()> {
+pub fn import(config: &CliConfig, input: &Path, password: Option<&str>) -> Result<()> {
     if config.is_initialized() {
         display::warning("Vauchi is already initialized.");
 
@@ -58,9 +123,7 @@ pub fn import(config: &CliConfig, input: &Path) -> Result<()> {
     let backup_data = fs::read(input)?;
     let backup = IdentityBackup::new(backup_data);
 
-    let password: String = Password::new()
-        .with_prompt("Enter backup password")
-        .interact()?;
+    let password = backup_password(password, false)?;
 
     let identity =
         Identity::import_backup(&backup, &password, crate::clock::shared().unix_seconds())?;
@@ -85,16 +148,13 @@ pub fn import(config: &CliConfig, input: &Path) -> Result<()> {
 }
 
 /// Exports a full backup (identity + contacts + own card + labels).
-pub fn export_full(config: &CliConfig, output: &Path) -> Result<()> {
+pub fn export_full(config: &CliConfig, output: &Path, password: Option<&str>) -> Result<()> {
     let wb = open_vauchi(config)?;
 
-    let password: String = Password::new()
-        .with_prompt("Enter backup password")
-        .with_confirmation("Confirm password", "Passwords don't match")
-        .interact()?;
+    let password = backup_password(password, true)?;
 
     let backup_hex = wb.export_full_backup(&password)?;
-    fs::write(output, backup_hex.as_bytes())?;
+    atomic_write_restricted(output, backup_hex.as_bytes())?;
 
     display::success(&format!("Full backup saved to {:?}", output));
     display::warning(
@@ -105,7 +165,7 @@ pub fn export_full(config: &CliConfig, output: &Path) -> Result<()> {
 }
 
 /// Imports a full backup (identity + contacts + own card + labels).
-pub fn import_full(config: &CliConfig, input: &Path) -> Result<()> {
+pub fn import_full(config: &CliConfig, input: &Path, password: Option<&str>) -> Result<()> {
     if config.is_initialized() {
         display::warning("Vauchi is already initialized.");
 
@@ -121,9 +181,7 @@ pub fn import_full(config: &CliConfig, input: &Path) -> Result<()> {
 
     let backup_hex = fs::read_to_string(input)?;
 
-    let password: String = Password::new()
-        .with_prompt("Enter backup password")
-        .interact()?;
+    let password = backup_password(password, false)?;
 
     fs::create_dir_all(&config.data_dir)?;
 
@@ -144,3 +202,105 @@ pub fn import_full(config: &CliConfig, input: &Path) -> Result<()> {
 
     Ok(())
 }
+
+/// Exports the identity and the contacts to separate files.
+///
+/// `<base>.identity` is a regular (encrypted) identity backup.
+/// `<base>.contacts` is an address-book export: plaintext vCards without
+/// the contacts' keys, readable by any address book. Importing it into
+/// another identity adds unverified placeholders until you exchange again.
+pub fn export_split(
+    config: &CliConfig,
+    base: &Path,
+    password: Option<&str>,
+    force: bool,
+    verify: bool,
+) -> Result<()> {
+    let identity_path = split_path(base, IDENTITY_SUFFIX);
+    let contacts_path = split_path(base, CONTACTS_SUFFIX);
+    check_overwrite(&identity_path, force)?;
+    check_overwrite(&contacts_path, force)?;
+
+    export(config, &identity_path, password, verify)?;
+
+    let wb = open_vauchi(config)?;
+    let contacts = wb.list_contacts()?;
+    let vcf: String = contacts.iter().map(|c| export_vcard(c.card())).collect();
+    atomic_write_restricted(&contacts_path, vcf)?;
+
+    display::success(&format!(
+        "{} contact(s) saved to {:?}",
+        contacts.len(),
+        contacts_path
+    ));
+    display::warning(
+        "The contacts file is a plaintext address book without contact keys. \
+         Store it somewhere safe.",
+    );
+
+    Ok(())
+}
+
+/// Imports a split backup written by [`export_split`].
+///
+/// If `input` names one part (`.identity` or `.contacts`), only that part is
+/// imported; otherwise `input` is the base and every part present is
+/// imported, identity first. Contacts are added to the current identity.
+pub fn import_split(config: &CliConfig, input: &Path, password: Option<&str>) -> Result<()> {
+    let part = input.extension().and_then(|e| e.to_str());
+    let (identity_path, contacts_path) = match part {
+        Some(IDENTITY_SUFFIX) => (Some(input.to_path_buf()), None),
+        Some(CONTACTS_SUFFIX) => (None, Some(input.to_path_buf())),
+        _ => (
+            Some(split_path(input, IDENTITY_SUFFIX)).filter(|p| p.exists()),
+            Some(split_path(input, CONTACTS_SUFFIX)).filter(|p| p.exists()),
+        ),
+    };
+
+    if identity_path.is_none() && contacts_path.is_none() {
+        bail!(
+            "No split backup found at {:?} (expected .{} and/or .{} files)",
+            input,
+            IDENTITY_SUFFIX,
+            CONTACTS_SUFFIX
+        );
+    }
+
+    if let Some(path) = identity_path {
+        import(config, &path, password)?;
+    }
+    if let Some(path) = contacts_path {
+        crate::commands::contacts::import_vcf(config, &path)?;
+    }
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const PASSWORD: &str = "correct-horse-battery";
+
+    // @internal
+    #[test]
+    fn test_verify_identity_backup_detects_corrupted_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("backup");
+        let identity = Identity::create("Test User", crate::clock::shared().unix_seconds());
+        let backup = identity.export_backup(PASSWORD).unwrap();
+
+        fs::write(&path, backup.as_bytes()).unwrap();
+        verify_identity_backup(&path, PASSWORD, &identity).unwrap();
+        assert!(verify_identity_backup(&path, "wrong-password", &identity).is_err());
+
+        let other = Identity::create("Other User", crate::clock::shared().unix_seconds());
+        assert!(verify_identity_backup(&path, PASSWORD, &other).is_err());
+
+        let bytes = backup.as_bytes();
+        fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+        assert!(verify_identity_backup(&path, PASSWORD, &identity).is_err());
+    }
+}
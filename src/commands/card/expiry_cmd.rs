@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Result;
+use vauchi_core::ContactField;
+
+use super::propagate;
+use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
+use crate::commands::field_expiry;
+use crate::config::CliConfig;
+use crate::display;
+
+/// Flags fields whose `--expires` date has passed.
+pub(super) fn show_expired(config: &CliConfig, fields: &[&ContactField], now: u64) -> Result<()> {
+    let store = field_expiry::ExpiryStore::load(config)?;
+    let mut any = false;
+    for field in fields {
+        let Some(expiry) = store.get(field.id()) else {
+            continue;
+        };
+        if expiry.expires_at <= now {
+            println!(
+                "  {:12} ⚠ expired {} (no longer shared)",
+                field.label(),
+                field_expiry::format_expiry(expiry.expires_at)
+            );
+            any = true;
+        }
+    }
+    if any {
+        display::info("Remove expired fields with 'vauchi card prune-expired'.");
+        println!();
+    }
+    Ok(())
+}
+
+/// Removes every field whose `--expires` date has passed.
+pub fn prune_expired(config: &CliConfig) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+    let now = wb.clock().unix_seconds();
+    let mut store = field_expiry::ExpiryStore::load(config)?;
+
+    let expired: Vec<&ContactField> = old_card
+        .fields()
+        .iter()
+        .filter(|f| store.is_expired(f.id(), now))
+        .collect();
+    if expired.is_empty() {
+        display::info("No expired fields");
+        return Ok(());
+    }
+
+    for field in &expired {
+        if wb.remove_own_field(field.label())? {
+            display::success(&format!("Removed expired field '{}'", field.label()));
+        }
+        store.remove(field.id());
+    }
+    store.save(config)?;
+
+    let new_card = wb.own_card()?.unwrap();
+    propagate(config, &wb, &old_card, &new_card)?;
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Field values read from the clipboard or stdin for `card add`.
+
+use anyhow::Result;
+
+/// Clipboard readers tried in order by `card add --from-clipboard`.
+///
+/// Shelling out to the platform tools avoids a clipboard dependency;
+/// the first one that runs and prints text wins.
+const CLIPBOARD_READERS: &[(&str, &[&str])] = &[
+    ("pbpaste", &[]),
+    ("wl-paste", &["--no-newline"]),
+    ("xclip", &["-selection", "clipboard", "-o"]),
+    ("xsel", &["--clipboard", "--output"]),
+    ("powershell", &["-NoProfile", "-Command", "Get-Clipboard"]),
+];
+
+/// Reads the system clipboard for `card add --from-clipboard`.
+pub fn read_clipboard() -> Result<String> {
+    read_clipboard_with(CLIPBOARD_READERS).ok_or_else(|| {
+        let tried: Vec<&str> = CLIPBOARD_READERS
+            .iter()
+            .map(|(program, _)| *program)
+            .collect();
+        anyhow::anyhow!(
+            "Could not read the clipboard (tried {}). \
+             Pass the value as an argument or use --stdin instead.",
+            tried.join(", ")
+        )
+    })
+}
+
+/// Reads a field value from stdin for `card add --stdin`.
+///
+/// The value is kept byte-for-byte, except for the one trailing newline
+/// that `echo` or a heredoc appends.
+pub fn read_stdin_value() -> Result<String> {
+    let mut buf = String::new();
+    std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf)?;
+    Ok(strip_trailing_newline(buf))
+}
+
+fn strip_trailing_newline(mut value: String) -> String {
+    if value.ends_with('\n') {
+        value.pop();
+        if value.ends_with('\r') {
+            value.pop();
+        }
+    }
+    value
+}
+
+/// Returns the trimmed output of the first reader that succeeds with
+/// non-empty text.
+fn read_clipboard_with(readers: &[(&str, &[&str])]) -> Option<String> {
+    readers.iter().find_map(|(program, args)| {
+        let output = std::process::Command::new(program)
+            .args(*args)
+            .stdin(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let text = String::from_utf8(output.stdout).ok()?;
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    })
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[cfg(unix)]
+    #[test]
+    fn test_read_clipboard_skips_missing_readers_and_trims() {
+        let readers: &[(&str, &[&str])] = &[
+            ("vauchi-no-such-clipboard-tool", &[]),
+            ("sh", &["-c", "printf '  alice@example.com\\n'"]),
+        ];
+        assert_eq!(
+            read_clipboard_with(readers).as_deref(),
+            Some("alice@example.com")
+        );
+        assert_eq!(
+            read_clipboard_with(&[("vauchi-no-such-clipboard-tool", &[])]),
+            None
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_strip_trailing_newline_removes_only_one() {
+        assert_eq!(strip_trailing_newline("a\nb\n".into()), "a\nb");
+        assert_eq!(strip_trailing_newline("a\r\n".into()), "a");
+        assert_eq!(strip_trailing_newline("a\n\n".into()), "a\n");
+        assert_eq!(strip_trailing_newline("a".into()), "a");
+    }
+}
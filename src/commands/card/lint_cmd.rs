@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Result;
+use vauchi_core::{ContactField, FieldType};
+
+use super::propagate;
+use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
+use crate::config::CliConfig;
+use crate::display;
+
+/// Returns the tidied form of a field value.
+///
+/// Trims surrounding whitespace everywhere; adds `https://` to bare
+/// website hosts, collapses runs of spaces in phone numbers and lowercases
+/// email domains.
+fn normalize_value(field_type: FieldType, value: &str) -> String {
+    let value = value.trim();
+    match field_type {
+        FieldType::Website if !value.is_empty() && !value.contains("://") => {
+            format!("https://{}", value)
+        }
+        FieldType::Phone => value.split_whitespace().collect::<Vec<_>>().join(" "),
+        FieldType::Email => match value.rsplit_once('@') {
+            Some((local, domain)) => format!("{}@{}", local, domain.to_lowercase()),
+            None => value.to_string(),
+        },
+        _ => value.to_string(),
+    }
+}
+
+/// Reports card fields whose values can be tidied; `fix` applies the
+/// changes and propagates them to contacts.
+pub fn lint(config: &CliConfig, fix: bool) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+
+    let changes: Vec<(&ContactField, String)> = old_card
+        .fields()
+        .iter()
+        .filter_map(|f| {
+            let normalized = normalize_value(f.field_type(), f.value());
+            (normalized != f.value()).then_some((f, normalized))
+        })
+        .collect();
+
+    if changes.is_empty() {
+        display::success("All fields are tidy");
+        return Ok(());
+    }
+
+    println!();
+    for (field, normalized) in &changes {
+        println!(
+            "  {}: {:?} -> {:?}",
+            field.label(),
+            field.value(),
+            normalized
+        );
+    }
+    println!();
+
+    if !fix {
+        display::info(&format!(
+            "{} field(s) can be tidied. Run 'vauchi card lint --fix' to apply.",
+            changes.len()
+        ));
+        return Ok(());
+    }
+
+    let now = wb.clock().unix_seconds();
+    let mut new_card = old_card.clone();
+    for (field, normalized) in &changes {
+        new_card.update_field_value(field.id(), normalized, now)?;
+    }
+    wb.update_own_card(&new_card)?;
+    display::success(&format!("Tidied {} field(s)", changes.len()));
+
+    propagate(config, &wb, &old_card, &new_card)?;
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_normalize_adds_scheme_to_bare_website() {
+        assert_eq!(
+            normalize_value(FieldType::Website, " example.com/me "),
+            "https://example.com/me"
+        );
+        assert_eq!(
+            normalize_value(FieldType::Website, "http://example.com"),
+            "http://example.com"
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_normalize_trims_email_and_lowercases_domain() {
+        assert_eq!(
+            normalize_value(FieldType::Email, "  Alice@Example.COM \t"),
+            "Alice@example.com"
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_normalize_collapses_phone_spacing() {
+        assert_eq!(
+            normalize_value(FieldType::Phone, " +41  79   123 45 67 "),
+            "+41 79 123 45 67"
+        );
+        assert_eq!(normalize_value(FieldType::Custom, "as is"), "as is");
+    }
+}
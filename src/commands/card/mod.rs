@@ -0,0 +1,880 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Card Command
+//!
+//! Manage your contact card.
+
+mod expiry_cmd;
+mod input;
+mod lint_cmd;
+mod reorder_cmd;
+
+pub use expiry_cmd::prune_expired;
+pub use input::{read_clipboard, read_stdin_value};
+pub use lint_cmd::lint;
+pub use reorder_cmd::reorder;
+
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use clap::ValueEnum;
+use vauchi_core::{ContactCard, ContactField, FieldType, Vauchi};
+
+use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
+use crate::commands::field_expiry;
+use crate::commands::field_tags;
+use crate::config::CliConfig;
+use crate::display;
+
+/// Initial visibility of a field added with `card add`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum InitialVisibility {
+    /// Shared with every contact (the default)
+    #[default]
+    Everyone,
+    /// Hidden from every existing contact until unhidden per contact
+    Nobody,
+}
+
+/// Parses a field type string using core's alias table.
+fn parse_field_type(s: &str) -> Result<(FieldType, Option<String>)> {
+    FieldType::from_alias(s).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unknown field type: {}. Use: email, phone, website, address, social, custom, or auto",
+            s
+        )
+    })
+}
+
+/// Field type that asks `card add` to infer the type from the value.
+const AUTO_FIELD_TYPE: &str = "auto";
+
+fn is_auto(field_type: &str) -> bool {
+    field_type.eq_ignore_ascii_case(AUTO_FIELD_TYPE)
+}
+
+/// Guesses a field type from its value, falling back to `Custom`.
+fn infer_field_type(value: &str) -> FieldType {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+
+    if lower.starts_with("https://") || lower.starts_with("http://") {
+        return FieldType::Website;
+    }
+    if let Some((local, domain)) = value.split_once('@')
+        && !local.is_empty()
+        && domain.contains('.')
+        && !domain.contains('@')
+        && !value.contains(char::is_whitespace)
+    {
+        return FieldType::Email;
+    }
+
+    let digits = value.chars().filter(char::is_ascii_digit).count();
+    let phone_chars = value
+        .trim_start_matches('+')
+        .chars()
+        .all(|c| c.is_ascii_digit() || " -().".contains(c));
+    if phone_chars && digits >= 5 {
+        return FieldType::Phone;
+    }
+
+    FieldType::Custom
+}
+
+/// Shows the current contact card.
+///
+/// When `only` is non-empty, just the fields whose labels match
+/// (case-insensitive) are shown; unknown labels produce a warning.
+/// `mask` partially redacts values for screen-sharing. With `stale_after`
+/// (seconds), each field's age is listed and older fields are flagged.
+/// `qr_each` follows the card with a QR code per actionable field.
+pub fn show(
+    config: &CliConfig,
+    only: &[String],
+    mask: bool,
+    stale_after: Option<u64>,
+    qr_each: bool,
+) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    match wb.own_card()? {
+        Some(card) => {
+            let (fields, missing) = select_fields(card.fields(), only);
+            for label in &missing {
+                let message = format!("Field '{}' not found", label);
+                // `--raw` stdout must stay pure JSON.
+                if config.raw {
+                    eprintln!("{}", message);
+                } else {
+                    display::warning(&message);
+                }
+            }
+
+            if config.raw {
+                crate::raw::print_json(&crate::raw::CardJson {
+                    display_name: card.display_name().to_string(),
+                    fields: fields
+                        .iter()
+                        .map(|f| {
+                            let mut json = crate::raw::FieldJson::from(*f);
+                            if mask {
+                                json.value = display::mask_value(f.field_type(), f.value());
+                            }
+                            json
+                        })
+                        .collect(),
+                })?;
+            } else {
+                let tags = field_tags::FieldTags::load(config)?;
+                display::display_card_fields(card.display_name(), &fields, mask, |f| {
+                    tags.get(f.id()).map(str::to_string)
+                });
+                expiry_cmd::show_expired(config, &fields, wb.clock().unix_seconds())?;
+                if let Some(threshold) = stale_after {
+                    show_field_ages(&fields, wb.clock().unix_seconds(), threshold);
+                }
+                if qr_each {
+                    for field in &fields {
+                        if let Some(uri) = field.to_uri() {
+                            println!("  {}: {}", field.label(), uri);
+                            crate::commands::qr::run(&uri, None)?;
+                        }
+                    }
+                }
+            }
+        }
+        None => {
+            display::warning("No contact card found. Create one with 'vauchi init'.");
+        }
+    }
+
+    Ok(())
+}
+
+/// Default `--stale-after` threshold: one year.
+pub(crate) const DEFAULT_STALE_AFTER_SECS: u64 = 365 * 24 * 60 * 60;
+
+/// Lists when each field was last changed, flagging ones older than `stale_after`.
+fn show_field_ages(fields: &[&ContactField], now: u64, stale_after: u64) {
+    println!("  Last changed:");
+    for field in fields {
+        let age = now.saturating_sub(field.updated_at());
+        if age > stale_after {
+            println!("  {:12} {} ⚠ may be stale", field.label(), format_age(age));
+        } else {
+            println!("  {:12} {}", field.label(), format_age(age));
+        }
+    }
+    println!();
+}
+
+/// Formats an age in seconds as a coarse "N units ago" string.
+fn format_age(secs: u64) -> String {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+
+    match secs {
+        s if s < MINUTE => "just now".to_string(),
+        s if s < HOUR => format!("{} min ago", s / MINUTE),
+        s if s < DAY => format!("{} h ago", s / HOUR),
+        s if s < 365 * DAY => format!("{} days ago", s / DAY),
+        s => format!("{} years ago", s / (365 * DAY)),
+    }
+}
+
+/// Prints a QR code for one field's actionable URI (`mailto:`, `tel:`,
+/// `https:`, ...), so it can be scanned without a full exchange.
+pub fn qr(config: &CliConfig, label: &str, output: Option<&Path>) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+
+    let field = card
+        .fields()
+        .iter()
+        .find(|f| f.label().eq_ignore_ascii_case(label))
+        .ok_or_else(|| anyhow::anyhow!("Field '{}' not found", label))?;
+    let Some(uri) = field.to_uri() else {
+        bail!("Field '{}' has no scannable action", field.label());
+    };
+
+    println!("  {}: {}", field.label(), uri);
+    crate::commands::qr::run(&uri, output)
+}
+
+/// Parses a duration such as `90d`, `12h`, `6w` or `1y` into seconds.
+///
+/// A bare number is taken as days.
+pub(crate) fn parse_duration(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let (number, unit) = match s.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => s.split_at(i),
+        None => (s, "d"),
+    };
+    let n: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{}': expected e.g. 90d", s))?;
+    let unit_secs = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        "y" => 365 * 24 * 60 * 60,
+        _ => {
+            return Err(format!(
+                "invalid duration unit '{}': use s, m, h, d, w or y",
+                unit
+            ));
+        }
+    };
+    n.checked_mul(unit_secs)
+        .ok_or_else(|| format!("duration '{}' is too large", s))
+}
+
+/// Picks the fields named in `only` (case-insensitive), in card order.
+///
+/// An empty `only` selects every field. Returns the selection and the
+/// requested labels that matched no field.
+fn select_fields<'a>(
+    fields: &'a [ContactField],
+    only: &[String],
+) -> (Vec<&'a ContactField>, Vec<String>) {
+    if only.is_empty() {
+        return (fields.iter().collect(), Vec::new());
+    }
+
+    let selected = fields
+        .iter()
+        .filter(|f| only.iter().any(|l| f.label().eq_ignore_ascii_case(l)))
+        .collect();
+    let missing = only
+        .iter()
+        .filter(|l| !fields.iter().any(|f| f.label().eq_ignore_ascii_case(l)))
+        .cloned()
+        .collect();
+
+    (selected, missing)
+}
+
+/// Options for `card add` beyond the field itself.
+#[derive(Debug, Default)]
+pub struct AddFieldOptions<'a> {
+    /// Look up email domains and website hosts first; a failed lookup
+    /// warns, or aborts when `strict` is set.
+    pub validate_dns: bool,
+    pub strict: bool,
+    /// Overwrite the value of an existing field with the same label.
+    pub replace: bool,
+    pub visibility: InitialVisibility,
+    /// Unix seconds from which the field is treated as expired.
+    pub expires: Option<u64>,
+    /// Store phone numbers in E.164 form, reading numbers without a
+    /// country code as national numbers of `region`.
+    pub normalize: bool,
+    pub region: Option<&'a str>,
+    /// Shown next to the field's icon in `card show`.
+    pub tag: Option<&'a str>,
+}
+
+/// Adds a field to the contact card.
+pub fn add(
+    config: &CliConfig,
+    field_type: &str,
+    label: &str,
+    value: &str,
+    options: &AddFieldOptions,
+) -> Result<()> {
+    let AddFieldOptions {
+        validate_dns,
+        strict,
+        replace,
+        visibility,
+        expires,
+        normalize,
+        region,
+        tag,
+    } = *options;
+
+    let wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    let (ft, type_name) = if is_auto(field_type) {
+        let ft = infer_field_type(value);
+        let name = format!("{:?}", ft).to_lowercase();
+        display::info(&format!("Detected field type: {}", name));
+        (ft, name)
+    } else {
+        (parse_field_type(field_type)?.0, field_type.to_string())
+    };
+
+    let normalized;
+    let value = if normalize && ft == FieldType::Phone {
+        normalized = crate::commands::phone::to_e164(value, region)?;
+        if normalized != value {
+            display::info(&format!("Normalized phone number to {}", normalized));
+        }
+        normalized.as_str()
+    } else {
+        value
+    };
+
+    if validate_dns
+        && let Some(host) = crate::commands::dns_check::host_for(ft, value)
+        && !crate::commands::dns_check::resolves(&host)
+    {
+        if strict {
+            bail!("DNS lookup failed for '{}'", host);
+        }
+        display::warning(&format!("DNS lookup failed for '{}'", host));
+    }
+
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+
+    // `edit` and `remove` match by label, so a second field with the same
+    // label would be unreachable through them.
+    let field_id = if let Some(existing) = old_card.fields().iter().find(|f| f.label() == label) {
+        if !replace {
+            bail!(
+                "Field '{}' already exists. Use 'vauchi card edit {} <value>' or pass --replace to overwrite it",
+                label,
+                label
+            );
+        }
+        let mut new_card = old_card.clone();
+        new_card.update_field_value(existing.id(), value, wb.clock().unix_seconds())?;
+        wb.update_own_card(&new_card)?;
+        display::success(&format!("Replaced value of field '{}'", label));
+        existing.id().to_string()
+    } else {
+        let field = ContactField::new(ft, label, value, wb.clock().unix_seconds());
+        let field_id = field.id().to_string();
+        wb.add_own_field(field)?;
+        display::success(&format!("Added {} field '{}'", type_name, label));
+        field_id
+    };
+
+    if let Some(tag) = tag {
+        let mut tags = field_tags::FieldTags::load(config)?;
+        tags.set(&field_id, tag);
+        tags.save(config)?;
+    }
+
+    if let Some(expires_at) = expires {
+        let mut store = field_expiry::ExpiryStore::load(config)?;
+        store.set(&field_id, expires_at);
+        store.save(config)?;
+        if expires_at <= wb.clock().unix_seconds() {
+            display::warning(&format!(
+                "Field '{}' is already expired and will not be shared",
+                label
+            ));
+        } else {
+            display::info(&format!(
+                "Field '{}' expires after {}",
+                label,
+                field_expiry::format_expiry(expires_at)
+            ));
+        }
+    }
+
+    // Hide before propagating so the value never reaches a contact.
+    if visibility == InitialVisibility::Nobody {
+        let contacts = wb.list_contacts()?;
+        for contact in &contacts {
+            wb.set_contact_visibility_override_and_repropagate(contact.id(), &field_id, false)?;
+        }
+        display::info(&format!(
+            "Hidden from {} contact(s). Use 'vauchi contacts unhide <contact> {}' to share it.",
+            contacts.len(),
+            label
+        ));
+    }
+
+    let new_card = wb.own_card()?.unwrap();
+    propagate(config, &wb, &old_card, &new_card)?;
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
+
+/// Queues a card change for contacts, warning when the offline queue is
+/// close to full.
+fn propagate(
+    config: &CliConfig,
+    wb: &Vauchi,
+    old_card: &ContactCard,
+    new_card: &ContactCard,
+) -> Result<()> {
+    let queued = wb.propagate_card_update(old_card, new_card)?;
+    if queued > 0 {
+        display::info(&format!("Update queued to {} contact(s)", queued));
+    }
+    if let Some(warning) = crate::commands::delivery::offline_queue_warning(config, wb) {
+        display::warning(&warning);
+    }
+    Ok(())
+}
+
+/// Field types offered by the `card add --prompt-missing` select menu.
+const PROMPT_FIELD_TYPES: &[&str] = &[
+    "email", "phone", "website", "address", "birthday", "social", "custom",
+];
+
+/// Fills in the `card add` arguments that were omitted.
+///
+/// Each missing argument is taken from `answers` in order; once those run
+/// out, it is prompted for, with a select menu for the field type.
+pub fn prompt_missing(
+    field_type: Option<String>,
+    label: Option<String>,
+    value: Option<String>,
+    answers: Vec<String>,
+) -> Result<(String, String, String)> {
+    use dialoguer::{Input, Select};
+
+    let mut answers = answers.into_iter();
+
+    let field_type = match field_type.or_else(|| answers.next()) {
+        Some(t) => t,
+        None => {
+            let selection = Select::new()
+                .with_prompt("Field type")
+                .items(PROMPT_FIELD_TYPES)
+                .default(0)
+                .interact()?;
+            PROMPT_FIELD_TYPES[selection].to_string()
+        }
+    };
+    if !is_auto(&field_type) {
+        parse_field_type(&field_type)?;
+    }
+
+    let label = match label.or_else(|| answers.next()) {
+        Some(l) => l,
+        None => Input::new().with_prompt("Label").interact_text()?,
+    };
+    let value = match value.or_else(|| answers.next()) {
+        Some(v) => v,
+        None => Input::new().with_prompt("Value").interact_text()?,
+    };
+
+    if answers.next().is_some() {
+        bail!("Too many --values answers: only omitted arguments are prompted for");
+    }
+
+    Ok((field_type, label, value))
+}
+
+/// Interactively prompts for a social network and username, then adds the field.
+///
+/// Displays a numbered list of available social networks from the registry,
+/// lets the user select by number, then prompts for the username.
+pub fn add_social_interactive(config: &CliConfig) -> Result<()> {
+    use dialoguer::{Input, Select};
+    use vauchi_core::SocialNetworkRegistry;
+
+    let wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found. Run 'vauchi init' first."))?;
+
+    let registry = SocialNetworkRegistry::with_defaults();
+    let networks = registry.all();
+
+    if networks.is_empty() {
+        bail!("No social networks available in the registry");
+    }
+
+    let items: Vec<String> = networks
+        .iter()
+        .map(|n| format!("{} ({})", n.display_name(), n.id()))
+        .collect();
+
+    println!();
+
+    let selection = Select::new()
+        .with_prompt("Select a social network")
+        .items(&items)
+        .default(0)
+        .interact()?;
+
+    let selected = networks[selection];
+    let network_id = selected.id().to_string();
+    let network_name = selected.display_name().to_string();
+
+    let username: String = Input::new()
+        .with_prompt(format!("{} username", network_name))
+        .interact_text()?;
+
+    let username = username.trim().to_string();
+    if username.is_empty() {
+        bail!("Username cannot be empty");
+    }
+
+    if let Some(url) = registry.profile_url(&network_id, &username) {
+        display::info(&format!("Profile URL: {}", url));
+    }
+
+    // Create and add the field (label = network id, value = username)
+    let field = ContactField::new(
+        FieldType::Social,
+        &network_id,
+        &username,
+        wb.clock().unix_seconds(),
+    );
+    wb.add_own_field(field)?;
+
+    display::success(&format!(
+        "Added social field '{}' with username '{}'",
+        network_id, username
+    ));
+
+    let new_card = wb.own_card()?.unwrap();
+    propagate(config, &wb, &old_card, &new_card)?;
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
+
+/// Removes a field from the contact card.
+pub fn remove(config: &CliConfig, label: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+
+    if wb.remove_own_field(label)? {
+        display::success(&format!("Removed field '{}'", label));
+        if let Some(field) = old_card.fields().iter().find(|f| f.label() == label) {
+            let mut store = field_expiry::ExpiryStore::load(config)?;
+            store.remove(field.id());
+            store.save(config)?;
+            let mut tags = field_tags::FieldTags::load(config)?;
+            if tags.remove(field.id()) {
+                tags.save(config)?;
+            }
+        }
+
+        let new_card = wb.own_card()?.unwrap();
+        propagate(config, &wb, &old_card, &new_card)?;
+    } else {
+        display::warning(&format!("Field '{}' not found", label));
+    }
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
+
+/// Edits a field value.
+pub fn edit(config: &CliConfig, label: &str, value: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    // Get current card (also serves as old card for delta)
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+
+    let field = old_card.fields().iter().find(|f| f.label() == label);
+
+    match field {
+        Some(f) => {
+            let mut new_card = old_card.clone();
+            new_card.update_field_value(f.id(), value, wb.clock().unix_seconds())?;
+            wb.update_own_card(&new_card)?;
+
+            display::success(&format!("Updated field '{}'", label));
+
+            propagate(config, &wb, &old_card, &new_card)?;
+        }
+        None => {
+            display::warning(&format!("Field '{}' not found", label));
+        }
+    }
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
+
+/// Edits the display name.
+pub fn edit_name(config: &CliConfig, name: &str) -> Result<()> {
+    let mut wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+
+    wb.update_display_name(name)?;
+
+    display::success(&format!("Display name updated to '{}'", name));
+
+    let new_card = wb.own_card()?.unwrap();
+    propagate(config, &wb, &old_card, &new_card)?;
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn test_config(data_dir: std::path::PathBuf) -> CliConfig {
+        CliConfig {
+            data_dir,
+            relay_url: "http://127.0.0.1:9".to_string(),
+            ohttp_relay_url: None,
+            raw: false,
+            offline_queue_limit: None,
+        }
+    }
+
+    /// Trace: contact_card_management.feature - "Edit an existing field value"
+    // @scenario: contact_card_management:Edit an existing field preserves its identity
+    #[test]
+    fn test_edit_preserves_existing_field_identity() {
+        let data_dir = tempfile::TempDir::new().unwrap();
+        let config = test_config(data_dir.path().to_path_buf());
+        crate::commands::init::run("Alice", false, &config, "en").unwrap();
+
+        add(
+            &config,
+            "phone",
+            "mobile",
+            "+12025550100",
+            &AddFieldOptions::default(),
+        )
+        .unwrap();
+        let field_id = open_vauchi(&config)
+            .unwrap()
+            .own_card()
+            .unwrap()
+            .unwrap()
+            .fields()
+            .iter()
+            .find(|field| field.label() == "mobile")
+            .unwrap()
+            .id()
+            .to_string();
+
+        edit(&config, "mobile", "+12025550101").unwrap();
+
+        let card = open_vauchi(&config).unwrap().own_card().unwrap().unwrap();
+        let field = card
+            .fields()
+            .iter()
+            .find(|field| field.label() == "mobile")
+            .unwrap();
+        assert_eq!(field.id(), field_id);
+        assert_eq!(field.value(), "+12025550101");
+    }
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("90s"), Ok(90));
+        assert_eq!(parse_duration("2h"), Ok(2 * 60 * 60));
+        assert_eq!(parse_duration("30"), Ok(30 * 24 * 60 * 60));
+        assert_eq!(parse_duration("1y"), Ok(DEFAULT_STALE_AFTER_SECS));
+        assert!(parse_duration("5 fortnights").is_err());
+        assert!(parse_duration("d").is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_format_age_buckets() {
+        assert_eq!(format_age(5), "just now");
+        assert_eq!(format_age(3 * 60 * 60), "3 h ago");
+        assert_eq!(format_age(400 * 24 * 60 * 60), "1 years ago");
+    }
+
+    // @internal
+    #[test]
+    fn test_select_fields_filters_case_insensitively() {
+        let fields = vec![
+            ContactField::new(FieldType::Phone, "Mobile", "+12025550100", 0),
+            ContactField::new(FieldType::Email, "Work", "alice@work.com", 0),
+        ];
+
+        let (selected, missing) = select_fields(&fields, &["mobile".to_string()]);
+
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].label(), "Mobile");
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_select_fields_reports_missing_labels() {
+        let fields = vec![ContactField::new(
+            FieldType::Phone,
+            "Mobile",
+            "+12025550100",
+            0,
+        )];
+
+        let (selected, missing) = select_fields(&fields, &["Home".to_string()]);
+
+        assert!(selected.is_empty());
+        assert_eq!(missing, vec!["Home".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_field_type_email_aliases() {
+        assert_eq!(parse_field_type("email").unwrap().0, FieldType::Email);
+        assert_eq!(parse_field_type("mail").unwrap().0, FieldType::Email);
+        assert_eq!(parse_field_type("EMAIL").unwrap().0, FieldType::Email);
+    }
+
+    #[test]
+    fn test_parse_field_type_phone_aliases() {
+        assert_eq!(parse_field_type("phone").unwrap().0, FieldType::Phone);
+        assert_eq!(parse_field_type("tel").unwrap().0, FieldType::Phone);
+        assert_eq!(parse_field_type("telephone").unwrap().0, FieldType::Phone);
+    }
+
+    // @internal
+    #[test]
+    fn test_infer_field_type_email() {
+        assert_eq!(infer_field_type("alice@example.com"), FieldType::Email);
+        assert_eq!(infer_field_type(" a.b+tag@mail.co.uk "), FieldType::Email);
+    }
+
+    // @internal
+    #[test]
+    fn test_infer_field_type_phone() {
+        assert_eq!(infer_field_type("+41 79 123 45 67"), FieldType::Phone);
+        assert_eq!(infer_field_type("(555) 123-4567"), FieldType::Phone);
+    }
+
+    // @internal
+    #[test]
+    fn test_infer_field_type_website() {
+        assert_eq!(infer_field_type("https://example.com"), FieldType::Website);
+        assert_eq!(
+            infer_field_type("HTTP://example.com/a@b"),
+            FieldType::Website
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_infer_field_type_ambiguous_is_custom() {
+        assert_eq!(infer_field_type("call me @ 5pm"), FieldType::Custom);
+        assert_eq!(infer_field_type("@alice"), FieldType::Custom);
+        assert_eq!(infer_field_type("1234"), FieldType::Custom);
+        assert_eq!(infer_field_type("example.com"), FieldType::Custom);
+        assert!(is_auto("AUTO"));
+        assert!(parse_field_type("auto").is_err());
+    }
+
+    #[test]
+    fn test_parse_field_type_unknown_returns_error() {
+        assert!(parse_field_type("unknown").is_err());
+        assert!(parse_field_type("").is_err());
+    }
+
+    // ====================================================================
+    // Property-Based Tests (CC-04, CC-14)
+    // ====================================================================
+
+    /// All known aliases mapped to their expected FieldType.
+    fn all_valid_aliases() -> Vec<(&'static str, FieldType)> {
+        vec![
+            ("email", FieldType::Email),
+            ("mail", FieldType::Email),
+            ("phone", FieldType::Phone),
+            ("tel", FieldType::Phone),
+            ("telephone", FieldType::Phone),
+            ("website", FieldType::Website),
+            ("web", FieldType::Website),
+            ("url", FieldType::Website),
+            ("address", FieldType::Address),
+            ("addr", FieldType::Address),
+            ("home", FieldType::Address),
+            ("birthday", FieldType::Birthday),
+            ("bday", FieldType::Birthday),
+            ("dob", FieldType::Birthday),
+            ("social", FieldType::Social),
+            ("twitter", FieldType::Social),
+            ("x", FieldType::Social),
+            ("instagram", FieldType::Social),
+            ("ig", FieldType::Social),
+            ("linkedin", FieldType::Social),
+            ("github", FieldType::Social),
+            ("gh", FieldType::Social),
+            ("custom", FieldType::Custom),
+            ("other", FieldType::Custom),
+            ("note", FieldType::Custom),
+        ]
+    }
+
+    proptest! {
+        /// Case-insensitive: any mixed-case variant of a valid alias is accepted.
+        #[test]
+        fn prop_parse_field_type_case_insensitive(
+            idx in 0usize..25,
+            bits in proptest::collection::vec(any::<bool>(), 20),
+        ) {
+            let aliases = all_valid_aliases();
+            let (alias, expected_type) = &aliases[idx];
+            let mixed: String = alias.chars().enumerate().map(|(i, c)| {
+                if *bits.get(i).unwrap_or(&false) {
+                    c.to_uppercase().next().unwrap()
+                } else {
+                    c
+                }
+            }).collect();
+
+            let result = parse_field_type(&mixed);
+            prop_assert!(result.is_ok(), "Should accept '{}' (from alias '{}')", mixed, alias);
+            prop_assert_eq!(
+                std::mem::discriminant(&result.unwrap().0),
+                std::mem::discriminant(expected_type),
+            );
+        }
+
+        /// Arbitrary non-alias strings always produce an error.
+        #[test]
+        fn prop_parse_field_type_rejects_unknown(s in "\\PC{0,100}") {
+            let known: Vec<&str> = vec![
+                "email", "mail", "phone", "tel", "telephone",
+                "website", "web", "url", "address", "addr", "home",
+                "birthday", "bday", "dob",
+                "social", "twitter", "x", "instagram", "ig",
+                "linkedin", "github", "gh",
+                "custom", "other", "note",
+            ];
+            if !known.contains(&s.to_lowercase().as_str()) {
+                prop_assert!(parse_field_type(&s).is_err());
+            }
+        }
+
+        /// Adversarial inputs (CC-14): never panics.
+        #[test]
+        fn prop_parse_field_type_never_panics(
+            s in prop::string::string_regex("(.|\n){0,200}").unwrap()
+        ) {
+            // allow(zero_assertions): No-panic fuzz test
+            let _ = parse_field_type(&s);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Result;
+use vauchi_core::{ContactCard, ContactField};
+
+use super::propagate;
+use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
+use crate::config::CliConfig;
+use crate::display;
+
+/// Returns the index one slot up or down from `index`, or `None` at the ends.
+fn nudged_index(index: usize, len: usize, up: bool) -> Option<usize> {
+    if up {
+        index.checked_sub(1)
+    } else {
+        Some(index + 1).filter(|&i| i < len)
+    }
+}
+
+/// Moves a field one position up or down on the card, clamping at the ends.
+pub fn reorder(config: &CliConfig, label: &str, up: bool) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+
+    let old_card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+    let fields: Vec<ContactField> = old_card.fields().to_vec();
+
+    let index = fields
+        .iter()
+        .position(|f| f.label() == label)
+        .ok_or_else(|| anyhow::anyhow!("Field '{}' not found", label))?;
+
+    let Some(target) = nudged_index(index, fields.len(), up) else {
+        display::info(&format!(
+            "Field '{}' is already at the {}",
+            label,
+            if up { "top" } else { "bottom" }
+        ));
+        return Ok(());
+    };
+
+    // Rebuild the card in the new order and save it in one update. The
+    // fields keep their IDs, so per-contact visibility is unaffected.
+    let mut reordered = fields;
+    reordered.swap(index, target);
+    let mut new_card = ContactCard::new(old_card.display_name());
+    for field in reordered {
+        new_card.add_field(field)?;
+    }
+    wb.update_own_card(&new_card)?;
+
+    display::success(&format!(
+        "Moved field '{}' to position {}",
+        label,
+        target + 1
+    ));
+
+    propagate(config, &wb, &old_card, &new_card)?;
+
+    drain_activity_log(&wb, event_rx);
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_nudge_last_field_up_swaps_with_previous() {
+        assert_eq!(nudged_index(2, 3, true), Some(1));
+    }
+
+    // @internal
+    #[test]
+    fn test_nudge_clamps_at_the_ends() {
+        assert_eq!(nudged_index(0, 3, true), None);
+        assert_eq!(nudged_index(2, 3, false), None);
+        assert_eq!(nudged_index(0, 3, false), Some(1));
+    }
+}
@@ -4,8 +4,14 @@
 
 //! Shared helpers for CLI commands.
 
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::io::{IsTerminal, Write};
+use std::path::Path;
 use std::sync::mpsc;
+use tempfile::NamedTempFile;
 use vauchi_core::{AuthMode, Vauchi, VauchiConfig, VauchiEvent};
 
 use crate::config::CliConfig;
@@ -167,6 +173,196 @@ pub(crate) fn drain_activity_log(wb: &Vauchi, rx: mpsc::Receiver<VauchiEvent>) {
     }
 }
 
+/// Fails unless a prompt can be answered, instead of letting it hang or
+/// read piped input.
+///
+/// `flags` names the options that answer the prompt non-interactively, if
+/// the command has any.
+pub(crate) fn require_interactive(flags: Option<&str>) -> Result<()> {
+    // dialoguer reads from stdin and draws on stderr.
+    if std::io::stdin().is_terminal() && std::io::stderr().is_terminal() {
+        return Ok(());
+    }
+    match flags {
+        Some(flags) => bail!(
+            "This command requires an interactive terminal or the {} option(s)",
+            flags
+        ),
+        None => bail!("This command requires an interactive terminal"),
+    }
+}
+
+/// Loads the JSON state file `name` from the data directory.
+///
+/// A missing file is treated as empty. A corrupt or unreadable one is an
+/// error named after `what`, so a later save cannot silently replace it;
+/// `vauchi doctor` reports such files.
+pub(crate) fn load_json_state<T: DeserializeOwned + Default>(
+    config: &CliConfig,
+    name: &str,
+    what: &str,
+) -> Result<T> {
+    let path = config.data_dir.join(name);
+    match fs::read_to_string(&path) {
+        Ok(json) => serde_json::from_str(&json)
+            .with_context(|| format!("Corrupt {} at {}", what, path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+/// Writes `value` to the JSON state file `name` in the data directory,
+/// readable by the owner only.
+pub(crate) fn save_json_state<T: Serialize>(
+    config: &CliConfig,
+    name: &str,
+    value: &T,
+) -> Result<()> {
+    atomic_write_restricted(
+        &config.data_dir.join(name),
+        serde_json::to_string_pretty(value)?,
+    )
+}
+
+/// Quotes a CSV cell when it contains a delimiter, quote or line break.
+///
+/// A cell starting with `=`, `+`, `-`, `@`, tab or carriage return gets a
+/// leading `'`, so a spreadsheet shows it as text instead of running it as
+/// a formula.
+pub(crate) fn csv_escape(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Refuses to replace an existing output file unless `force` is set.
+pub(crate) fn check_overwrite(path: &Path, force: bool) -> Result<()> {
+    if !force && path.exists() {
+        bail!(
+            "{} already exists. Use --force to overwrite it.",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Writes `data` to `path` through a temporary file next to it and a
+/// rename, so an interrupted write never leaves a partial `path`.
+///
+/// A replaced file keeps its permissions; a new one is created 0644.
+pub(crate) fn atomic_write(path: &Path, data: impl AsRef<[u8]>) -> Result<()> {
+    write_via_temp(path, data.as_ref(), false, |_| Ok(()), persist)
+}
+
+/// Like [`atomic_write`], but readable by the owner only, for files that
+/// hold keys or personal data.
+pub(crate) fn atomic_write_restricted(path: &Path, data: impl AsRef<[u8]>) -> Result<()> {
+    write_via_temp(path, data.as_ref(), true, |_| Ok(()), persist)
+}
+
+/// Like [`atomic_write_restricted`], but runs `check` on the written
+/// temporary file first. If it fails, `path` is left as it was.
+pub(crate) fn atomic_write_checked(
+    path: &Path,
+    data: impl AsRef<[u8]>,
+    check: impl FnOnce(&Path) -> Result<()>,
+) -> Result<()> {
+    write_via_temp(path, data.as_ref(), true, check, persist)
+}
+
+fn persist(tmp: NamedTempFile, path: &Path) -> std::io::Result<()> {
+    tmp.persist(path).map(drop).map_err(|e| e.error)
+}
+
+fn write_via_temp(
+    path: &Path,
+    data: &[u8],
+    restricted: bool,
+    check: impl FnOnce(&Path) -> Result<()>,
+    persist: impl FnOnce(NamedTempFile, &Path) -> std::io::Result<()>,
+) -> Result<()> {
+    if path.file_name().is_none() {
+        bail!("{} is not a file path", path.display());
+    }
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    // The temporary file is removed when dropped, i.e. on any error below.
+    let failed_write = || format!("Failed to write {}", path.display());
+    let tmp = write_synced(dir, path, data, restricted).with_context(failed_write)?;
+    check(tmp.path())?;
+    persist(tmp, path).with_context(failed_write)
+}
+
+/// Writes `data` to a new, randomly named file in `dir` and flushes it.
+///
+/// The file is owner-only if `restricted`; otherwise it takes the
+/// permissions of the `replacing` file, or 0644 if there is none.
+fn write_synced(
+    dir: &Path,
+    replacing: &Path,
+    data: &[u8],
+    restricted: bool,
+) -> std::io::Result<NamedTempFile> {
+    // NamedTempFile creates the file owner-only.
+    let mut tmp = NamedTempFile::new_in(dir)?;
+    #[cfg(unix)]
+    if !restricted {
+        use std::os::unix::fs::PermissionsExt;
+        let permissions = match fs::metadata(replacing) {
+            Ok(metadata) => metadata.permissions(),
+            Err(_) => fs::Permissions::from_mode(0o644),
+        };
+        tmp.as_file().set_permissions(permissions)?;
+    }
+    tmp.write_all(data)?;
+    tmp.as_file().sync_all()?;
+    Ok(tmp)
+}
+
+/// Runs `op` on each item of a bulk command.
+///
+/// By default every item is attempted, failures are reported as they
+/// happen, and a summary error is returned if any failed. With `fail_fast`,
+/// the first failure stops the batch and is returned with how far it got.
+pub(crate) fn run_batch<T: AsRef<str>>(
+    items: &[T],
+    fail_fast: bool,
+    mut op: impl FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    let mut failed = 0;
+    for (i, item) in items.iter().enumerate() {
+        let item = item.as_ref();
+        if let Err(e) = op(item) {
+            if fail_fast {
+                bail!(
+                    "Stopped at '{}' after processing {} of {} item(s): {}",
+                    item,
+                    i + 1,
+                    items.len(),
+                    e
+                );
+            }
+            crate::display::error(&format!("{}: {}", item, e));
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} of {} item(s) failed", failed, items.len());
+    }
+    Ok(())
+}
+
 // INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
 #[cfg(test)]
 mod tests {
@@ -246,6 +442,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
         let identity = Identity::create("Test User", crate::clock::shared().unix_seconds());
         config.save_local_identity(&identity).unwrap();
@@ -269,6 +466,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let result = open_vauchi(&config);
@@ -291,6 +489,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let identity = Identity::create("Test User", crate::clock::shared().unix_seconds());
@@ -312,6 +511,7 @@ mod tests {
             relay_url: "ws://localhost:9999".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let identity = Identity::create("Storage Path Test", crate::clock::shared().unix_seconds());
@@ -414,4 +614,134 @@ mod tests {
             "unauthenticated"
         );
     }
+
+    // @internal
+    #[test]
+    fn atomic_write_replaces_file_and_leaves_no_temp() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("export.json");
+        fs::write(&path, "old").unwrap();
+
+        atomic_write(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    // @internal
+    #[cfg(unix)]
+    #[test]
+    fn atomic_write_keeps_permissions_of_replaced_file() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempdir().unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+
+        let private = dir.path().join("private.json");
+        atomic_write_restricted(&private, "old").unwrap();
+        assert_eq!(mode(&private), 0o600);
+        atomic_write(&private, "new").unwrap();
+        assert_eq!(mode(&private), 0o600);
+
+        let public = dir.path().join("public.json");
+        atomic_write(&public, "new").unwrap();
+        assert_eq!(mode(&public), 0o644);
+    }
+
+    // @internal
+    #[test]
+    fn failed_rename_leaves_no_partial_destination() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("backup.vauchi");
+
+        let err = write_via_temp(
+            &path,
+            b"secret",
+            true,
+            |_| Ok(()),
+            |_, _| Err(std::io::Error::other("disk full")),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("Failed to write"));
+        assert!(!path.exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    // @internal
+    #[test]
+    fn csv_escape_neutralises_formulas() {
+        assert_eq!(csv_escape("Bob Jones"), "Bob Jones");
+        assert_eq!(
+            csv_escape("=HYPERLINK(\"x\")"),
+            "\"'=HYPERLINK(\"\"x\"\")\""
+        );
+        assert_eq!(csv_escape("+1 555"), "'+1 555");
+        assert_eq!(csv_escape("@bob"), "'@bob");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+    }
+
+    // @internal
+    #[test]
+    fn failed_check_leaves_destination_untouched() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("backup.vauchi");
+        fs::write(&path, "old").unwrap();
+
+        let err = atomic_write_checked(&path, "new", |tmp| {
+            assert_eq!(fs::read_to_string(tmp).unwrap(), "new");
+            bail!("does not restore")
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("does not restore"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    // @internal
+    #[test]
+    fn check_overwrite_requires_force_for_existing_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("labels.json");
+        assert!(check_overwrite(&path, false).is_ok());
+
+        fs::write(&path, "{}").unwrap();
+        let err = check_overwrite(&path, false).unwrap_err();
+        assert!(err.to_string().contains("--force"));
+        assert!(check_overwrite(&path, true).is_ok());
+    }
+
+    // @internal
+    #[test]
+    fn run_batch_continues_past_failures_by_default() {
+        let mut seen = Vec::new();
+        let err = run_batch(&["a", "bad", "c"], false, |item| {
+            seen.push(item.to_string());
+            if item == "bad" {
+                bail!("nope");
+            }
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert_eq!(seen, ["a", "bad", "c"]);
+        assert_eq!(err.to_string(), "1 of 3 item(s) failed");
+    }
+
+    // @internal
+    #[test]
+    fn run_batch_fail_fast_stops_at_first_failure() {
+        let mut seen = Vec::new();
+        let err = run_batch(&["a", "bad", "c"], true, |item| {
+            seen.push(item.to_string());
+            if item == "bad" {
+                bail!("nope");
+            }
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert_eq!(seen, ["a", "bad"]);
+        assert!(err.to_string().contains("after processing 2 of 3"));
+    }
 }
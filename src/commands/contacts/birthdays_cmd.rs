@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+use vauchi_core::FieldType;
+
+use crate::commands::common::open_vauchi;
+use crate::config::CliConfig;
+use crate::display;
+
+/// A contact's birthday, as read from a `birthday` field.
+#[derive(Debug, PartialEq, Eq)]
+struct Birthday {
+    year: Option<i32>,
+    month: u32,
+    day: u32,
+}
+
+/// Lists contacts whose birthday falls within `within_secs` from today.
+///
+/// Birthdays come from `birthday` fields on contact cards, soonest first.
+/// Dates without a year (`--MM-DD`) are listed without an age.
+///
+/// # Examples
+///
+/// ```text
+/// vauchi contacts birthdays
+/// vauchi contacts birthdays --within 7d
+/// ```
+pub fn birthdays(config: &CliConfig, within_secs: u64) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let today = chrono::DateTime::from_timestamp(crate::clock::unix_seconds() as i64, 0)
+        .unwrap_or_default()
+        .date_naive();
+    let within_days = (within_secs / (24 * 60 * 60)) as i64;
+
+    let mut upcoming = Vec::new();
+    let mut unreadable = 0;
+    for contact in wb.list_contacts()? {
+        for field in contact.card().fields() {
+            if !matches!(field.field_type(), FieldType::Birthday) {
+                continue;
+            }
+            let Some(birthday) = parse_birthday(field.value()) else {
+                unreadable += 1;
+                continue;
+            };
+            let next = next_occurrence(&birthday, today);
+            let days = (next - today).num_days();
+            if days <= within_days {
+                upcoming.push((next, days, contact.display_name().to_string(), birthday));
+            }
+        }
+    }
+
+    if upcoming.is_empty() {
+        display::info(&format!("No birthdays in the next {} day(s).", within_days));
+    } else {
+        upcoming.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.2.cmp(&b.2)));
+
+        println!();
+        println!("Upcoming birthdays ({}):", upcoming.len());
+        for (date, days, name, birthday) in &upcoming {
+            let when = match days {
+                0 => "today".to_string(),
+                1 => "tomorrow".to_string(),
+                n => format!("in {} days", n),
+            };
+            let age = birthday
+                .year
+                .map(|y| format!(", turns {}", date.year() - y))
+                .unwrap_or_default();
+            println!("  {}  {} ({}{})", date.format("%b %d"), name, when, age);
+        }
+        println!();
+    }
+
+    if unreadable > 0 {
+        display::warning(&format!(
+            "Skipped {} birthday field(s) with an unrecognized date",
+            unreadable
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parses `YYYY-MM-DD`, `YYYYMMDD`, or a year-less `--MM-DD` / `--MMDD` /
+/// `MM-DD` date.
+fn parse_birthday(value: &str) -> Option<Birthday> {
+    let value = value.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y%m%d"))
+    {
+        return Some(Birthday {
+            year: Some(date.year()),
+            month: date.month(),
+            day: date.day(),
+        });
+    }
+
+    let digits: String = value
+        .strip_prefix("--")
+        .unwrap_or(value)
+        .chars()
+        .filter(|c| *c != '-')
+        .collect();
+    if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let month = digits[..2].parse().ok()?;
+    let day = digits[2..].parse().ok()?;
+    // 2000 is a leap year, so Feb 29 is accepted.
+    NaiveDate::from_ymd_opt(2000, month, day)?;
+    Some(Birthday {
+        year: None,
+        month,
+        day,
+    })
+}
+
+/// The next date on or after `today` that is `birthday`'s anniversary.
+///
+/// Feb 29 birthdays fall on Mar 1 in non-leap years.
+fn next_occurrence(birthday: &Birthday, today: NaiveDate) -> NaiveDate {
+    let in_year = |year| {
+        NaiveDate::from_ymd_opt(year, birthday.month, birthday.day)
+            .or_else(|| NaiveDate::from_ymd_opt(year, 3, 1))
+            .unwrap_or(today)
+    };
+    let this_year = in_year(today.year());
+    if this_year >= today {
+        this_year
+    } else {
+        in_year(today.year() + 1)
+    }
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // @internal
+    #[test]
+    fn test_parse_birthday_formats() {
+        let full = Birthday {
+            year: Some(1990),
+            month: 3,
+            day: 14,
+        };
+        assert_eq!(parse_birthday("1990-03-14"), Some(full));
+        assert_eq!(parse_birthday("19900314").unwrap().year, Some(1990));
+
+        for yearless in ["--03-14", "--0314", "03-14"] {
+            let parsed = parse_birthday(yearless).unwrap();
+            assert_eq!((parsed.year, parsed.month, parsed.day), (None, 3, 14));
+        }
+
+        assert_eq!(parse_birthday("13-45"), None);
+        assert_eq!(parse_birthday("next tuesday"), None);
+    }
+
+    // @internal
+    #[test]
+    fn test_next_occurrence_wraps_to_next_year() {
+        let birthday = parse_birthday("--01-02").unwrap();
+        assert_eq!(
+            next_occurrence(&birthday, date(2026, 12, 30)),
+            date(2027, 1, 2)
+        );
+        assert_eq!(
+            next_occurrence(&birthday, date(2026, 1, 2)),
+            date(2026, 1, 2)
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_next_occurrence_leap_day_in_common_year() {
+        let birthday = parse_birthday("1992-02-29").unwrap();
+        assert_eq!(
+            next_occurrence(&birthday, date(2026, 2, 1)),
+            date(2026, 3, 1)
+        );
+    }
+}
@@ -2,30 +2,703 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::fs::File;
-use std::io::Write;
+use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
+use clap::ValueEnum;
+use qrcode::QrCode;
+use qrcode::render::svg;
+use serde_json::{Map, Value, json};
+use vauchi_core::Contact;
 use vauchi_core::contact_card::vcard::export_vcard;
 
 use super::find_contact;
-use crate::commands::common::open_vauchi;
+use crate::commands::common::{atomic_write, check_overwrite, open_vauchi};
 use crate::config::CliConfig;
 use crate::display;
 
-/// Exports a contact as vCard (.vcf format).
-pub fn export(config: &CliConfig, id_or_name: &str, output_path: &str) -> Result<()> {
+/// Output format for `contacts export`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ContactExportFormat {
+    /// vCard text (.vcf)
+    Vcard,
+    /// jCard, the JSON form of vCard (RFC 7095)
+    Jcard,
+}
+
+/// vCard version written by `contacts export`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum VcardVersion {
+    /// vCard 3.0 (RFC 2426), read by most address books
+    #[value(name = "3")]
+    V3,
+    /// vCard 4.0 (RFC 6350), for modern address books
+    #[value(name = "4")]
+    V4,
+}
+
+/// Exports a contact as vCard (.vcf format) or jCard.
+///
+/// With `anonymize`, every value is replaced by a placeholder naming its
+/// property and the UID is truncated, so the card can be shared to
+/// diagnose its structure.
+pub fn export(
+    config: &CliConfig,
+    id_or_name: &str,
+    output_path: &str,
+    anonymize: bool,
+    format: ContactExportFormat,
+    version: VcardVersion,
+) -> Result<()> {
     let wb = open_vauchi(config)?;
 
     let contact = find_contact(&wb, id_or_name)?;
     let contact_name = contact.display_name().to_string();
 
-    let vcard_content = export_vcard(contact.card());
+    let mut vcard_content = convert_vcard_version(&export_vcard(contact.card()), version);
+    if anonymize {
+        vcard_content = anonymize_vcard(&vcard_content);
+    }
+    let content = match format {
+        ContactExportFormat::Vcard => vcard_content,
+        ContactExportFormat::Jcard => {
+            serde_json::to_string_pretty(&vcard_to_jcard(&vcard_content))?
+        }
+    };
+
+    atomic_write(Path::new(output_path), &content)?;
+
+    if anonymize {
+        display::success(&format!(
+            "Exported {} to {} (anonymized)",
+            contact_name, output_path
+        ));
+    } else {
+        display::success(&format!("Exported {} to {}", contact_name, output_path));
+    }
+
+    Ok(())
+}
+
+/// Exports every contact added within the last `since_secs` seconds to one
+/// file, for incremental imports into another address book.
+///
+/// As jCard, the file holds an array of jCard objects.
+pub fn export_since(
+    config: &CliConfig,
+    since_secs: u64,
+    output_path: &Path,
+    format: ContactExportFormat,
+    version: VcardVersion,
+) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let contacts = added_within(wb.list_contacts()?, wb.clock().unix_seconds(), since_secs);
+    if contacts.is_empty() {
+        display::info("No contacts were added in that period");
+        return Ok(());
+    }
 
-    let mut file = File::create(output_path)?;
-    file.write_all(vcard_content.as_bytes())?;
+    atomic_write(
+        output_path,
+        render_contacts(contacts.iter(), format, version)?,
+    )?;
 
-    display::success(&format!("Exported {} to {}", contact_name, output_path));
+    display::success(&format!(
+        "Exported {} contact(s) to {}",
+        contacts.len(),
+        output_path.display()
+    ));
 
     Ok(())
 }
+
+/// Exports one file per label into `output_dir`, for distributing rosters.
+///
+/// Each file is named after its label; contacts without a label go to
+/// `ungrouped`. Labels with no exported members get no file. With
+/// `since_secs`, only contacts added within that many seconds are kept.
+pub fn export_by_label(
+    config: &CliConfig,
+    output_dir: &Path,
+    since_secs: Option<u64>,
+    format: ContactExportFormat,
+    version: VcardVersion,
+    force: bool,
+) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let contacts = match since_secs {
+        Some(secs) => added_within(wb.list_contacts()?, wb.clock().unix_seconds(), secs),
+        None => wb.list_contacts()?,
+    };
+    if contacts.is_empty() {
+        display::info("No contacts to export");
+        return Ok(());
+    }
+
+    let extension = match format {
+        ContactExportFormat::Vcard => "vcf",
+        ContactExportFormat::Jcard => "json",
+    };
+    let groups = wb.storage().labels().load_all_groups()?;
+    let mut files: Vec<(String, Vec<&Contact>)> = Vec::new();
+    for group in &groups {
+        let members: Vec<&Contact> = contacts
+            .iter()
+            .filter(|c| group.contacts().iter().any(|id| c.id() == id))
+            .collect();
+        if !members.is_empty() {
+            files.push((label_file_name(group.name()), members));
+        }
+    }
+    let ungrouped: Vec<&Contact> = contacts
+        .iter()
+        .filter(|c| {
+            !groups
+                .iter()
+                .any(|g| g.contacts().iter().any(|id| c.id() == id))
+        })
+        .collect();
+    if !ungrouped.is_empty() {
+        files.push((UNGROUPED_FILE_NAME.to_string(), ungrouped));
+    }
+
+    let mut paths = Vec::new();
+    for (name, _) in &files {
+        let path = output_dir.join(format!("{}.{}", name, extension));
+        if paths.contains(&path) {
+            bail!(
+                "Two labels would both be written to {}. Rename one of them first.",
+                path.display()
+            );
+        }
+        check_overwrite(&path, force)?;
+        paths.push(path);
+    }
+
+    std::fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create {}", output_dir.display()))?;
+    for ((_, members), path) in files.iter().zip(&paths) {
+        atomic_write(
+            path,
+            render_contacts(members.iter().copied(), format, version)?,
+        )?;
+        display::info(&format!("{}: {} contact(s)", path.display(), members.len()));
+    }
+
+    display::success(&format!(
+        "Exported {} contact(s) into {} file(s) in {}",
+        contacts.len(),
+        paths.len(),
+        output_dir.display()
+    ));
+
+    Ok(())
+}
+
+/// File name, without extension, for contacts that have no label.
+const UNGROUPED_FILE_NAME: &str = "ungrouped";
+
+/// Turns a label name into a file name, replacing path separators and
+/// other characters that are unsafe in file names.
+fn label_file_name(label: &str) -> String {
+    let name: String = label
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() || name.chars().all(|c| c == '_') {
+        "label".to_string()
+    } else {
+        name
+    }
+}
+
+/// Renders contacts as one vCard file, or as a JSON array of jCards.
+fn render_contacts<'a>(
+    contacts: impl Iterator<Item = &'a Contact>,
+    format: ContactExportFormat,
+    version: VcardVersion,
+) -> Result<String> {
+    let vcards = contacts.map(|c| convert_vcard_version(&export_vcard(c.card()), version));
+    Ok(match format {
+        ContactExportFormat::Vcard => vcards.collect(),
+        ContactExportFormat::Jcard => serde_json::to_string_pretty(&Value::Array(
+            vcards.map(|vcard| vcard_to_jcard(&vcard)).collect(),
+        ))?,
+    })
+}
+
+/// Keeps the contacts exchanged at most `since_secs` before `now`.
+fn added_within(contacts: Vec<Contact>, now: u64, since_secs: u64) -> Vec<Contact> {
+    contacts
+        .into_iter()
+        .filter(|c| now.saturating_sub(c.exchange_timestamp()) <= since_secs)
+        .collect()
+}
+
+/// Rewrites vCard text as `version`.
+///
+/// Sets the VERSION line. For 4.0, adds `KIND:individual`, writes phone
+/// numbers as `tel:` URIs (`TEL;VALUE=uri:tel:+41-79-...`) and turns
+/// `TYPE=pref` into `PREF=1`; for 3.0, those changes are undone. Folded
+/// continuation lines are kept as they are.
+fn convert_vcard_version(vcard: &str, version: VcardVersion) -> String {
+    let v4 = version == VcardVersion::V4;
+    let has_kind = vcard
+        .lines()
+        .any(|line| line.to_uppercase().starts_with("KIND:"));
+
+    let mut out = String::new();
+    for line in vcard.lines() {
+        let (head, value) = match line.split_once(':') {
+            Some(split) if !line.starts_with([' ', '\t']) => split,
+            _ => {
+                out.push_str(line);
+                out.push_str("\r\n");
+                continue;
+            }
+        };
+        let mut parts = head.split(';');
+        let name = parts.next().unwrap_or_default();
+        let property = name.rsplit('.').next().unwrap_or(name).to_uppercase();
+
+        match property.as_str() {
+            "VERSION" => {
+                out.push_str(if v4 {
+                    "VERSION:4.0\r\n"
+                } else {
+                    "VERSION:3.0\r\n"
+                });
+                if v4 && !has_kind {
+                    out.push_str("KIND:individual\r\n");
+                }
+                continue;
+            }
+            "KIND" if !v4 => continue,
+            _ => {}
+        }
+
+        let is_tel = property == "TEL";
+        let mut params = Vec::new();
+        let mut pref = false;
+        for param in parts {
+            let (key, param_value) = param.split_once('=').unwrap_or((param, ""));
+            match key.to_uppercase().as_str() {
+                "TYPE" if v4 => {
+                    let types: Vec<&str> = param_value
+                        .split(',')
+                        .filter(|t| {
+                            let is_pref = t.eq_ignore_ascii_case("pref");
+                            pref |= is_pref;
+                            !is_pref
+                        })
+                        .collect();
+                    if !types.is_empty() {
+                        params.push(format!("{}={}", key, types.join(",")));
+                    }
+                }
+                "PREF" if !v4 => pref = true,
+                "VALUE" if is_tel => {}
+                _ => params.push(param.to_string()),
+            }
+        }
+        if pref && v4 {
+            params.push("PREF=1".to_string());
+        } else if pref {
+            match params
+                .iter_mut()
+                .find(|p| p.to_uppercase().starts_with("TYPE="))
+            {
+                Some(types) => types.push_str(",pref"),
+                None => params.push("TYPE=pref".to_string()),
+            }
+        }
+
+        let value = match (is_tel, v4) {
+            (true, true) => {
+                params.insert(0, "VALUE=uri".to_string());
+                let number = value.strip_prefix("tel:").unwrap_or(value);
+                format!(
+                    "tel:{}",
+                    number.split_whitespace().collect::<Vec<_>>().join("-")
+                )
+            }
+            (true, false) => value.strip_prefix("tel:").unwrap_or(value).to_string(),
+            _ => value.to_string(),
+        };
+
+        out.push_str(name);
+        for param in &params {
+            out.push(';');
+            out.push_str(param);
+        }
+        out.push(':');
+        out.push_str(&value);
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Replaces each vCard property value with `[property]`, keeping one line
+/// per property so counts and order survive.
+///
+/// Parameters are dropped, as they can carry labels, and folded
+/// continuation lines go with the value they continue. The UID keeps its
+/// first 8 characters.
+fn anonymize_vcard(vcard: &str) -> String {
+    let mut out = String::new();
+    for line in vcard.lines() {
+        if line.starts_with([' ', '\t']) {
+            continue;
+        }
+        let Some((head, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = head.split(';').next().unwrap_or(head);
+        let name = name.rsplit('.').next().unwrap_or(name).to_uppercase();
+        let value = match name.as_str() {
+            "BEGIN" | "END" | "VERSION" | "PRODID" | "KIND" => value.to_string(),
+            "UID" => format!("{}...", value.chars().take(8).collect::<String>()),
+            _ => format!("[{}]", name.to_lowercase()),
+        };
+        out.push_str(&format!("{}:{}\r\n", name, value));
+    }
+    out
+}
+
+/// Converts vCard text to jCard (RFC 7095): `["vcard", [properties]]`,
+/// each property being `[name, {params}, type, value]`.
+///
+/// Folded lines are joined and escapes undone; the structured N and ADR
+/// values become arrays of their components.
+fn vcard_to_jcard(vcard: &str) -> Value {
+    let mut lines: Vec<String> = Vec::new();
+    for line in vcard.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut properties = Vec::new();
+    for line in &lines {
+        let Some((head, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut parts = head.split(';');
+        let name = parts.next().unwrap_or_default();
+        let name = name.rsplit('.').next().unwrap_or(name).to_lowercase();
+        if name == "begin" || name == "end" {
+            continue;
+        }
+
+        let mut params = Map::new();
+        let mut value_type = match name.as_str() {
+            "url" | "photo" | "logo" | "source" => "uri".to_string(),
+            _ => "text".to_string(),
+        };
+        for param in parts {
+            let (key, param_value) = param.split_once('=').unwrap_or((param, ""));
+            let key = key.to_lowercase();
+            let param_value = param_value.trim_matches('"');
+            if key == "value" {
+                value_type = param_value.to_lowercase();
+            } else {
+                params.insert(key, Value::String(param_value.to_string()));
+            }
+        }
+
+        let value = match name.as_str() {
+            "n" | "adr" => Value::Array(
+                split_unescaped(value, ';')
+                    .iter()
+                    .map(|part| Value::String(unescape_vcard(part)))
+                    .collect(),
+            ),
+            _ => Value::String(unescape_vcard(value)),
+        };
+        properties.push(json!([name, params, value_type, value]));
+    }
+
+    json!(["vcard", properties])
+}
+
+/// Splits on `sep` where it is not escaped with a backslash.
+fn split_unescaped(value: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == sep => {
+                parts.push(&value[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+/// Undoes vCard text escaping (`\n`, `\,`, `\;`, `\\`).
+fn unescape_vcard(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Exports a printable HTML sheet with one vCard QR code per contact.
+///
+/// Selects a single contact when `id_or_name` is given, the members of
+/// `label` when given, and all contacts otherwise. With `since_secs`, only
+/// contacts added within that many seconds are kept.
+pub fn export_qr_sheet(
+    config: &CliConfig,
+    id_or_name: Option<&str>,
+    label: Option<&str>,
+    since_secs: Option<u64>,
+    output_path: &Path,
+) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let contacts = match (id_or_name, label) {
+        (Some(id), _) => vec![find_contact(&wb, id)?],
+        (None, Some(label_name)) => {
+            let group = wb
+                .find_group_fuzzy(label_name)?
+                .ok_or_else(|| anyhow::anyhow!("Label not found: {}", label_name))?;
+            wb.list_contacts()?
+                .into_iter()
+                .filter(|c| group.contacts().iter().any(|id| c.id() == id))
+                .collect()
+        }
+        (None, None) => wb.list_contacts()?,
+    };
+    let contacts = match since_secs {
+        Some(secs) => added_within(contacts, wb.clock().unix_seconds(), secs),
+        None => contacts,
+    };
+
+    if contacts.is_empty() {
+        bail!("No contacts to export");
+    }
+
+    let entries: Vec<(String, String)> = contacts
+        .iter()
+        .map(|c| (c.display_name().to_string(), export_vcard(c.card())))
+        .collect();
+    let html = render_qr_sheet(&entries)?;
+
+    atomic_write(output_path, &html)?;
+
+    display::success(&format!(
+        "Exported QR sheet with {} contact(s) to {}",
+        entries.len(),
+        output_path.display()
+    ));
+
+    Ok(())
+}
+
+/// Renders `(name, payload)` pairs as an HTML grid of inline SVG QR codes.
+fn render_qr_sheet(entries: &[(String, String)]) -> Result<String> {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Vauchi contacts</title>\n<style>\n\
+         body { font-family: sans-serif; }\n\
+         .sheet { display: grid; grid-template-columns: repeat(3, 1fr); gap: 24px; }\n\
+         figure { margin: 0; text-align: center; break-inside: avoid; }\n\
+         </style>\n</head>\n<body>\n<div class=\"sheet\">\n",
+    );
+
+    for (name, payload) in entries {
+        let code = QrCode::new(payload.as_bytes())
+            .with_context(|| format!("Contact '{}' is too large for a QR code", name))?;
+        let image = code.render::<svg::Color>().min_dimensions(200, 200).build();
+        // Drop the XML declaration so the SVG can be inlined.
+        let svg_start = image.find("<svg").unwrap_or(0);
+
+        html.push_str("<figure>\n");
+        html.push_str(&image[svg_start..]);
+        html.push_str(&format!(
+            "\n<figcaption>{}</figcaption>\n</figure>\n",
+            escape_html(name)
+        ));
+    }
+
+    html.push_str("</div>\n</body>\n</html>\n");
+    Ok(html)
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_label_file_name_replaces_unsafe_characters() {
+        assert_eq!(label_file_name("Team Rosters"), "Team Rosters");
+        assert_eq!(label_file_name("a/b\\c"), "a_b_c");
+        assert_eq!(label_file_name(" Café-2 "), "Café-2");
+        assert_eq!(label_file_name(".."), "label");
+    }
+
+    // @internal
+    #[test]
+    fn test_qr_sheet_has_one_qr_per_contact() {
+        let entries = vec![
+            (
+                "Alice".to_string(),
+                "BEGIN:VCARD\nFN:Alice\nEND:VCARD".to_string(),
+            ),
+            (
+                "Bob".to_string(),
+                "BEGIN:VCARD\nFN:Bob\nEND:VCARD".to_string(),
+            ),
+        ];
+
+        let html = render_qr_sheet(&entries).unwrap();
+
+        assert_eq!(html.matches("<svg").count(), 2);
+        assert!(html.contains("<figcaption>Alice</figcaption>"));
+        assert!(html.contains("<figcaption>Bob</figcaption>"));
+        assert!(!html.contains("<?xml"));
+    }
+
+    // @internal
+    #[test]
+    fn test_added_within_excludes_old_contacts() {
+        use vauchi_core::ContactCard;
+        use vauchi_core::crypto::SymmetricKey;
+
+        let now = 10 * 86400;
+        let contact = |key: u8, name: &str, exchanged_at: u64| {
+            Contact::from_exchange(
+                [key; 32],
+                ContactCard::new(name),
+                SymmetricKey::generate(),
+                exchanged_at,
+            )
+        };
+        let contacts = vec![
+            contact(1, "Old Friend", now - 3 * 86400),
+            contact(2, "New Friend", now - 3600),
+        ];
+
+        let recent = added_within(contacts, now, 86400);
+
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].display_name(), "New Friend");
+    }
+
+    // @internal
+    #[test]
+    fn test_anonymized_vcard_hides_values_and_keeps_field_count() {
+        let vcard = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Bob Jones\r\n\
+                     UID:0123456789abcdef\r\n\
+                     EMAIL;TYPE=work:bob@example.com\r\n\
+                     TEL;TYPE=cell:+1 202 555 0100\r\n\
+                     item1.URL:https://bob.example\r\n \
+                     /folded\r\nEND:VCARD\r\n";
+
+        let anonymized = anonymize_vcard(vcard);
+
+        assert!(!anonymized.contains("bob@example.com"));
+        assert!(!anonymized.contains("555"));
+        assert!(!anonymized.contains("Bob Jones"));
+        assert!(!anonymized.contains("folded"));
+        assert!(anonymized.contains("EMAIL:[email]"));
+        assert!(anonymized.contains("TEL:[tel]"));
+        assert!(anonymized.contains("URL:[url]"));
+        assert!(anonymized.contains("UID:01234567..."));
+        assert_eq!(anonymized.lines().count(), 8);
+    }
+
+    // @internal
+    #[test]
+    fn test_jcard_has_vcard_shape() {
+        let vcard = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Bob Jones\r\n\
+                     N:Jones;Bob;;;\r\n\
+                     EMAIL;TYPE=work:bob@example.com\r\n\
+                     NOTE:line one\\nline \r\n two\\, more\r\nEND:VCARD\r\n";
+
+        let text = serde_json::to_string(&vcard_to_jcard(vcard)).unwrap();
+        let jcard: Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(jcard[0], "vcard");
+        let properties = jcard[1].as_array().unwrap();
+        assert_eq!(properties.len(), 5);
+        assert_eq!(properties[0], json!(["version", {}, "text", "4.0"]));
+        assert_eq!(
+            properties[2],
+            json!(["n", {}, "text", ["Jones", "Bob", "", "", ""]])
+        );
+        assert_eq!(
+            properties[3],
+            json!(["email", {"type": "work"}, "text", "bob@example.com"])
+        );
+        assert_eq!(properties[4][3], "line one\nline two, more");
+    }
+
+    // @internal
+    #[test]
+    fn test_vcard_4_uses_tel_uris_and_kind() {
+        let vcard = "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Bob Jones\r\n\
+                     TEL;TYPE=cell,pref:+1 202 555 0100\r\n\
+                     EMAIL;TYPE=work:bob@example.com\r\nEND:VCARD\r\n";
+
+        let v4 = convert_vcard_version(vcard, VcardVersion::V4);
+
+        assert!(v4.contains("\r\nVERSION:4.0\r\nKIND:individual\r\n"));
+        assert!(v4.contains("\r\nTEL;VALUE=uri;TYPE=cell;PREF=1:tel:+1-202-555-0100\r\n"));
+        assert!(v4.contains("\r\nEMAIL;TYPE=work:bob@example.com\r\n"));
+        assert!(!v4.contains("VERSION:3.0"));
+
+        let v3 = convert_vcard_version(&v4, VcardVersion::V3);
+
+        assert!(v3.contains("\r\nVERSION:3.0\r\n"));
+        assert!(v3.contains("\r\nTEL;TYPE=cell,pref:+1-202-555-0100\r\n"));
+        assert!(!v3.contains("KIND"));
+        assert!(!v3.contains("tel:"));
+    }
+
+    // @internal
+    #[test]
+    fn test_qr_sheet_escapes_names() {
+        let entries = vec![("<b>Eve</b>".to_string(), "x".to_string())];
+
+        let html = render_qr_sheet(&entries).unwrap();
+
+        assert!(html.contains("&lt;b&gt;Eve&lt;/b&gt;"));
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::path::Path;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use vauchi_core::Contact;
+
+use crate::commands::common::{atomic_write_restricted, csv_escape, open_vauchi};
+use crate::config::CliConfig;
+use crate::display;
+
+/// Output format for `contacts export-keys`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum KeyExportFormat {
+    Json,
+    Csv,
+}
+
+/// Cryptographic identity of a contact, for out-of-band verification.
+#[derive(Serialize)]
+struct ContactKeyRecord {
+    id: String,
+    display_name: String,
+    /// Hex public key; empty for imported contacts that have none.
+    public_key: String,
+    fingerprint: String,
+    fingerprint_verified: bool,
+    exchange_timestamp: u64,
+}
+
+impl From<&Contact> for ContactKeyRecord {
+    fn from(c: &Contact) -> Self {
+        Self {
+            id: c.id().to_string(),
+            display_name: c.display_name().to_string(),
+            public_key: c.public_key().map(hex::encode).unwrap_or_default(),
+            fingerprint: c.fingerprint().to_string(),
+            fingerprint_verified: c.is_fingerprint_verified(),
+            exchange_timestamp: c.exchange_timestamp(),
+        }
+    }
+}
+
+/// Exports public keys, fingerprints and verification status of all contacts.
+pub fn export_keys(config: &CliConfig, output_path: &Path, format: KeyExportFormat) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let records: Vec<ContactKeyRecord> = wb
+        .list_contacts()?
+        .iter()
+        .map(ContactKeyRecord::from)
+        .collect();
+
+    let content = match format {
+        KeyExportFormat::Json => serde_json::to_string_pretty(&records)?,
+        KeyExportFormat::Csv => to_csv(&records),
+    };
+    atomic_write_restricted(output_path, content)?;
+
+    display::success(&format!(
+        "Exported keys for {} contact(s) to {}",
+        records.len(),
+        output_path.display()
+    ));
+
+    Ok(())
+}
+
+fn to_csv(records: &[ContactKeyRecord]) -> String {
+    let mut csv = String::from(
+        "id,display_name,public_key,fingerprint,fingerprint_verified,exchange_timestamp\n",
+    );
+    for r in records {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            csv_escape(&r.id),
+            csv_escape(&r.display_name),
+            r.public_key,
+            csv_escape(&r.fingerprint),
+            r.fingerprint_verified,
+            r.exchange_timestamp
+        ));
+    }
+    csv
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str) -> ContactKeyRecord {
+        ContactKeyRecord {
+            id: "c1".to_string(),
+            display_name: name.to_string(),
+            public_key: "ab".repeat(32),
+            fingerprint: "1234 5678".to_string(),
+            fingerprint_verified: true,
+            exchange_timestamp: 1_700_000_000,
+        }
+    }
+
+    // @internal
+    #[test]
+    fn test_csv_contains_header_and_row() {
+        let csv = to_csv(&[record("Bob")]);
+        let mut lines = csv.lines();
+
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("id,display_name,public_key")
+        );
+        let row = lines.next().unwrap();
+        assert!(row.contains("Bob"));
+        assert!(row.contains("1234 5678"));
+        assert!(row.ends_with("true,1700000000"));
+    }
+
+    // @internal
+    #[test]
+    fn test_csv_quotes_names_with_commas() {
+        let csv = to_csv(&[record("Smith, \"Bob\"")]);
+        assert!(csv.contains("\"Smith, \"\"Bob\"\"\""));
+    }
+}
@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Result;
+use clap::ValueEnum;
+use vauchi_core::Contact;
+
+use crate::commands::common::open_vauchi;
+use crate::config::CliConfig;
+
+/// Output format for `contacts graph`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+/// Node ID used for the local identity.
+const SELF_NODE: &str = "me";
+
+/// A contact in the verification graph, linked to the local identity.
+struct GraphContact {
+    id: String,
+    label: String,
+    verified: bool,
+    trusted: bool,
+}
+
+impl From<&Contact> for GraphContact {
+    fn from(c: &Contact) -> Self {
+        Self {
+            id: c.id().to_string(),
+            label: c.display_name().to_string(),
+            verified: c.is_fingerprint_verified(),
+            trusted: c.is_recovery_trusted(),
+        }
+    }
+}
+
+/// Prints the verification/trust network of the local identity.
+pub fn graph(config: &CliConfig, format: GraphFormat) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let own_name = wb
+        .identity()
+        .map(|i| i.display_name().to_string())
+        .unwrap_or_else(|| "Me".to_string());
+    let contacts: Vec<GraphContact> = wb.list_contacts()?.iter().map(GraphContact::from).collect();
+
+    match format {
+        GraphFormat::Dot => print!("{}", render_dot(&own_name, &contacts)),
+        GraphFormat::Json => {
+            crate::raw::print_json(&render_json(&own_name, &contacts))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders the graph as Graphviz DOT.
+///
+/// Verified contacts are drawn solid and green, unverified ones dashed;
+/// recovery-trusted edges are bold and labelled.
+fn render_dot(own_name: &str, contacts: &[GraphContact]) -> String {
+    let mut dot = String::from("graph vauchi {\n");
+    dot.push_str(&format!(
+        "  \"{}\" [label=\"{}\", shape=doublecircle];\n",
+        SELF_NODE,
+        dot_escape(own_name)
+    ));
+
+    for c in contacts {
+        let style = if c.verified {
+            "style=solid, color=green"
+        } else {
+            "style=dashed, color=gray"
+        };
+        dot.push_str(&format!(
+            "  \"{}\" [label=\"{}\", verified={}, {}];\n",
+            dot_escape(&c.id),
+            dot_escape(&c.label),
+            c.verified,
+            style
+        ));
+    }
+
+    for c in contacts {
+        let mut attrs = vec![if c.verified {
+            "style=solid"
+        } else {
+            "style=dashed"
+        }];
+        if c.trusted {
+            attrs.push("penwidth=2, label=\"trusted\"");
+        }
+        dot.push_str(&format!(
+            "  \"{}\" -- \"{}\" [{}];\n",
+            SELF_NODE,
+            dot_escape(&c.id),
+            attrs.join(", ")
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Renders the graph as a `{nodes, edges}` JSON document.
+fn render_json(own_name: &str, contacts: &[GraphContact]) -> serde_json::Value {
+    let mut nodes = vec![serde_json::json!({ "id": SELF_NODE, "label": own_name, "self": true })];
+    nodes.extend(
+        contacts
+            .iter()
+            .map(|c| serde_json::json!({ "id": c.id, "label": c.label, "self": false })),
+    );
+    let edges: Vec<_> = contacts
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "source": SELF_NODE,
+                "target": c.id,
+                "verified": c.verified,
+                "trusted": c.trusted,
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "nodes": nodes, "edges": edges })
+}
+
+/// Escapes a string for use inside a quoted DOT ID.
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contact(id: &str, verified: bool, trusted: bool) -> GraphContact {
+        GraphContact {
+            id: id.to_string(),
+            label: format!("Contact {}", id),
+            verified,
+            trusted,
+        }
+    }
+
+    // @internal
+    #[test]
+    fn test_dot_marks_trusted_edges() {
+        let dot = render_dot("Alice", &[contact("c1", true, true)]);
+        assert!(dot.contains("\"me\" -- \"c1\" [style=solid, penwidth=2, label=\"trusted\"]"));
+    }
+
+    // @internal
+    #[test]
+    fn test_dot_escapes_quotes_in_names() {
+        let dot = render_dot("Al \"the\" ice", &[]);
+        assert!(dot.contains("label=\"Al \\\"the\\\" ice\""));
+    }
+
+    // @internal
+    #[test]
+    fn test_json_has_self_node_and_one_edge_per_contact() {
+        let json = render_json(
+            "Alice",
+            &[contact("c1", false, false), contact("c2", true, false)],
+        );
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(json["edges"].as_array().unwrap().len(), 2);
+        assert_eq!(json["edges"][1]["verified"], true);
+    }
+}
@@ -5,6 +5,10 @@
 use std::path::Path;
 
 use anyhow::{Context, Result};
+use dialoguer::Password;
+use vauchi_core::contact_card::vcard::export_vcard;
+use vauchi_core::crypto::SymmetricKey;
+use vauchi_core::{Contact, Vauchi, VauchiConfig};
 
 use crate::commands::common::open_vauchi;
 use crate::config::CliConfig;
@@ -26,3 +30,79 @@ pub fn import(config: &CliConfig, file: &Path) -> Result<()> {
 
     Ok(())
 }
+
+/// Imports the contacts from a full backup into the current identity.
+///
+/// The backup is restored into a throwaway store, and its contacts are
+/// added as placeholder (unverified) contacts via vCard. Contacts whose
+/// name already exists here are skipped. The current identity is not
+/// changed.
+pub fn import_from_backup(config: &CliConfig, file: &Path, password: Option<&str>) -> Result<()> {
+    let backup_hex =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read {:?}", file))?;
+    let wb = open_vauchi(config)?;
+
+    let password = match password {
+        Some(p) => p.to_string(),
+        None => Password::new()
+            .with_prompt("Enter backup password")
+            .interact()?,
+    };
+
+    // Owner-only and randomly named, removed when dropped.
+    let scratch_dir = tempfile::Builder::new()
+        .prefix(".backup-import-")
+        .tempdir_in(&config.data_dir)
+        .context("Failed to create a scratch directory")?;
+    let backup_contacts = read_backup_contacts(config, scratch_dir.path(), &backup_hex, &password)?;
+
+    let existing = wb.list_contacts()?;
+    let (new, skipped) = split_new_contacts(backup_contacts, &existing);
+
+    let vcf: String = new.iter().map(|c| export_vcard(c.card())).collect();
+    let imported = if new.is_empty() {
+        0
+    } else {
+        wb.import_contacts_from_vcf(vcf.as_bytes())?.imported
+    };
+
+    display::success(&format!("Imported {} contacts from backup", imported));
+    if skipped > 0 {
+        display::info(&format!("Skipped {} contacts that already exist", skipped));
+    }
+    display::info("Imported contacts are unverified placeholders until you exchange again.");
+
+    Ok(())
+}
+
+/// Restores a full backup into `scratch_dir` and returns its contacts.
+fn read_backup_contacts(
+    config: &CliConfig,
+    scratch_dir: &Path,
+    backup_hex: &str,
+    password: &str,
+) -> Result<Vec<Contact>> {
+    let wb_config = VauchiConfig::with_storage_path(scratch_dir.join("backup.db"))
+        .with_relay_url(&config.relay_url)
+        .with_storage_key(SymmetricKey::generate());
+    let mut scratch = Vauchi::new(wb_config)?;
+    scratch
+        .import_full_backup(backup_hex, password)
+        .context("Failed to decrypt backup (wrong password or not a full backup?)")?;
+    Ok(scratch.list_contacts()?)
+}
+
+/// Splits backup contacts into ones not yet present (by name) and a skip count.
+fn split_new_contacts(backup: Vec<Contact>, existing: &[Contact]) -> (Vec<Contact>, usize) {
+    let total = backup.len();
+    let new: Vec<Contact> = backup
+        .into_iter()
+        .filter(|c| {
+            !existing
+                .iter()
+                .any(|e| e.display_name().eq_ignore_ascii_case(c.display_name()))
+        })
+        .collect();
+    let skipped = total - new.len();
+    (new, skipped)
+}
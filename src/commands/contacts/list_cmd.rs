@@ -4,7 +4,7 @@
 
 use anyhow::Result;
 
-use crate::commands::common::open_vauchi_authenticated;
+use crate::commands::common::{open_vauchi, open_vauchi_authenticated};
 use crate::config::CliConfig;
 use crate::display;
 
@@ -77,6 +77,31 @@ pub fn list(
     Ok(())
 }
 
+/// Prints only the number of contacts `list` would show.
+pub fn count(
+    config: &CliConfig,
+    pin: Option<&str>,
+    offset: usize,
+    limit: usize,
+    archived: bool,
+) -> Result<()> {
+    let count = if archived {
+        let wb = open_vauchi(config)?;
+        wb.list_archived_contacts()?.len()
+    } else {
+        let wb = open_vauchi_authenticated(config, pin)?;
+        page_len(wb.list_contacts()?.len(), offset, limit)
+    };
+    println!("{}", count);
+    Ok(())
+}
+
+/// Number of contacts on a page starting at `offset` (`limit` 0 = all).
+fn page_len(total: usize, offset: usize, limit: usize) -> usize {
+    let rest = total.saturating_sub(offset);
+    if limit == 0 { rest } else { rest.min(limit) }
+}
+
 /// Searches contacts by query (respects auth mode).
 pub fn search(config: &CliConfig, pin: Option<&str>, query: &str, locale: &str) -> Result<()> {
     let wb = open_vauchi_authenticated(config, pin)?;
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use anyhow::{Result, bail};
+use vauchi_core::Contact;
 
 use super::find_contact;
 use crate::commands::common::open_vauchi;
@@ -124,16 +125,8 @@ pub fn duplicates(config: &CliConfig) -> Result<()> {
     println!();
 
     for (i, pair) in active_duplicates.iter().enumerate() {
-        let name1 = contacts
-            .iter()
-            .find(|c| c.id() == pair.id1)
-            .map(|c| c.display_name().to_string())
-            .unwrap_or_else(|| pair.id1[..8.min(pair.id1.len())].to_string());
-        let name2 = contacts
-            .iter()
-            .find(|c| c.id() == pair.id2)
-            .map(|c| c.display_name().to_string())
-            .unwrap_or_else(|| pair.id2[..8.min(pair.id2.len())].to_string());
+        let name1 = contact_name(&contacts, &pair.id1);
+        let name2 = contact_name(&contacts, &pair.id2);
 
         let similarity_pct = (pair.similarity * 100.0) as u32;
 
@@ -156,6 +149,120 @@ pub fn duplicates(config: &CliConfig) -> Result<()> {
     Ok(())
 }
 
+/// Finds likely duplicate contacts and suggests merges.
+///
+/// Contacts sharing a public key are hard duplicates: the same person was
+/// added twice. Contacts with similar names or overlapping field values are
+/// soft duplicates; dismissed pairs are left out.
+///
+/// # Examples
+///
+/// ```text
+/// vauchi contacts dedupe
+/// ```
+pub fn dedupe(config: &CliConfig) -> Result<()> {
+    use vauchi_core::contact::merge::{filter_dismissed, find_duplicates};
+
+    let wb = open_vauchi(config)?;
+    let contacts = wb.list_contacts()?;
+
+    if contacts.len() < 2 {
+        display::info("Need at least 2 contacts to check for duplicates.");
+        return Ok(());
+    }
+
+    let hard = hard_duplicates(&contacts);
+    let dismissed = wb.storage().contacts().load_dismissed_duplicates()?;
+    let soft: Vec<_> = filter_dismissed(find_duplicates(&contacts), &dismissed)
+        .into_iter()
+        .filter(|pair| {
+            !hard
+                .iter()
+                .any(|(a, b)| is_same_pair(a.id(), b.id(), &pair.id1, &pair.id2))
+        })
+        .collect();
+
+    if hard.is_empty() && soft.is_empty() {
+        display::info("No duplicate candidates found.");
+        return Ok(());
+    }
+
+    println!();
+    if !hard.is_empty() {
+        display::warning(&format!(
+            "Hard duplicates, same public key ({}):",
+            hard.len()
+        ));
+        for (a, b) in &hard {
+            println!(
+                "  {} <-> {}  [{} / {}]",
+                a.display_name(),
+                b.display_name(),
+                a.id(),
+                b.id()
+            );
+        }
+        println!();
+    }
+
+    if !soft.is_empty() {
+        println!(
+            "Possible duplicates, similar name or fields ({}):",
+            soft.len()
+        );
+        for pair in &soft {
+            println!(
+                "  {} <-> {} ({}% similar)",
+                contact_name(&contacts, &pair.id1),
+                contact_name(&contacts, &pair.id2),
+                (pair.similarity * 100.0) as u32
+            );
+        }
+        println!();
+    }
+
+    display::info("Use 'vauchi contacts merge <contact1> <contact2>' to merge a pair.");
+    if !soft.is_empty() {
+        display::info(
+            "Use 'vauchi contacts dismiss-duplicate <contact1> <contact2>' to dismiss a false positive.",
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Returns every pair of contacts that share a public key.
+///
+/// Contacts without a public key (e.g. vCard imports) are never paired.
+fn hard_duplicates(contacts: &[Contact]) -> Vec<(&Contact, &Contact)> {
+    let mut pairs = Vec::new();
+    for (i, a) in contacts.iter().enumerate() {
+        let Some(key) = a.public_key() else {
+            continue;
+        };
+        for b in &contacts[i + 1..] {
+            if b.public_key() == Some(key) {
+                pairs.push((a, b));
+            }
+        }
+    }
+    pairs
+}
+
+fn is_same_pair(a1: &str, b1: &str, a2: &str, b2: &str) -> bool {
+    (a1 == a2 && b1 == b2) || (a1 == b2 && b1 == a2)
+}
+
+/// Display name for `id`, or its short prefix if the contact is gone.
+fn contact_name(contacts: &[Contact], id: &str) -> String {
+    contacts
+        .iter()
+        .find(|c| c.id() == id)
+        .map(|c| c.display_name().to_string())
+        .unwrap_or_else(|| id[..8.min(id.len())].to_string())
+}
+
 /// Dismisses a duplicate pair as a false positive.
 ///
 /// The pair will no longer appear in the duplicates list.
@@ -223,3 +330,47 @@ pub fn undismiss_duplicate(config: &CliConfig, contact1: &str, contact2: &str) -
 
     Ok(())
 }
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vauchi_core::ContactCard;
+    use vauchi_core::crypto::SymmetricKey;
+
+    fn contact(key: [u8; 32], name: &str) -> Contact {
+        Contact::from_exchange(
+            key,
+            ContactCard::new(name),
+            SymmetricKey::generate(),
+            crate::clock::unix_seconds(),
+        )
+    }
+
+    // @internal
+    #[test]
+    fn test_hard_duplicates_reports_same_public_key() {
+        let contacts = [
+            contact([1u8; 32], "Bob Jones"),
+            contact([2u8; 32], "Carol White"),
+            contact([1u8; 32], "Robert J."),
+        ];
+
+        let hard = hard_duplicates(&contacts);
+
+        assert_eq!(hard.len(), 1);
+        assert_eq!(hard[0].0.display_name(), "Bob Jones");
+        assert_eq!(hard[0].1.display_name(), "Robert J.");
+    }
+
+    // @internal
+    #[test]
+    fn test_hard_duplicates_ignores_distinct_keys() {
+        let contacts = [
+            contact([1u8; 32], "Alice Johnson"),
+            contact([2u8; 32], "Alice Johnson"),
+        ];
+
+        assert!(hard_duplicates(&contacts).is_empty());
+    }
+}
@@ -7,10 +7,13 @@
 //! List, view, and manage contacts.
 
 mod archive_cmd;
+mod birthdays_cmd;
 mod block_cmd;
 mod delete_cmd;
 mod export_cmd;
+mod export_keys_cmd;
 mod favorite_cmd;
+mod graph_cmd;
 mod hide_cmd;
 mod import_cmd;
 mod limit_cmd;
@@ -18,29 +21,44 @@ mod list_cmd;
 mod merge_cmd;
 mod notes_cmd;
 mod open_cmd;
+mod prune_cmd;
 mod remove_cmd;
+mod resend_card_cmd;
 mod show_cmd;
+mod trash_cmd;
 mod trust_cmd;
 mod verify_cmd;
 mod visibility_cmd;
+mod watch_cmd;
 
 pub use archive_cmd::{archive, list_archived, unarchive};
+pub use birthdays_cmd::birthdays;
 pub use block_cmd::{block, list_blocked, unblock};
 pub use delete_cmd::delete;
-pub use export_cmd::export;
+pub use export_cmd::{
+    ContactExportFormat, VcardVersion, export, export_by_label, export_qr_sheet, export_since,
+};
+pub use export_keys_cmd::{KeyExportFormat, export_keys};
 pub use favorite_cmd::{favorite, unfavorite};
+pub use graph_cmd::{GraphFormat, graph};
 pub use hide_cmd::{hide_contact, list_hidden, unhide_contact};
-pub use import_cmd::import as import_vcf;
+pub use import_cmd::{import as import_vcf, import_from_backup};
 pub use limit_cmd::limit;
-pub use list_cmd::{list, search};
-pub use merge_cmd::{dismiss_duplicate, duplicates, merge, undismiss_duplicate};
+pub use list_cmd::{count, list, search};
+pub use merge_cmd::{dedupe, dismiss_duplicate, duplicates, merge, undismiss_duplicate};
 pub use notes_cmd::{add_note, delete_note, edit_note, show_note};
 pub use open_cmd::{open_field, open_interactive};
+pub use prune_cmd::prune_unverified;
 pub use remove_cmd::remove;
-pub use show_cmd::{show, show_visibility};
+pub use resend_card_cmd::resend_card;
+pub use show_cmd::{show, show_shared, show_visibility};
+pub(crate) use trash_cmd::{TRASH_FILE, Trash};
+pub use trash_cmd::{empty_trash, list_trash, purge_expired_trash, restore};
 pub use trust_cmd::{trust, untrust};
-pub use verify_cmd::verify;
+pub use verify_cmd::{list_unverified, verify, verify_batch};
 pub use visibility_cmd::{hide_field, unhide_field};
+pub(crate) use watch_cmd::{WATCH_FILE, WatchList};
+pub use watch_cmd::{unwatch, watch};
 
 use anyhow::{Result, bail};
 use vauchi_core::Vauchi;
@@ -2,7 +2,10 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use std::io::{IsTerminal, Write};
+
 use anyhow::Result;
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use vauchi_core::contact_card::ContactAction;
 
 use super::{action_label, execute_action, find_contact};
@@ -11,7 +14,15 @@ use crate::config::CliConfig;
 use crate::display;
 
 /// Opens a contact field in the system default application.
-pub fn open_field(config: &CliConfig, contact_id_or_name: &str, field_label: &str) -> Result<()> {
+///
+/// With `copy`, or when there is no display to open an app on, the value
+/// is copied instead. Copying is also the fallback when opening fails.
+pub fn open_field(
+    config: &CliConfig,
+    contact_id_or_name: &str,
+    field_label: &str,
+    copy: bool,
+) -> Result<()> {
     let wb = open_vauchi(config)?;
 
     let contact = find_contact(&wb, contact_id_or_name)?;
@@ -24,6 +35,14 @@ pub fn open_field(config: &CliConfig, contact_id_or_name: &str, field_label: &st
         .find(|f| f.label().to_lowercase() == field_label.to_lowercase())
         .ok_or_else(|| anyhow::anyhow!("Field '{}' not found for {}", field_label, contact_name))?;
 
+    if copy || is_headless() {
+        if !copy {
+            display::info("No display found; copying the value instead of opening it.");
+        }
+        copy_value(field.label(), field.value());
+        return Ok(());
+    }
+
     // Get URI using vauchi-core's secure URI builder
     let uri = field.to_uri();
     let action = field.to_action();
@@ -52,10 +71,7 @@ pub fn open_field(config: &CliConfig, contact_id_or_name: &str, field_label: &st
                 }
                 Err(e) => {
                     display::error(&format!("Failed to open: {}", e));
-                    println!();
-                    println!("  Value: {}", field.value());
-                    println!();
-                    display::info("You can select and copy the value above manually.");
+                    copy_value(field.label(), field.value());
                 }
             }
         }
@@ -107,7 +123,7 @@ pub fn open_interactive(config: &CliConfig, contact_id_or_name: &str) -> Result<
 
     // If only one action (CopyToClipboard), skip the action menu
     if actions.len() <= 1 {
-        return open_field(config, contact.id(), selected_field.label());
+        return open_field(config, contact.id(), selected_field.label(), false);
     }
 
     let action_items: Vec<String> = actions.iter().map(action_label).collect();
@@ -120,3 +136,30 @@ pub fn open_interactive(config: &CliConfig, contact_id_or_name: &str) -> Result<
 
     execute_action(&actions[action_idx])
 }
+
+/// True when there is no graphical session to open an app in (Linux and
+/// BSD without `DISPLAY` or `WAYLAND_DISPLAY`).
+fn is_headless() -> bool {
+    cfg!(all(unix, not(target_os = "macos")))
+        && std::env::var_os("DISPLAY").is_none()
+        && std::env::var_os("WAYLAND_DISPLAY").is_none()
+}
+
+/// Copies `value` to the clipboard through the terminal (OSC 52).
+///
+/// OSC 52 needs no display server, so it also works over SSH. The value is
+/// printed as well, for terminals without OSC 52 support and piped output.
+fn copy_value(label: &str, value: &str) {
+    let mut stdout = std::io::stdout();
+    if stdout.is_terminal() {
+        let _ = write!(stdout, "\x1b]52;c;{}\x07", BASE64.encode(value));
+        let _ = stdout.flush();
+        display::success(&format!("Copied {} to clipboard", label));
+    } else {
+        display::info(&format!(
+            "Copy {}: no terminal to copy through, value printed below",
+            label
+        ));
+    }
+    println!("  {}: {}", label, value);
+}
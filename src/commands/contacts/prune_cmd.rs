@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Result;
+use dialoguer::Confirm;
+use vauchi_core::Contact;
+
+use super::trash_cmd;
+use crate::commands::common::{
+    drain_activity_log, open_vauchi, register_activity_log_handler, require_interactive,
+};
+use crate::config::CliConfig;
+use crate::display;
+
+/// Removes unverified contacts exchanged more than `older_than_secs` ago.
+///
+/// Recovery-trusted and favorite contacts are always kept. Pruned
+/// contacts go to the trash like `contacts remove`. Asks for confirmation
+/// unless `yes` is set.
+pub fn prune_unverified(config: &CliConfig, older_than_secs: u64, yes: bool) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let now = wb.clock().unix_seconds();
+
+    let stale: Vec<Contact> = wb
+        .list_contacts()?
+        .into_iter()
+        .filter(|c| is_prunable(c, now, older_than_secs))
+        .collect();
+
+    if stale.is_empty() {
+        display::info("No unverified contacts to prune");
+        return Ok(());
+    }
+
+    println!();
+    println!("  Unverified contacts to remove ({}):", stale.len());
+    for contact in &stale {
+        println!("    {}", contact.display_name());
+    }
+    println!();
+
+    if !yes {
+        require_interactive(Some("--yes"))?;
+        let confirmed = Confirm::new()
+            .with_prompt(format!("Remove {} contact(s)?", stale.len()))
+            .default(false)
+            .interact()?;
+        if !confirmed {
+            display::info("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let event_rx = register_activity_log_handler(&wb);
+    let mut removed = 0;
+    for contact in &stale {
+        if trash_cmd::move_to_trash(config, &wb, contact)? {
+            removed += 1;
+        }
+    }
+    drain_activity_log(&wb, event_rx);
+
+    display::success(&format!("Removed {} unverified contact(s)", removed));
+
+    Ok(())
+}
+
+/// True for an unverified contact, not trusted for recovery or marked
+/// favorite, exchanged more than `older_than_secs` before `now`.
+fn is_prunable(contact: &Contact, now: u64, older_than_secs: u64) -> bool {
+    !contact.is_fingerprint_verified()
+        && !contact.is_recovery_trusted()
+        && !contact.is_favorite()
+        && now.saturating_sub(contact.exchange_timestamp()) >= older_than_secs
+}
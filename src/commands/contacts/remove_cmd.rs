@@ -4,23 +4,58 @@
 
 use anyhow::Result;
 
+use super::trash_cmd;
 use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
 use crate::config::CliConfig;
 use crate::display;
 
 /// Removes a contact.
-pub fn remove(config: &CliConfig, id: &str) -> Result<()> {
+///
+/// The contact is moved to the trash, from which `contacts restore` can
+/// bring it back for a while. With `dry_run`, only reports the contact
+/// and the labels it would leave.
+pub fn remove(config: &CliConfig, id: &str, dry_run: bool) -> Result<()> {
     let wb = open_vauchi(config)?;
-    let event_rx = register_activity_log_handler(&wb);
 
-    // Get contact name before removing
-    let contact = wb.get_contact(id)?;
-    let name = contact.as_ref().map(|c| c.display_name().to_string());
+    if dry_run {
+        let Some(contact) = wb.get_contact(id)? else {
+            display::warning(&format!("Contact '{}' not found", id));
+            return Ok(());
+        };
+
+        let labels: Vec<String> = wb
+            .storage()
+            .labels()
+            .load_all_groups()?
+            .iter()
+            .filter(|l| l.contacts().iter().any(|c| c == contact.id()))
+            .map(|l| l.name().to_string())
+            .collect();
+
+        display::info(&format!(
+            "Dry run: would remove contact {} ({})",
+            contact.display_name(),
+            contact.id()
+        ));
+        if !labels.is_empty() {
+            println!("  Label memberships affected: {}", labels.join(", "));
+        }
+        return Ok(());
+    }
+
+    let Some(contact) = wb.get_contact(id)? else {
+        display::warning(&format!("Contact '{}' not found", id));
+        return Ok(());
+    };
+
+    let event_rx = register_activity_log_handler(&wb);
 
-    if wb.remove_contact(id)? {
-        display::success(&format!(
-            "Removed contact: {}",
-            name.unwrap_or_else(|| id.to_string())
+    if trash_cmd::move_to_trash(config, &wb, &contact)? {
+        display::success(&format!("Removed contact: {}", contact.display_name()));
+        display::info(&format!(
+            "Restore it within {} days with 'vauchi contacts restore {}'",
+            trash_cmd::TRASH_RETENTION_SECS / 86400,
+            contact.id()
         ));
     } else {
         display::warning(&format!("Contact '{}' not found", id));
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Result;
+
+use super::find_contact;
+use crate::commands::common::open_vauchi;
+use crate::config::CliConfig;
+use crate::display;
+
+/// Queues the full current card for a single contact.
+///
+/// Uses the same path as the initial card sent after an exchange, so the
+/// contact receives every visible field regardless of which earlier
+/// updates it missed. Delivery happens on the next sync.
+pub fn resend_card(config: &CliConfig, id: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let contact = find_contact(&wb, id)?;
+    let name = contact.display_name().to_string();
+
+    wb.queue_initial_card_for_contact(contact.id())
+        .map_err(|e| anyhow::anyhow!("Could not queue card for {}: {}", name, e))?;
+
+    display::success(&format!("Queued your full card for {}", name));
+    display::info("Run 'vauchi sync' to send it.");
+
+    Ok(())
+}
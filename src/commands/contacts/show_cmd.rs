@@ -3,6 +3,8 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use anyhow::Result;
+use console::style;
+use vauchi_app::notification_types::ActivityLogEntry as AppActivityEntry;
 use vauchi_core::FieldVisibility;
 
 use super::find_contact;
@@ -11,7 +13,18 @@ use crate::config::CliConfig;
 use crate::display;
 
 /// Shows details for a specific contact (respects auth mode).
-pub fn show(config: &CliConfig, pin: Option<&str>, id: &str) -> Result<()> {
+///
+/// `raw_keys` adds the contact's public key, fingerprint and exchange time.
+///
+/// With `history`, the contact's entries from the activity log (exchange,
+/// received card updates, failures) are listed beneath the details.
+pub fn show(
+    config: &CliConfig,
+    pin: Option<&str>,
+    id: &str,
+    raw_keys: bool,
+    history: bool,
+) -> Result<()> {
     let wb = open_vauchi_authenticated(config, pin)?;
 
     let contact = wb.get_contact(id)?.or_else(|| {
@@ -22,10 +35,45 @@ pub fn show(config: &CliConfig, pin: Option<&str>, id: &str) -> Result<()> {
 
     match contact {
         Some(c) => {
+            let mut rows: Vec<_> = if history {
+                wb.activity_log_poll(0, crate::clock::unix_seconds())?
+                    .into_iter()
+                    .filter(|row| row.contact_id.as_deref() == Some(c.id()))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            rows.sort_by_key(|row| row.created_at);
+
             if config.raw {
-                crate::raw::print_json(&crate::raw::ContactJson::from(&c))?;
+                let mut json = serde_json::to_value(crate::raw::ContactJson::from(&c))?;
+                if raw_keys {
+                    json["keys"] = serde_json::json!({
+                        "public_key": c.public_key().map(hex::encode),
+                        "fingerprint": c.fingerprint().to_string(),
+                        "exchange_timestamp": c.exchange_timestamp(),
+                    });
+                }
+                if history {
+                    json["history"] = rows
+                        .iter()
+                        .map(|row| {
+                            serde_json::json!({
+                                "category": row.category,
+                                "created_at": row.created_at,
+                            })
+                        })
+                        .collect();
+                }
+                crate::raw::print_json(&json)?;
             } else {
                 display::display_contact_details(&c);
+                if raw_keys {
+                    display::display_contact_keys(&c);
+                }
+                if history {
+                    display::display_contact_history(&rows);
+                }
             }
         }
         None => {
@@ -99,3 +147,116 @@ pub fn show_visibility(config: &CliConfig, contact_id_or_name: &str, locale: &st
 
     Ok(())
 }
+
+/// Lists the card fields a contact currently shares with you.
+///
+/// Fields named in the contact's received card updates that are no longer
+/// on their card are listed as removed, with when the update arrived.
+pub fn show_shared(config: &CliConfig, contact_id_or_name: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let contact = find_contact(&wb, contact_id_or_name)?;
+    let fields = contact.card().fields();
+
+    let updates: Vec<(u64, Vec<String>)> = wb
+        .activity_log_poll(0, crate::clock::unix_seconds())?
+        .into_iter()
+        .filter(|row| row.contact_id.as_deref() == Some(contact.id()))
+        .filter_map(|row| match serde_json::from_str(&row.payload) {
+            Ok(AppActivityEntry::CardUpdateReceived { changed_fields, .. }) => {
+                Some((row.created_at, changed_fields))
+            }
+            _ => None,
+        })
+        .collect();
+    let current: Vec<&str> = fields.iter().map(|f| f.label()).collect();
+    let removed = removed_fields(&current, &updates);
+
+    if config.raw {
+        return crate::raw::print_json(&serde_json::json!({
+            "contact": contact.display_name(),
+            "shared": fields.iter().map(crate::raw::FieldJson::from).collect::<Vec<_>>(),
+            "removed": removed
+                .iter()
+                .map(|(label, at)| serde_json::json!({ "label": label, "removed_at": at }))
+                .collect::<Vec<_>>(),
+        }));
+    }
+
+    println!();
+    println!(
+        "{} shares {} field(s) with you:",
+        style(contact.display_name()).bold(),
+        fields.len()
+    );
+    for field in fields {
+        println!(
+            "  {:6} {:12} {}",
+            display::field_icon(field.field_type()),
+            field.label(),
+            field.value()
+        );
+    }
+
+    if !removed.is_empty() {
+        println!();
+        println!("No longer shared:");
+        for (label, at) in &removed {
+            let when = chrono::DateTime::from_timestamp(*at as i64, 0)
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "unknown".into());
+            println!(
+                "  {:12} {}",
+                label,
+                style(format!("removed by {}", when)).dim()
+            );
+        }
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Labels changed by `updates` that are not in `current`, each with the
+/// time of the latest update that touched it, oldest first.
+fn removed_fields(current: &[&str], updates: &[(u64, Vec<String>)]) -> Vec<(String, u64)> {
+    let mut latest: std::collections::BTreeMap<&str, u64> = std::collections::BTreeMap::new();
+    for (at, labels) in updates {
+        for label in labels {
+            if current.contains(&label.as_str()) {
+                continue;
+            }
+            let entry = latest.entry(label.as_str()).or_default();
+            *entry = (*entry).max(*at);
+        }
+    }
+
+    let mut removed: Vec<(String, u64)> = latest
+        .into_iter()
+        .map(|(label, at)| (label.to_string(), at))
+        .collect();
+    removed.sort_by_key(|(_, at)| *at);
+    removed
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_removed_fields_skips_current_labels() {
+        let updates = vec![
+            (10, vec!["Mobile".to_string(), "Work".to_string()]),
+            (20, vec!["Mobile".to_string()]),
+            (15, vec!["Fax".to_string()]),
+        ];
+
+        let removed = removed_fields(&["Work"], &updates);
+
+        assert_eq!(
+            removed,
+            vec![("Fax".to_string(), 15), ("Mobile".to_string(), 20)]
+        );
+    }
+}
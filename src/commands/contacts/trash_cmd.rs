@@ -0,0 +1,247 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Trash for removed contacts.
+//!
+//! `contacts remove` removes a contact from storage and keeps a snapshot
+//! of it here, ratchet included. The snapshot is encrypted with the
+//! storage key, so the trash file never holds key material in the clear.
+//! `contacts restore` adds the snapshot back with its keys intact.
+//! Snapshots older than the retention window are dropped on the next
+//! trash access or sync, and `contacts trash empty` drops them all at
+//! once.
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use vauchi_core::crypto::encryption::{decrypt, encrypt};
+use vauchi_core::{Contact, Vauchi};
+use zeroize::Zeroizing;
+
+use crate::commands::common::{load_json_state, open_vauchi, save_json_state};
+use crate::config::CliConfig;
+use crate::display;
+
+pub(crate) const TRASH_FILE: &str = ".contact_trash.json";
+
+/// How long a removed contact can be restored.
+pub(crate) const TRASH_RETENTION_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// A removed contact awaiting permanent removal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct TrashEntry {
+    contact_id: String,
+    display_name: String,
+    removed_at: u64,
+    /// The removed contact as it was in storage, encrypted with the
+    /// storage key (hex).
+    contact: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct Trash(Vec<TrashEntry>);
+
+impl Trash {
+    fn load(config: &CliConfig) -> Result<Self> {
+        load_json_state(config, TRASH_FILE, "contact trash")
+    }
+
+    fn save(&self, config: &CliConfig) -> Result<()> {
+        save_json_state(config, TRASH_FILE, self)
+    }
+
+    /// Drops the entries removed at least [`TRASH_RETENTION_SECS`] ago.
+    ///
+    /// Returns whether any entry was dropped.
+    fn drop_expired(&mut self, now: u64) -> bool {
+        let before = self.0.len();
+        self.0
+            .retain(|e| now.saturating_sub(e.removed_at) < TRASH_RETENTION_SECS);
+        self.0.len() != before
+    }
+
+    /// Finds an entry by contact ID, ID prefix, or name (case-insensitive).
+    fn position(&self, query: &str) -> Option<usize> {
+        let lower = query.to_lowercase();
+        self.0
+            .iter()
+            .position(|e| e.contact_id == query)
+            .or_else(|| self.0.iter().position(|e| e.contact_id.starts_with(query)))
+            .or_else(|| {
+                self.0
+                    .iter()
+                    .position(|e| e.display_name.to_lowercase().contains(&lower))
+            })
+    }
+}
+
+/// Serializes `contact` and encrypts it with the storage key.
+fn seal_snapshot(config: &CliConfig, contact: &Contact) -> Result<String> {
+    let plaintext = Zeroizing::new(serde_json::to_vec(contact)?);
+    let sealed = encrypt(&config.storage_key()?, &plaintext)
+        .map_err(|e| anyhow!("Failed to encrypt the trash entry: {e}"))?;
+    Ok(hex::encode(sealed))
+}
+
+/// Decrypts the contact snapshot of `entry`.
+fn open_snapshot(config: &CliConfig, entry: &TrashEntry) -> Result<Contact> {
+    let corrupt = || format!("Corrupt trash entry for '{}'", entry.display_name);
+    let sealed = hex::decode(&entry.contact).with_context(corrupt)?;
+    let plaintext = Zeroizing::new(
+        decrypt(&config.storage_key()?, &sealed).map_err(|e| anyhow!("{}: {e}", corrupt()))?,
+    );
+    serde_json::from_slice(&plaintext).with_context(corrupt)
+}
+
+/// Snapshots `contact` into the trash, then removes it from storage.
+///
+/// Returns false if storage no longer had the contact, in which case
+/// nothing is trashed. Callers drain the activity log themselves.
+pub(crate) fn move_to_trash(config: &CliConfig, wb: &Vauchi, contact: &Contact) -> Result<bool> {
+    let now = wb.clock().unix_seconds();
+    let mut trash = Trash::load(config)?;
+    trash.drop_expired(now);
+    trash.0.retain(|e| e.contact_id != contact.id());
+    trash.0.push(TrashEntry {
+        contact_id: contact.id().to_string(),
+        display_name: contact.display_name().to_string(),
+        removed_at: now,
+        contact: seal_snapshot(config, contact)?,
+    });
+    // Save the snapshot first so a failed removal never loses the contact.
+    trash.save(config)?;
+
+    if !wb.remove_contact(contact.id())? {
+        trash.0.pop();
+        trash.save(config)?;
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// Drops trash entries past the retention window.
+pub fn purge_expired_trash(config: &CliConfig, now: u64) -> Result<()> {
+    let mut trash = Trash::load(config)?;
+    if trash.drop_expired(now) {
+        trash.save(config)?;
+    }
+    Ok(())
+}
+
+/// Restores a removed contact from the trash.
+pub fn restore(config: &CliConfig, id: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let mut trash = Trash::load(config)?;
+    let purged = trash.drop_expired(wb.clock().unix_seconds());
+
+    let Some(index) = trash.position(id) else {
+        if purged {
+            trash.save(config)?;
+        }
+        bail!(
+            "Contact '{}' is not in the trash. Removed contacts can be restored for {} days.",
+            id,
+            TRASH_RETENTION_SECS / 86400
+        );
+    };
+
+    let entry = &trash.0[index];
+    if wb.get_contact(&entry.contact_id)?.is_some() {
+        bail!("Contact '{}' already exists", entry.display_name);
+    }
+    let contact = open_snapshot(config, entry)?;
+    wb.add_contact(contact)?;
+
+    let entry = trash.0.remove(index);
+    trash.save(config)?;
+    display::success(&format!("Restored contact: {}", entry.display_name));
+
+    Ok(())
+}
+
+/// Lists the contacts in the trash and when each is removed for good.
+pub fn list_trash(config: &CliConfig) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let now = wb.clock().unix_seconds();
+    let mut trash = Trash::load(config)?;
+    if trash.drop_expired(now) {
+        trash.save(config)?;
+    }
+
+    if trash.0.is_empty() {
+        display::info("The trash is empty.");
+        return Ok(());
+    }
+
+    println!();
+    for entry in &trash.0 {
+        let left = (entry.removed_at + TRASH_RETENTION_SECS).saturating_sub(now);
+        println!(
+            "  {}  {}  (restorable for {} more day(s))",
+            &entry.contact_id[..8.min(entry.contact_id.len())],
+            entry.display_name,
+            left.div_ceil(86400)
+        );
+    }
+    println!();
+    display::info("Use 'vauchi contacts restore <id>' to restore a contact.");
+
+    Ok(())
+}
+
+/// Permanently removes every contact in the trash.
+pub fn empty_trash(config: &CliConfig) -> Result<()> {
+    let trash = Trash::load(config)?;
+    if trash.0.is_empty() {
+        display::info("The trash is empty.");
+        return Ok(());
+    }
+
+    Trash::default().save(config)?;
+    display::success(&format!("Permanently removed {} contact(s)", trash.0.len()));
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, name: &str, removed_at: u64) -> TrashEntry {
+        TrashEntry {
+            contact_id: id.to_string(),
+            display_name: name.to_string(),
+            removed_at,
+            contact: String::new(),
+        }
+    }
+
+    // @internal
+    #[test]
+    fn test_drop_expired_keeps_recent_entries() {
+        let mut trash = Trash(vec![
+            entry("aaa", "Bob Jones", 0),
+            entry("bbb", "Carol White", 100),
+        ]);
+
+        assert!(trash.drop_expired(TRASH_RETENTION_SECS + 50));
+        assert_eq!(trash.0, [entry("bbb", "Carol White", 100)]);
+        assert!(!trash.drop_expired(TRASH_RETENTION_SECS + 50));
+    }
+
+    // @internal
+    #[test]
+    fn test_position_matches_id_prefix_and_name() {
+        let trash = Trash(vec![
+            entry("aaa111", "Bob Jones", 0),
+            entry("bbb222", "Carol White", 0),
+        ]);
+
+        assert_eq!(trash.position("bbb222"), Some(1));
+        assert_eq!(trash.position("aaa"), Some(0));
+        assert_eq!(trash.position("carol"), Some(1));
+        assert_eq!(trash.position("dave"), None);
+    }
+}
@@ -2,10 +2,13 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use anyhow::Result;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::Serialize;
 
 use super::find_contact;
-use crate::commands::common::open_vauchi;
+use crate::commands::common::{open_vauchi, run_batch};
 use crate::config::CliConfig;
 use crate::display;
 
@@ -41,3 +44,155 @@ pub fn verify(config: &CliConfig, id: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Verifies contacts listed in `file`, one `<contact> <fingerprint>` per line.
+///
+/// Separate the contact from the fingerprint with a tab to keep a grouped
+/// fingerprint such as `AB12 CD34` intact; without a tab, the fingerprint is
+/// the last word and must be written unspaced. A contact is marked verified
+/// only when its fingerprint matches; case, spaces and colons within the
+/// fingerprint are ignored. Blank lines and lines starting with `#` are
+/// skipped.
+pub fn verify_batch(config: &CliConfig, file: &Path) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let lines: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    let mut verified = 0;
+    let result = run_batch(&lines, false, |line| {
+        let (id, fingerprint) = parse_batch_line(line)?;
+        let contact = find_contact(&wb, id)?;
+        if !same_fingerprint(contact.fingerprint(), fingerprint) {
+            bail!(
+                "fingerprint does not match {}; not verified",
+                contact.display_name()
+            );
+        }
+        if !contact.is_fingerprint_verified() {
+            wb.verify_contact_fingerprint(contact.id())?;
+        }
+        display::success(&format!(
+            "Verified fingerprint for {}",
+            contact.display_name()
+        ));
+        verified += 1;
+        Ok(())
+    });
+
+    display::info(&format!(
+        "Verified {} of {} contact(s)",
+        verified,
+        lines.len()
+    ));
+    result
+}
+
+/// Splits a batch line into the contact and the fingerprint after it.
+///
+/// Splits on the first tab if there is one, otherwise on the last whitespace.
+fn parse_batch_line(line: &str) -> Result<(&str, &str)> {
+    line.split_once('\t')
+        .or_else(|| line.rsplit_once(char::is_whitespace))
+        .map(|(contact, fingerprint)| (contact.trim_end(), fingerprint.trim()))
+        .filter(|(contact, fingerprint)| !contact.is_empty() && !fingerprint.is_empty())
+        .ok_or_else(|| anyhow!("expected '<contact> <fingerprint>'"))
+}
+
+/// Compares fingerprints ignoring case and separators.
+fn same_fingerprint(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>()
+    };
+    let a = normalize(a);
+    !a.is_empty() && a == normalize(b)
+}
+
+/// Unverified contact entry for `--raw` output.
+#[derive(Serialize)]
+struct UnverifiedJson {
+    id: String,
+    display_name: String,
+    fingerprint: String,
+}
+
+/// Lists contacts whose fingerprints still need in-person verification.
+pub fn list_unverified(config: &CliConfig) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let unverified: Vec<_> = wb
+        .list_contacts()?
+        .into_iter()
+        .filter(|c| !c.is_fingerprint_verified())
+        .collect();
+
+    if config.raw {
+        let json: Vec<UnverifiedJson> = unverified
+            .iter()
+            .map(|c| UnverifiedJson {
+                id: c.id().to_string(),
+                display_name: c.display_name().to_string(),
+                fingerprint: c.fingerprint().to_string(),
+            })
+            .collect();
+        return crate::raw::print_json(&json);
+    }
+
+    if unverified.is_empty() {
+        display::success("All contacts are verified");
+        return Ok(());
+    }
+
+    println!();
+    println!("  Contacts to verify in person ({}):", unverified.len());
+    println!();
+    for contact in &unverified {
+        println!("  [ ] {}", contact.display_name());
+        println!("      {}", contact.fingerprint());
+    }
+    println!();
+    display::info("Run 'vauchi contacts verify <id>' after comparing fingerprints.");
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_parse_batch_line_keeps_spaces_in_name() {
+        assert_eq!(
+            parse_batch_line("Bob Jones  ab12cd").unwrap(),
+            ("Bob Jones", "ab12cd")
+        );
+        assert!(parse_batch_line("ab12cd").is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_parse_batch_line_tab_keeps_grouped_fingerprint() {
+        assert_eq!(
+            parse_batch_line("Bob Jones\tAB12 CD34").unwrap(),
+            ("Bob Jones", "AB12 CD34")
+        );
+        assert!(parse_batch_line("\tAB12 CD34").is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_same_fingerprint_ignores_case_and_separators() {
+        assert!(same_fingerprint("AB12 CD34", "ab:12:cd:34"));
+        assert!(!same_fingerprint("AB12 CD34", "ab12cd35"));
+        assert!(!same_fingerprint("", ""));
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::find_contact;
+use crate::commands::common::{load_json_state, open_vauchi, save_json_state};
+use crate::config::CliConfig;
+use crate::display;
+
+/// Local file listing watched contact IDs; core has no watch flag.
+pub(crate) const WATCH_FILE: &str = ".watched_contacts.json";
+
+/// Contacts whose card updates `sync` calls out.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct WatchList(BTreeSet<String>);
+
+impl WatchList {
+    pub(crate) fn load(config: &CliConfig) -> Result<Self> {
+        load_json_state(config, WATCH_FILE, "watched contacts file")
+    }
+
+    fn save(&self, config: &CliConfig) -> Result<()> {
+        save_json_state(config, WATCH_FILE, self)
+    }
+
+    pub(crate) fn contains(&self, contact_id: &str) -> bool {
+        self.0.contains(contact_id)
+    }
+
+    /// Returns the watched contacts among activity rows, given as
+    /// `(category, contact_id)`, that received a card update.
+    ///
+    /// Each contact is listed once, in the order first seen.
+    pub(crate) fn updated<'a>(
+        &self,
+        rows: impl IntoIterator<Item = (&'a str, Option<&'a str>)>,
+    ) -> Vec<&'a str> {
+        let mut updated = Vec::new();
+        for (category, contact_id) in rows {
+            if category == "card_update_received"
+                && let Some(id) = contact_id
+                && self.contains(id)
+                && !updated.contains(&id)
+            {
+                updated.push(id);
+            }
+        }
+        updated
+    }
+}
+
+/// Watches a contact so `sync` calls out their card updates.
+pub fn watch(config: &CliConfig, id: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let contact = find_contact(&wb, id)?;
+
+    let mut list = WatchList::load(config)?;
+    if !list.0.insert(contact.id().to_string()) {
+        display::info(&format!("Already watching {}", contact.display_name()));
+        return Ok(());
+    }
+    list.save(config)?;
+    display::success(&format!(
+        "Watching {}. 'vauchi sync' will call out their card updates.",
+        contact.display_name()
+    ));
+
+    Ok(())
+}
+
+/// Stops watching a contact.
+pub fn unwatch(config: &CliConfig, id: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let contact = find_contact(&wb, id)?;
+
+    let mut list = WatchList::load(config)?;
+    if !list.0.remove(contact.id()) {
+        display::info(&format!("Not watching {}", contact.display_name()));
+        return Ok(());
+    }
+    list.save(config)?;
+    display::success(&format!("Stopped watching {}", contact.display_name()));
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_update_from_watched_contact_is_reported() {
+        let list = WatchList(BTreeSet::from(["bob".to_string()]));
+        let rows = [
+            ("card_update_received", Some("carol")),
+            ("contact_added", Some("bob")),
+            ("card_update_received", Some("bob")),
+            ("card_update_received", Some("bob")),
+            ("own_card_updated", None),
+        ];
+
+        assert_eq!(list.updated(rows), ["bob"]);
+        assert!(WatchList::default().updated(rows).is_empty());
+    }
+}
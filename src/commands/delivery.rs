@@ -7,7 +7,7 @@
 //! Provides CLI access to delivery status, retry processing, cleanup,
 //! and human-readable error translation.
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 
 use crate::config::CliConfig;
 use crate::display;
@@ -87,8 +87,60 @@ pub fn status(config: &CliConfig) -> Result<()> {
     Ok(())
 }
 
+/// Share of the offline queue capacity, in percent, from which queued
+/// updates are warned about.
+const QUEUE_WARNING_PERCENT: usize = 90;
+
+/// Returns a warning when the updates waiting for delivery approach the
+/// offline queue capacity, past which updates could be dropped.
+///
+/// The capacity is `--offline-queue-limit` when given, else the one the
+/// connectivity diagnostics report. Best-effort: the update this follows
+/// already succeeded, so if the queue cannot be inspected, no warning is
+/// returned.
+pub(crate) fn offline_queue_warning(
+    config: &CliConfig,
+    wb: &vauchi_core::Vauchi,
+) -> Option<String> {
+    let depth = wb
+        .storage()
+        .deliveries()
+        .count_deliveries_by_status(&vauchi_core::storage::DeliveryStatus::Queued)
+        .ok()?;
+    let limit = match config.offline_queue_limit {
+        Some(limit) => limit as usize,
+        None => {
+            let report = vauchi_core::network::ConnectivityDiagnostics::new()
+                .run()
+                .ok()?;
+            usize::try_from(report.offline_queue_capacity).unwrap_or(0)
+        }
+    };
+    queue_capacity_warning(depth, limit)
+}
+
+fn queue_capacity_warning(depth: usize, limit: usize) -> Option<String> {
+    if limit == 0 {
+        None
+    } else if depth >= limit {
+        Some(format!(
+            "Offline queue is full ({}/{}); further updates may be dropped. Run 'vauchi sync' to deliver them.",
+            depth, limit
+        ))
+    } else if depth * 100 >= limit * QUEUE_WARNING_PERCENT {
+        Some(format!(
+            "Offline queue is nearly full ({}/{}). Run 'vauchi sync' to deliver queued updates.",
+            depth, limit
+        ))
+    } else {
+        None
+    }
+}
+
 /// Lists delivery records, optionally filtered by status.
-pub fn list(config: &CliConfig, filter: Option<&str>) -> Result<()> {
+///
+/// With `count_only`, prints just the number of matching records.
+pub fn list(config: &CliConfig, filter: Option<&str>, count_only: bool) -> Result<()> {
     let wb = open_vauchi(config)?;
     let storage = wb.storage();
 
@@ -102,6 +154,11 @@ pub fn list(config: &CliConfig, filter: Option<&str>) -> Result<()> {
         _ => storage.deliveries().get_all_delivery_records()?,
     };
 
+    if count_only {
+        println!("{}", records.len());
+        return Ok(());
+    }
+
     if records.is_empty() {
         display::info("No delivery records found.");
         return Ok(());
@@ -122,6 +179,150 @@ pub fn list(config: &CliConfig, filter: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Failed share of a recipient's deliveries at or above which it is flagged.
+const HIGH_FAILURE_RATIO: f64 = 0.5;
+
+/// Delivery record counts by status.
+#[derive(Debug, Default, PartialEq, Eq, serde::Serialize)]
+struct StatusCounts {
+    queued: usize,
+    sent: usize,
+    stored: usize,
+    delivered: usize,
+    expired: usize,
+    failed: usize,
+    total: usize,
+}
+
+impl StatusCounts {
+    fn add(&mut self, status: &vauchi_core::storage::DeliveryStatus) {
+        use vauchi_core::storage::DeliveryStatus;
+
+        match status {
+            DeliveryStatus::Queued => self.queued += 1,
+            DeliveryStatus::Sent => self.sent += 1,
+            DeliveryStatus::Stored => self.stored += 1,
+            DeliveryStatus::Delivered => self.delivered += 1,
+            DeliveryStatus::Expired => self.expired += 1,
+            DeliveryStatus::Failed { .. } => self.failed += 1,
+            _ => {}
+        }
+        self.total += 1;
+    }
+
+    fn failed_ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.failed as f64 / self.total as f64
+        }
+    }
+}
+
+/// Groups delivery statuses by recipient ID, busiest recipients first.
+fn aggregate_by_recipient<'a>(
+    records: impl IntoIterator<Item = (&'a str, &'a vauchi_core::storage::DeliveryStatus)>,
+) -> Vec<(String, StatusCounts)> {
+    let mut groups: std::collections::BTreeMap<String, StatusCounts> =
+        std::collections::BTreeMap::new();
+    for (recipient_id, status) in records {
+        groups
+            .entry(recipient_id.to_string())
+            .or_default()
+            .add(status);
+    }
+
+    let mut rows: Vec<_> = groups.into_iter().collect();
+    rows.sort_by(|a, b| b.1.total.cmp(&a.1.total));
+    rows
+}
+
+/// Shows delivery record counts, optionally broken down per recipient.
+pub fn stats(config: &CliConfig, by_recipient: bool) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let records = wb.storage().deliveries().get_all_delivery_records()?;
+
+    if !by_recipient {
+        let mut counts = StatusCounts::default();
+        for record in &records {
+            counts.add(&record.status);
+        }
+        if config.raw {
+            return crate::raw::print_json(&counts);
+        }
+        display::info(&format!("{} delivery record(s)", counts.total));
+        println!();
+        print_counts("  ", &counts);
+        return Ok(());
+    }
+
+    let rows = aggregate_by_recipient(records.iter().map(|r| (r.recipient_id.as_str(), &r.status)));
+
+    let named: Vec<(String, String, StatusCounts)> = rows
+        .into_iter()
+        .map(|(id, counts)| {
+            let name = wb
+                .get_contact(&id)
+                .ok()
+                .flatten()
+                .map(|c| c.display_name().to_string())
+                .unwrap_or_else(|| format!("(unknown {})", &id[..8.min(id.len())]));
+            (id, name, counts)
+        })
+        .collect();
+
+    if config.raw {
+        #[derive(serde::Serialize)]
+        struct RecipientStatsJson<'a> {
+            recipient_id: &'a str,
+            display_name: &'a str,
+            #[serde(flatten)]
+            counts: &'a StatusCounts,
+            high_failure: bool,
+        }
+        let json: Vec<_> = named
+            .iter()
+            .map(|(id, name, counts)| RecipientStatsJson {
+                recipient_id: id,
+                display_name: name,
+                counts,
+                high_failure: counts.failed_ratio() >= HIGH_FAILURE_RATIO,
+            })
+            .collect();
+        return crate::raw::print_json(&json);
+    }
+
+    if named.is_empty() {
+        display::info("No delivery records found.");
+        return Ok(());
+    }
+
+    display::info(&format!("Deliveries for {} recipient(s):", named.len()));
+    for (_, name, counts) in &named {
+        println!();
+        println!("  {} ({} total)", name, counts.total);
+        print_counts("    ", counts);
+        if counts.failed_ratio() >= HIGH_FAILURE_RATIO {
+            display::warning(&format!(
+                "{:.0}% of deliveries to {} failed",
+                counts.failed_ratio() * 100.0,
+                name
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn print_counts(indent: &str, counts: &StatusCounts) {
+    println!("{}Queued:     {}", indent, counts.queued);
+    println!("{}Sent:       {}", indent, counts.sent);
+    println!("{}Stored:     {}", indent, counts.stored);
+    println!("{}Delivered:  {}", indent, counts.delivered);
+    println!("{}Expired:    {}", indent, counts.expired);
+    println!("{}Failed:     {}", indent, counts.failed);
+}
+
 /// Runs the retry scheduler tick, processing due retries.
 pub fn retry(config: &CliConfig) -> Result<()> {
     let wb = open_vauchi(config)?;
@@ -165,6 +366,35 @@ pub fn cleanup(config: &CliConfig) -> Result<()> {
     Ok(())
 }
 
+/// Drops the records of updates the recipient acknowledged from the
+/// delivery history.
+///
+/// Only the history shown by `delivery list` shrinks; pending updates are
+/// not touched. Unlike [`cleanup`], nothing is expired: records still
+/// waiting for an ACK are left alone. Returns how many records were removed.
+pub(crate) fn trim_acknowledged(wb: &vauchi_core::Vauchi) -> Result<usize> {
+    let deliveries = wb.storage().deliveries();
+    let records = deliveries.get_all_delivery_records()?;
+    let mut removed = 0;
+    for message_id in acknowledged_ids(records.iter().map(|r| (r.message_id.as_str(), &r.status))) {
+        if deliveries.delete_delivery_record(message_id)? {
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Message IDs of records delivered and acknowledged by the recipient.
+fn acknowledged_ids<'a>(
+    records: impl IntoIterator<Item = (&'a str, &'a vauchi_core::storage::DeliveryStatus)>,
+) -> Vec<&'a str> {
+    records
+        .into_iter()
+        .filter(|(_, status)| matches!(status, vauchi_core::storage::DeliveryStatus::Delivered))
+        .map(|(message_id, _)| message_id)
+        .collect()
+}
+
 /// Translates a failure reason code to a user-friendly message.
 pub fn translate(reason: &str) -> Result<()> {
     let message = vauchi_core::network::failure_to_user_message(reason);
@@ -172,6 +402,123 @@ pub fn translate(reason: &str) -> Result<()> {
     Ok(())
 }
 
+/// Explains one delivery record: where it is, who it is for, and why.
+///
+/// `message_id` may be the full ID or the unique prefix shown by
+/// `delivery list`.
+pub fn explain(config: &CliConfig, message_id: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let records = wb.storage().deliveries().get_all_delivery_records()?;
+    let record = find_by_id(&records, message_id, |r| r.message_id.as_str())?;
+
+    let recipient = wb
+        .get_contact(&record.recipient_id)?
+        .map(|c| c.display_name().to_string());
+    let explanation = explain_status(&record.status);
+
+    // The retry queue is shared, so its next run applies to any message
+    // still waiting to go out.
+    let next_retry_at = match record.status {
+        vauchi_core::storage::DeliveryStatus::Queued
+        | vauchi_core::storage::DeliveryStatus::Sent
+        | vauchi_core::storage::DeliveryStatus::Failed { .. } => {
+            vauchi_core::network::ConnectivityDiagnostics::new()
+                .run()
+                .ok()
+                .map(|report| report.next_retry_at)
+                .filter(|at| !at.is_empty())
+        }
+        _ => None,
+    };
+
+    if config.raw {
+        #[derive(serde::Serialize)]
+        struct DeliveryExplainJson<'a> {
+            message_id: &'a str,
+            recipient_id: &'a str,
+            recipient_name: Option<&'a str>,
+            status: String,
+            explanation: &'a str,
+            next_retry_at: Option<&'a str>,
+        }
+        return crate::raw::print_json(&DeliveryExplainJson {
+            message_id: &record.message_id,
+            recipient_id: &record.recipient_id,
+            recipient_name: recipient.as_deref(),
+            status: format_delivery_status(&record.status),
+            explanation: &explanation,
+            next_retry_at: next_retry_at.as_deref(),
+        });
+    }
+
+    display::info(&format!("Delivery {}", record.message_id));
+    println!();
+    println!("  Status:     {}", format_delivery_status(&record.status));
+    match &recipient {
+        Some(name) => println!("  Recipient:  {} ({})", name, record.recipient_id),
+        None => println!(
+            "  Recipient:  {} (not in your contacts; it may have been removed)",
+            record.recipient_id
+        ),
+    }
+    if let Some(at) = &next_retry_at {
+        println!("  Next retry: {}", at);
+    }
+    println!();
+    println!("  {}", explanation);
+
+    Ok(())
+}
+
+/// Finds the item whose ID equals `id` or, failing that, starts with it.
+fn find_by_id<'a, T>(items: &'a [T], id: &str, key: impl Fn(&T) -> &str) -> Result<&'a T> {
+    let id = id.trim();
+    if id.is_empty() {
+        bail!("Message ID cannot be empty");
+    }
+    if let Some(item) = items.iter().find(|item| key(item) == id) {
+        return Ok(item);
+    }
+
+    let mut matches = items.iter().filter(|item| key(item).starts_with(id));
+    match (matches.next(), matches.next()) {
+        (Some(item), None) => Ok(item),
+        (Some(_), Some(_)) => bail!(
+            "Message ID '{}' is ambiguous; use more characters from 'vauchi delivery list'",
+            id
+        ),
+        (None, _) => bail!(
+            "No delivery record with ID '{}'. See 'vauchi delivery list'.",
+            id
+        ),
+    }
+}
+
+/// Describes what a delivery status means for the sender.
+fn explain_status(status: &vauchi_core::storage::DeliveryStatus) -> String {
+    use vauchi_core::storage::DeliveryStatus;
+
+    match status {
+        DeliveryStatus::Queued => {
+            "Waiting to be sent. It goes out with the next 'vauchi sync'.".to_string()
+        }
+        DeliveryStatus::Sent => {
+            "Sent to the relay, which has not yet confirmed storing it.".to_string()
+        }
+        DeliveryStatus::Stored => {
+            "Stored on the relay until the recipient's device picks it up.".to_string()
+        }
+        DeliveryStatus::Delivered => "Delivered to the recipient.".to_string(),
+        DeliveryStatus::Expired => {
+            "Expired before the recipient picked it up; it will not be retried.".to_string()
+        }
+        DeliveryStatus::Failed { reason } => {
+            vauchi_core::network::failure_to_user_message(reason).to_string()
+        }
+        _ => "Unknown delivery status.".to_string(),
+    }
+}
+
 /// Formats a DeliveryStatus for display.
 fn format_delivery_status(status: &vauchi_core::storage::DeliveryStatus) -> String {
     match status {
@@ -201,6 +548,7 @@ mod tests {
             relay_url: "wss://test.example.com".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         // Initialize identity so open_vauchi works
@@ -229,7 +577,7 @@ mod tests {
     #[test]
     fn test_list_empty_shows_no_records() {
         let (_dir, config) = setup_test_config();
-        let result = list(&config, None);
+        let result = list(&config, None, false);
         assert!(
             result.is_ok(),
             "List command should succeed: {:?}",
@@ -241,7 +589,7 @@ mod tests {
     #[test]
     fn test_list_with_failed_filter() {
         let (_dir, config) = setup_test_config();
-        let result = list(&config, Some("failed"));
+        let result = list(&config, Some("failed"), false);
         assert!(
             result.is_ok(),
             "List with filter should succeed: {:?}",
@@ -284,6 +632,46 @@ mod tests {
         );
     }
 
+    // @internal
+    #[test]
+    fn test_explain_unknown_id_errors() {
+        let (_dir, config) = setup_test_config();
+        let err = explain(&config, "deadbeef").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("No delivery record with ID 'deadbeef'")
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_find_by_id_matches_full_id_or_unique_prefix() {
+        let ids: Vec<String> = ["abcd1234", "abcd5678", "ef001122"]
+            .map(String::from)
+            .to_vec();
+        let key = String::as_str;
+
+        assert_eq!(find_by_id(&ids, "abcd5678", key).unwrap(), "abcd5678");
+        assert_eq!(find_by_id(&ids, "ef", key).unwrap(), "ef001122");
+        let err = find_by_id(&ids, "abcd", key).unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+        assert!(find_by_id(&ids, "99", key).is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_explain_status_uses_failure_message() {
+        let reason = "connection_timeout";
+        let status = vauchi_core::storage::DeliveryStatus::Failed {
+            reason: reason.to_string(),
+        };
+        assert_eq!(
+            explain_status(&status),
+            vauchi_core::network::failure_to_user_message(reason).to_string()
+        );
+        assert!(explain_status(&vauchi_core::storage::DeliveryStatus::Stored).contains("relay"));
+    }
+
     // @scenario: message_delivery:Delivery status formatting
     #[test]
     fn test_format_delivery_status_queued() {
@@ -306,6 +694,73 @@ mod tests {
         assert_eq!(format_delivery_status(&status), "delivered");
     }
 
+    // @internal
+    #[test]
+    fn test_acknowledged_ids_selects_only_delivered_records() {
+        use vauchi_core::storage::DeliveryStatus;
+
+        let failed = DeliveryStatus::Failed {
+            reason: "connection_timeout".to_string(),
+        };
+        let records = [
+            ("acked", &DeliveryStatus::Delivered),
+            ("queued", &DeliveryStatus::Queued),
+            ("stored", &DeliveryStatus::Stored),
+            ("failed", &failed),
+            ("expired", &DeliveryStatus::Expired),
+        ];
+
+        assert_eq!(acknowledged_ids(records), ["acked"]);
+    }
+
+    // @internal
+    #[test]
+    fn test_stats_by_recipient_groups_rows() {
+        use vauchi_core::storage::DeliveryStatus;
+
+        let failed = DeliveryStatus::Failed {
+            reason: "connection_timeout".to_string(),
+        };
+        let records = [
+            ("alice", &DeliveryStatus::Delivered),
+            ("bob", &failed),
+            ("alice", &DeliveryStatus::Queued),
+            ("bob", &failed),
+            ("alice", &failed),
+        ];
+
+        let rows = aggregate_by_recipient(records);
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, "alice");
+        assert_eq!(rows[0].1.total, 3);
+        assert_eq!(rows[0].1.delivered, 1);
+        assert_eq!(rows[1].0, "bob");
+        assert_eq!(rows[1].1.failed, 2);
+        assert!(rows[1].1.failed_ratio() >= HIGH_FAILURE_RATIO);
+        assert!(rows[0].1.failed_ratio() < HIGH_FAILURE_RATIO);
+    }
+
+    // @internal
+    #[test]
+    fn test_stats_by_recipient_with_no_records() {
+        let (_dir, config) = setup_test_config();
+        assert!(stats(&config, true).is_ok());
+    }
+
+    // @internal
+    #[test]
+    fn test_queue_capacity_warning_past_low_limit() {
+        assert_eq!(queue_capacity_warning(0, 2), None);
+        assert_eq!(queue_capacity_warning(8, 10), None);
+        assert!(
+            queue_capacity_warning(9, 10)
+                .unwrap()
+                .contains("nearly full (9/10)")
+        );
+        assert!(queue_capacity_warning(3, 2).unwrap().contains("full (3/2)"));
+    }
+
     // @scenario: message_delivery:ConnectivityDiagnostics report is accessible
     // @scenario: message_delivery.feature:Debug connectivity issues
     #[test]
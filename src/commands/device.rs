@@ -7,10 +7,13 @@
 //! Multi-device linking and management.
 
 use std::fs;
+use std::path::Path;
 
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use clap::ValueEnum;
 use dialoguer::{Confirm, Input};
+use serde::{Deserialize, Serialize};
 use vauchi_core::DeviceSyncOrchestrator;
 use vauchi_core::exchange::{
     DeviceLinkQR, DeviceLinkResponder, DeviceLinkResponse, ProximityProof, compute_confirmation_mac,
@@ -18,13 +21,64 @@ use vauchi_core::exchange::{
 use vauchi_core::sync::DeviceLinkIntent;
 use vauchi_core::{Vauchi, VauchiConfig};
 
-use crate::commands::common::open_vauchi;
+use crate::commands::common::{
+    atomic_write_restricted, load_json_state, open_vauchi, save_json_state,
+};
 use crate::commands::device_link_persistence::persist_updated_registry;
 use crate::config::CliConfig;
 use crate::display;
 
-/// Lists all linked devices.
-pub fn list(config: &CliConfig, locale: &str) -> Result<()> {
+/// How device-link blobs travel between the two devices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LinkTransport {
+    /// Shown on screen as a QR code and copy-paste text.
+    Qr,
+    /// Written to and read from a file, for air-gapped linking.
+    File,
+}
+
+/// Returns the link blob given on the command line, or read from `file`.
+pub fn link_input(arg: Option<String>, file: Option<&Path>) -> Result<String> {
+    match (arg, file) {
+        (Some(data), None) => Ok(data),
+        (None, Some(path)) => Ok(fs::read_to_string(path)
+            .with_context(|| format!("Failed to read link data from {}", path.display()))?
+            .trim()
+            .to_string()),
+        (Some(_), Some(_)) => bail!("Pass the link data either inline or with --file, not both"),
+        (None, None) => bail!("Missing link data"),
+    }
+}
+
+/// Writes the next link blob to `file` for the other device to pick up.
+fn write_link_output(file: &Path, data: &str, next_step: &str) -> Result<()> {
+    atomic_write_restricted(file, data)?;
+    display::success(&format!("Link data written to {}", file.display()));
+    display::info(&format!(
+        "Carry the file to the other device and run: vauchi device {} --transport file --file <path>",
+        next_step
+    ));
+    Ok(())
+}
+
+/// Local audit log of device revocations (the core registry keeps no reasons).
+pub(crate) const REVOCATION_LOG_FILE: &str = ".device_revocations.json";
+
+/// One past device revocation, as recorded by `device revoke`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RevocationRecord {
+    device_id: String,
+    device_name: String,
+    revoked_at: u64,
+    reason: Option<String>,
+}
+
+/// Lists linked devices: the active ones by default, only the revoked ones
+/// with `revoked`, or both with `all`.
+///
+/// Revoked entries show when and why they were revoked, if `device revoke`
+/// recorded it.
+pub fn list(config: &CliConfig, revoked: bool, all: bool, locale: &str) -> Result<()> {
     let wb = open_vauchi(config)?;
 
     let identity = wb
@@ -50,7 +104,17 @@ pub fn list(config: &CliConfig, locale: &str) -> Result<()> {
             println!("{}", display::t("cli.cmd.device.linked_devices", locale));
             println!("{}", "─".repeat(50));
 
-            for (i, device) in registry.all_devices().iter().enumerate() {
+            let revocations = load_revocations(config)?;
+            let devices = select_devices(registry.all_devices(), |d| d.is_active(), revoked, all);
+            if devices.is_empty() {
+                display::info(if revoked {
+                    "No revoked devices."
+                } else {
+                    "No active devices."
+                });
+            }
+
+            for (i, device) in devices {
                 let status = if device.is_active() {
                     console::style("active").green()
                 } else {
@@ -71,6 +135,19 @@ pub fn list(config: &CliConfig, locale: &str) -> Result<()> {
                     current
                 );
                 println!("     ID: {}...", hex::encode(&device.device_id[..8]));
+                if !device.is_active() {
+                    let device_id = hex::encode(&device.device_id);
+                    if let Some(record) = revocations.iter().rfind(|r| r.device_id == device_id) {
+                        let time = chrono::DateTime::from_timestamp(record.revoked_at as i64, 0)
+                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                            .unwrap_or_else(|| "unknown".into());
+                        println!(
+                            "     Revoked: {} ({})",
+                            time,
+                            record.reason.as_deref().unwrap_or("no reason given")
+                        );
+                    }
+                }
             }
             println!("{}", "─".repeat(50));
             println!(
@@ -91,7 +168,9 @@ pub fn list(config: &CliConfig, locale: &str) -> Result<()> {
 }
 
 /// Generates a QR code for linking a new device.
-pub fn link(config: &CliConfig) -> Result<()> {
+///
+/// With a `file`, the link data is written there instead of shown.
+pub fn link(config: &CliConfig, file: Option<&Path>) -> Result<()> {
     let wb = open_vauchi(config)?;
 
     let identity = wb
@@ -104,21 +183,26 @@ pub fn link(config: &CliConfig) -> Result<()> {
         .load_device_registry()?
         .unwrap_or_else(|| identity.initial_device_registry());
 
-    display::info("Generating device link QR code...");
-    println!();
-
     let initiator =
         identity.create_device_link_initiator(registry, crate::clock::shared().unix_seconds());
     let qr = initiator.qr();
 
-    println!("{}", qr.to_qr_image_string());
-    println!();
-
     let data_string = qr.to_data_string();
     let pending_link_path = config.data_dir.join(".pending_device_link");
     fs::create_dir_all(&config.data_dir)?;
     crate::config::write_restricted(&pending_link_path, &data_string)?;
 
+    if let Some(file) = file {
+        write_link_output(file, &data_string, "join")?;
+        display::warning("This link data expires in 5 minutes.");
+        return Ok(());
+    }
+
+    display::info("Generating device link QR code...");
+    println!();
+    println!("{}", qr.to_qr_image_string());
+    println!();
+
     display::info("Device link data (for testing):");
     println!("  {}", data_string);
     println!();
@@ -138,6 +222,7 @@ pub fn join(
     qr_data: &str,
     device_name_arg: Option<&str>,
     yes: bool,
+    file: Option<&Path>,
 ) -> Result<()> {
     if config.is_initialized() {
         display::warning("Vauchi is already initialized on this device.");
@@ -181,6 +266,16 @@ pub fn join(
 
     let request_b64 = BASE64.encode(&encrypted_request);
 
+    let link_key_path = config.data_dir.join(".pending_link_key");
+    let device_name_path = config.data_dir.join(".pending_device_name");
+    fs::create_dir_all(&config.data_dir)?;
+    crate::config::write_restricted(&link_key_path, qr_data)?;
+    crate::config::write_restricted(&device_name_path, &device_name)?;
+
+    if let Some(file) = file {
+        return write_link_output(file, &request_b64, "complete");
+    }
+
     display::info("Send this request to the existing device:");
     println!();
     println!("  {}", request_b64);
@@ -190,12 +285,6 @@ pub fn join(
     println!("  vauchi device complete {}", request_b64);
     println!();
 
-    let link_key_path = config.data_dir.join(".pending_link_key");
-    let device_name_path = config.data_dir.join(".pending_device_name");
-    fs::create_dir_all(&config.data_dir)?;
-    crate::config::write_restricted(&link_key_path, qr_data)?;
-    crate::config::write_restricted(&device_name_path, &device_name)?;
-
     display::info("After the existing device responds, run:");
     println!("  vauchi device finish <response_data>");
 
@@ -213,6 +302,7 @@ pub fn complete(
     request_data: &str,
     auto_confirm: bool,
     replace: bool,
+    file: Option<&Path>,
 ) -> Result<()> {
     let wb = open_vauchi(config)?;
 
@@ -302,14 +392,18 @@ pub fn complete(
     ));
     println!();
 
-    display::info("Send this response to the new device:");
-    println!();
-    println!("  {}", response_b64);
-    println!();
-
-    display::info("On the new device, run:");
-    println!("  vauchi device finish {}", response_b64);
-    println!();
+    if let Some(file) = file {
+        write_link_output(file, &response_b64, "finish")?;
+    } else {
+        display::info("Send this response to the new device:");
+        println!();
+        println!("  {}", response_b64);
+        println!();
+
+        display::info("On the new device, run:");
+        println!("  vauchi device finish {}", response_b64);
+        println!();
+    }
 
     let _ = fs::remove_file(&pending_link_path);
 
@@ -366,7 +460,15 @@ pub fn finish(config: &CliConfig, response_data: &str) -> Result<()> {
 }
 
 /// Revokes a device from the registry.
-pub fn revoke(config: &CliConfig, device_id_prefix: &str, auto_confirm: bool) -> Result<()> {
+///
+/// An optional `reason` (e.g. lost, sold, compromised) is recorded in the
+/// local revocation log shown by `device history`.
+pub fn revoke(
+    config: &CliConfig,
+    device_id_prefix: &str,
+    reason: Option<&str>,
+    auto_confirm: bool,
+) -> Result<()> {
     let wb = open_vauchi(config)?;
 
     let identity = wb
@@ -416,6 +518,16 @@ pub fn revoke(config: &CliConfig, device_id_prefix: &str, auto_confirm: bool) ->
         .ok_or_else(|| anyhow::anyhow!("Device disappeared from the registry"))?;
     wb.revoke_device(device_index)?;
 
+    append_revocation(
+        config,
+        RevocationRecord {
+            device_id: hex::encode(&device.device_id),
+            device_name: device.device_name.clone(),
+            revoked_at: crate::clock::unix_seconds(),
+            reason: reason.map(str::to_string),
+        },
+    )?;
+
     display::success(&format!(
         "Device '{}' has been revoked.",
         device.device_name
@@ -425,6 +537,68 @@ pub fn revoke(config: &CliConfig, device_id_prefix: &str, auto_confirm: bool) ->
     Ok(())
 }
 
+/// Lists past device revocations with timestamps and reasons.
+pub fn history(config: &CliConfig) -> Result<()> {
+    // Opened only to enforce the usual initialization check.
+    let _wb = open_vauchi(config)?;
+
+    let records = load_revocations(config)?;
+
+    if config.raw {
+        return crate::raw::print_json(&records);
+    }
+
+    if records.is_empty() {
+        display::info("No devices have been revoked.");
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", "─".repeat(50));
+    for record in &records {
+        let time = chrono::DateTime::from_timestamp(record.revoked_at as i64, 0)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "unknown".into());
+        println!("  {}  {}", time, record.device_name);
+        println!(
+            "     ID: {}...",
+            &record.device_id[..record.device_id.len().min(16)]
+        );
+        println!(
+            "     Reason: {}",
+            record.reason.as_deref().unwrap_or("(none given)")
+        );
+    }
+    println!("{}", "─".repeat(50));
+
+    Ok(())
+}
+
+/// Picks the devices `device list` shows, keeping their registry position
+/// for numbering: the active ones, only the revoked ones, or every device.
+fn select_devices<T>(
+    devices: &[T],
+    is_active: impl Fn(&T) -> bool,
+    revoked: bool,
+    all: bool,
+) -> Vec<(usize, &T)> {
+    devices
+        .iter()
+        .enumerate()
+        .filter(|(_, device)| all || is_active(device) != revoked)
+        .collect()
+}
+
+fn load_revocations(config: &CliConfig) -> Result<Vec<RevocationRecord>> {
+    load_json_state(config, REVOCATION_LOG_FILE, "device revocation log")
+}
+
+fn append_revocation(config: &CliConfig, record: RevocationRecord) -> Result<()> {
+    let mut records = load_revocations(config)?;
+    records.push(record);
+    save_json_state(config, REVOCATION_LOG_FILE, &records)
+}
+
 /// Decommissions this device after a replacement handover.
 pub fn decommission(config: &CliConfig, auto_confirm: bool) -> Result<()> {
     let wb = open_vauchi(config)?;
@@ -461,6 +635,9 @@ pub fn decommission(config: &CliConfig, auto_confirm: bool) -> Result<()> {
 }
 
 /// Shows device info for the current device.
+///
+/// With `--raw`, prints the full device ID, public ID, device name and
+/// fingerprint as one JSON object for scripts.
 pub fn info(config: &CliConfig) -> Result<()> {
     let wb = open_vauchi(config)?;
 
@@ -470,6 +647,15 @@ pub fn info(config: &CliConfig) -> Result<()> {
 
     let device_info = identity.device_info();
 
+    if config.raw {
+        return crate::raw::print_json(&serde_json::json!({
+            "device_id": hex::encode(device_info.device_id()),
+            "public_id": wb.public_id()?,
+            "device_name": device_info.device_name(),
+            "fingerprint": wb.own_fingerprint()?,
+        }));
+    }
+
     println!();
     println!("{}", "─".repeat(50));
     println!("  {}", console::style("Device Information").bold().cyan());
@@ -505,3 +691,75 @@ fn format_timestamp(ts: u64) -> String {
         "Unknown".to_string()
     }
 }
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config(dir: &tempfile::TempDir) -> CliConfig {
+        CliConfig {
+            data_dir: dir.path().to_path_buf(),
+            relay_url: "wss://test.example.com".to_string(),
+            ohttp_relay_url: None,
+            raw: false,
+            offline_queue_limit: None,
+        }
+    }
+
+    // @internal
+    #[test]
+    fn test_revocation_log_starts_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(load_revocations(&test_config(&dir)).unwrap().is_empty());
+    }
+
+    // @internal
+    #[test]
+    fn test_revocation_reason_is_kept_in_history() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let config = test_config(&dir);
+
+        append_revocation(
+            &config,
+            RevocationRecord {
+                device_id: "ab".repeat(32),
+                device_name: "Old Phone".to_string(),
+                revoked_at: 1_700_000_000,
+                reason: Some("lost on the train".to_string()),
+            },
+        )
+        .unwrap();
+        append_revocation(
+            &config,
+            RevocationRecord {
+                device_id: "cd".repeat(32),
+                device_name: "Tablet".to_string(),
+                revoked_at: 1_700_000_100,
+                reason: None,
+            },
+        )
+        .unwrap();
+
+        let records = load_revocations(&config).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].reason.as_deref(), Some("lost on the train"));
+        assert_eq!(records[1].device_name, "Tablet");
+    }
+
+    // @internal
+    #[test]
+    fn test_select_devices_filters_by_revocation() {
+        // (name, active) stands in for registry entries.
+        let registry = [("Phone", true), ("Old Phone", false), ("Laptop", true)];
+
+        let active = select_devices(&registry, |d| d.1, false, false);
+        assert_eq!(active, [(0, &("Phone", true)), (2, &("Laptop", true))]);
+
+        let revoked = select_devices(&registry, |d| d.1, true, false);
+        assert_eq!(revoked, [(1, &("Old Phone", false))]);
+
+        let all = select_devices(&registry, |d| d.1, false, true);
+        assert_eq!(all.len(), 3);
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Opt-in DNS reachability checks for email and website card fields.
+//!
+//! Uses the system resolver (A/AAAA via `ToSocketAddrs`). There is no DNS
+//! client dependency for MX queries, so an email domain counts as resolvable
+//! when it has an address record.
+
+use std::net::ToSocketAddrs;
+
+use vauchi_core::FieldType;
+
+/// Extracts the host to look up for a field, if the field type is checked.
+pub(crate) fn host_for(field_type: FieldType, value: &str) -> Option<String> {
+    let host = match field_type {
+        FieldType::Email => value.rsplit_once('@')?.1,
+        FieldType::Website => {
+            let rest = value.split_once("://").map_or(value, |(_, rest)| rest);
+            let authority = rest.split(['/', '?', '#']).next()?;
+            let authority = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+            authority.split(':').next()?
+        }
+        _ => return None,
+    };
+
+    let host = host.trim().trim_end_matches('.');
+    (!host.is_empty()).then(|| host.to_lowercase())
+}
+
+/// Returns whether `host` resolves to at least one address.
+pub(crate) fn resolves(host: &str) -> bool {
+    (host, 0)
+        .to_socket_addrs()
+        .map(|mut addrs| addrs.next().is_some())
+        .unwrap_or(false)
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_host_for_email_uses_domain() {
+        assert_eq!(
+            host_for(FieldType::Email, "alice@Example.COM"),
+            Some("example.com".to_string())
+        );
+        assert_eq!(host_for(FieldType::Email, "no-at-sign"), None);
+    }
+
+    // @internal
+    #[test]
+    fn test_host_for_website_strips_scheme_port_and_path() {
+        assert_eq!(
+            host_for(
+                FieldType::Website,
+                "https://user@alice.example.com:8443/about?x=1"
+            ),
+            Some("alice.example.com".to_string())
+        );
+        assert_eq!(
+            host_for(FieldType::Website, "alice.example.com"),
+            Some("alice.example.com".to_string())
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_host_for_skips_other_types() {
+        assert_eq!(host_for(FieldType::Phone, "+1-555-123-4567"), None);
+    }
+
+    // @internal
+    #[test]
+    fn test_invalid_tld_never_resolves() {
+        // RFC 6761 reserves `.invalid`; lookups fail even offline.
+        assert!(!resolves("vauchi-test.invalid"));
+    }
+}
@@ -0,0 +1,552 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Doctor Command
+//!
+//! Checks the data directory for damaged files and, with `--repair`,
+//! recovers the ones that can be reset without losing identity or contacts.
+//! With `--network`, checks the relay connection instead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use console::style;
+use serde::de::DeserializeOwned;
+use vauchi_core::FieldType;
+use vauchi_core::types::AhaMomentTracker;
+
+use crate::commands::common::open_vauchi;
+use crate::commands::contacts::{TRASH_FILE, Trash, WATCH_FILE, WatchList};
+use crate::commands::device::{REVOCATION_LOG_FILE, RevocationRecord};
+use crate::commands::dns_check;
+use crate::commands::field_expiry::{EXPIRY_FILE, ExpiryStore};
+use crate::commands::field_tags::{FIELD_TAGS_FILE, FieldTags};
+use crate::commands::gdpr::{DELETION_GRACE_FILE, DeletionGrace};
+use crate::commands::labels::{LABEL_RULES_FILE, LabelRules};
+use crate::commands::sync::LAST_SYNC_FILE;
+use crate::config::{self, CliConfig};
+use crate::display;
+
+/// Suffix for a damaged file moved aside by `--repair`.
+const QUARANTINE_SUFFIX: &str = "corrupt";
+
+/// JSON state files kept by commands in the data directory, what each
+/// holds, and whether its content is what the command loads.
+///
+/// `--repair` moves a damaged one aside; its command then starts afresh.
+const STATE_FILES: &[(&str, &str, fn(&str) -> bool)] = &[
+    (
+        REVOCATION_LOG_FILE,
+        "revocation log",
+        parses::<Vec<RevocationRecord>>,
+    ),
+    (LABEL_RULES_FILE, "label rules file", parses::<LabelRules>),
+    (EXPIRY_FILE, "field expiry file", parses::<ExpiryStore>),
+    (FIELD_TAGS_FILE, "field tags file", parses::<FieldTags>),
+    (TRASH_FILE, "contact trash", parses::<Trash>),
+    (WATCH_FILE, "watched contacts file", parses::<WatchList>),
+    (LAST_SYNC_FILE, "sync summary", parses::<serde_json::Value>),
+    (
+        DELETION_GRACE_FILE,
+        "deletion grace file",
+        parses::<DeletionGrace>,
+    ),
+];
+
+/// Handshake time above which `--network` warns that the relay is slow.
+const SLOW_HANDSHAKE: Duration = Duration::from_secs(2);
+
+/// How `--repair` can fix a problem.
+enum Repair {
+    /// Move the file aside and write this content in its place.
+    Reset(String),
+    /// Move the file aside and let the CLI start afresh without it.
+    Quarantine,
+}
+
+/// A problem found in the data directory.
+struct Problem {
+    path: PathBuf,
+    message: String,
+    repair: Option<Repair>,
+}
+
+/// Checks the data directory and reports problems.
+///
+/// Covers the identity file, key files, the storage database and the JSON
+/// state files. With `repair`, damaged state files are moved to
+/// `<file>.corrupt` and reset; identity, keys and the database are never
+/// touched.
+pub fn run(config: &CliConfig, repair: bool) -> Result<()> {
+    println!();
+    println!("  Data directory: {}", config.data_dir.display());
+    println!();
+
+    if !config.data_dir.is_dir() {
+        display::info("Data directory does not exist yet. Run 'vauchi init <name>' first.");
+        return Ok(());
+    }
+
+    let mut problems = Vec::new();
+    if config.is_initialized() {
+        check_identity(config, &mut problems);
+        check_key_files(&config.data_dir, &mut problems);
+        check_storage(config, &mut problems);
+    } else {
+        display::info("No identity found; skipping identity, key and storage checks.");
+    }
+    check_state_files(&config.data_dir, &mut problems);
+
+    if problems.is_empty() {
+        display::success("No problems found");
+        return Ok(());
+    }
+
+    let mut remaining = 0;
+    for problem in &problems {
+        println!(
+            "  {} {}: {}",
+            style("✗").red().bold(),
+            file_name(&problem.path),
+            problem.message
+        );
+        match (&problem.repair, repair) {
+            (Some(fix), true) => match apply_repair(&problem.path, fix) {
+                Ok(moved) => {
+                    let action = match fix {
+                        Repair::Reset(_) => "reset",
+                        Repair::Quarantine => "removed",
+                    };
+                    println!(
+                        "    {} {} (damaged copy kept as {})",
+                        style("→").green(),
+                        action,
+                        file_name(&moved)
+                    );
+                }
+                Err(e) => {
+                    remaining += 1;
+                    println!("    {} repair failed: {:#}", style("✗").red(), e);
+                }
+            },
+            (Some(_), false) => {
+                remaining += 1;
+                println!("    can be reset with 'vauchi doctor --repair'");
+            }
+            (None, _) => {
+                remaining += 1;
+                println!("    not repaired automatically; restore it from a backup");
+            }
+        }
+    }
+    println!();
+
+    if remaining > 0 {
+        bail!("{} problem(s) found", remaining);
+    }
+    display::success("All problems repaired");
+    Ok(())
+}
+
+/// Outcome of one `--network` check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Warn => "warn",
+            Status::Fail => "fail",
+        }
+    }
+}
+
+/// One `--network` check and, unless it passed, how to fix it.
+struct NetworkCheck {
+    name: &'static str,
+    status: Status,
+    detail: String,
+    hint: Option<&'static str>,
+}
+
+impl NetworkCheck {
+    fn pass(name: &'static str, detail: String) -> Self {
+        Self {
+            name,
+            status: Status::Pass,
+            detail,
+            hint: None,
+        }
+    }
+
+    fn problem(name: &'static str, status: Status, detail: String, hint: &'static str) -> Self {
+        Self {
+            name,
+            status,
+            detail,
+            hint: Some(hint),
+        }
+    }
+}
+
+/// Checks that the relay can be reached and reports pass, warn or fail
+/// per check, with a hint for each problem.
+///
+/// Covers the relay URL, DNS resolution of its host and the connection
+/// handshake (OHTTP key bootstrap), timing the handshake. Checks stop at
+/// the first failure, since later ones depend on it. An unreachable relay
+/// is reported, not treated as an error.
+pub fn network(config: &CliConfig) -> Result<()> {
+    let checks = network_checks(config);
+
+    if config.raw {
+        let rows: Vec<_> = checks
+            .iter()
+            .map(|c| {
+                serde_json::json!({
+                    "check": c.name,
+                    "status": c.status.as_str(),
+                    "detail": c.detail,
+                    "hint": c.hint,
+                })
+            })
+            .collect();
+        return crate::raw::print_json(&rows);
+    }
+
+    println!();
+    println!("  Relay: {}", config.relay_url);
+    println!();
+    for check in &checks {
+        let mark = match check.status {
+            Status::Pass => style("✓").green().bold(),
+            Status::Warn => style("!").yellow().bold(),
+            Status::Fail => style("✗").red().bold(),
+        };
+        println!("  {} {}: {}", mark, check.name, check.detail);
+        if let Some(hint) = check.hint {
+            println!("    {}", hint);
+        }
+    }
+    println!();
+
+    match checks.last().map(|c| c.status) {
+        Some(Status::Fail) => display::warning("Relay network check failed"),
+        _ if checks.iter().any(|c| c.status == Status::Warn) => {
+            display::warning("Relay reachable, with warnings")
+        }
+        _ => display::success("Relay network checks passed"),
+    }
+    Ok(())
+}
+
+fn network_checks(config: &CliConfig) -> Vec<NetworkCheck> {
+    let mut checks = Vec::new();
+
+    if let Err(e) = config::validate_relay_url(&config.relay_url) {
+        checks.push(NetworkCheck::problem(
+            "Relay URL",
+            Status::Fail,
+            e.to_string(),
+            "Set a valid relay with 'vauchi relay set wss://<host>'",
+        ));
+        return checks;
+    }
+    checks.push(NetworkCheck::pass("Relay URL", "valid".to_string()));
+
+    let host = dns_check::host_for(FieldType::Website, &config.relay_url).unwrap_or_default();
+    if !dns_check::resolves(&host) {
+        checks.push(NetworkCheck::problem(
+            "DNS",
+            Status::Fail,
+            format!("{} does not resolve", host),
+            "Check the relay host name and that this device is online",
+        ));
+        return checks;
+    }
+    checks.push(NetworkCheck::pass("DNS", format!("{} resolves", host)));
+
+    if !config.is_initialized() {
+        checks.push(NetworkCheck::problem(
+            "Handshake",
+            Status::Warn,
+            "skipped, no identity".to_string(),
+            "Run 'vauchi init <name>' to test the relay handshake",
+        ));
+        return checks;
+    }
+    checks.push(check_handshake(config));
+    checks
+}
+
+fn check_handshake(config: &CliConfig) -> NetworkCheck {
+    let mut wb = match open_vauchi(config) {
+        Ok(wb) => wb,
+        Err(e) => {
+            return NetworkCheck::problem(
+                "Handshake",
+                Status::Fail,
+                format!("cannot open storage ({e})"),
+                "Run 'vauchi doctor' to check the data directory",
+            );
+        }
+    };
+
+    let started = Instant::now();
+    let result = wb.connect();
+    let elapsed = started.elapsed();
+    match result {
+        Ok(_) => {
+            wb.disconnect();
+            let detail = format!("succeeded in {} ms", elapsed.as_millis());
+            if elapsed > SLOW_HANDSHAKE {
+                NetworkCheck::problem(
+                    "Handshake",
+                    Status::Warn,
+                    detail,
+                    "The relay is slow to answer; sync may time out on this network",
+                )
+            } else {
+                NetworkCheck::pass("Handshake", detail)
+            }
+        }
+        Err(e) => NetworkCheck::problem(
+            "Handshake",
+            Status::Fail,
+            format!("relay unreachable ({e})"),
+            "Check that the relay is running, or switch with 'vauchi relay set <url>'",
+        ),
+    }
+}
+
+fn check_identity(config: &CliConfig, problems: &mut Vec<Problem>) {
+    if let Err(e) = config.import_local_identity() {
+        problems.push(Problem {
+            path: config.identity_path(),
+            message: format!("cannot be loaded ({e})"),
+            repair: None,
+        });
+    }
+}
+
+fn check_key_files(data_dir: &Path, problems: &mut Vec<Problem>) {
+    #[cfg(not(feature = "secure-storage"))]
+    {
+        let key_path = data_dir.join(".fallback-key");
+        if let Ok(bytes) = fs::read(&key_path)
+            && bytes.len() != 32
+        {
+            problems.push(Problem {
+                path: key_path,
+                message: format!("has {} bytes, expected 32", bytes.len()),
+                repair: None,
+            });
+        }
+    }
+
+    let password_path = data_dir.join(".backup-password");
+    if let Ok(password) = fs::read_to_string(&password_path) {
+        let len = password.trim().len();
+        if len != 64 {
+            problems.push(Problem {
+                path: password_path,
+                message: format!("has {len} characters, expected 64"),
+                repair: None,
+            });
+        }
+    }
+}
+
+fn check_storage(config: &CliConfig, problems: &mut Vec<Problem>) {
+    let result = open_vauchi(config).and_then(|wb| {
+        wb.list_contacts()?;
+        wb.own_card()?;
+        Ok(())
+    });
+    if let Err(e) = result {
+        problems.push(Problem {
+            path: config.storage_path(),
+            message: format!("cannot be read ({e})"),
+            repair: None,
+        });
+    }
+}
+
+fn check_state_files(data_dir: &Path, problems: &mut Vec<Problem>) {
+    let aha_path = data_dir.join("aha_tracker.json");
+    if let Ok(json) = fs::read_to_string(&aha_path)
+        && AhaMomentTracker::from_json(&json).is_err()
+    {
+        problems.push(Problem {
+            path: aha_path,
+            message: "is not a valid tracker file".to_string(),
+            repair: AhaMomentTracker::default()
+                .to_json()
+                .ok()
+                .map(Repair::Reset),
+        });
+    }
+
+    for (name, what, is_valid) in STATE_FILES {
+        let path = data_dir.join(name);
+        if let Ok(json) = fs::read_to_string(&path)
+            && !is_valid(&json)
+        {
+            problems.push(Problem {
+                path,
+                message: format!("is not a valid {what}"),
+                repair: Some(Repair::Quarantine),
+            });
+        }
+    }
+}
+
+fn parses<T: DeserializeOwned>(json: &str) -> bool {
+    serde_json::from_str::<T>(json).is_ok()
+}
+
+/// Moves `path` to `<path>.corrupt`, then applies `repair`.
+///
+/// An earlier damaged copy is never overwritten: if `<path>.corrupt` exists,
+/// the file goes to `<path>.corrupt.1`, `<path>.corrupt.2` and so on.
+/// Returns where the damaged copy was moved.
+fn apply_repair(path: &Path, repair: &Repair) -> Result<PathBuf> {
+    let moved = quarantine_path(path);
+    fs::rename(path, &moved).with_context(|| format!("Failed to move {} aside", path.display()))?;
+    if let Repair::Reset(content) = repair {
+        crate::config::write_restricted(path, content)?;
+    }
+    Ok(moved)
+}
+
+/// First `<path>.corrupt[.N]` that does not exist yet.
+fn quarantine_path(path: &Path) -> PathBuf {
+    let mut base = path.as_os_str().to_owned();
+    base.push(".");
+    base.push(QUARANTINE_SUFFIX);
+
+    let mut candidate = PathBuf::from(&base);
+    let mut n = 1;
+    while candidate.exists() {
+        let mut next = base.clone();
+        next.push(format!(".{n}"));
+        candidate = PathBuf::from(next);
+        n += 1;
+    }
+    candidate
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_corrupt_tracker_is_reported_and_reset() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aha_tracker.json");
+        fs::write(&path, "{not json").unwrap();
+
+        let mut problems = Vec::new();
+        check_state_files(dir.path(), &mut problems);
+        assert_eq!(problems.len(), 1);
+
+        let moved = apply_repair(&path, problems[0].repair.as_ref().unwrap()).unwrap();
+        assert_eq!(fs::read_to_string(moved).unwrap(), "{not json");
+        assert!(AhaMomentTracker::from_json(&fs::read_to_string(&path).unwrap()).is_ok());
+    }
+
+    // @internal
+    #[test]
+    fn test_repair_keeps_earlier_damaged_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(REVOCATION_LOG_FILE);
+        fs::write(&path, "first").unwrap();
+        let first = apply_repair(&path, &Repair::Quarantine).unwrap();
+
+        fs::write(&path, "second").unwrap();
+        let second = apply_repair(&path, &Repair::Quarantine).unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(fs::read_to_string(first).unwrap(), "first");
+        assert_eq!(fs::read_to_string(second).unwrap(), "second");
+        assert!(!path.exists());
+    }
+
+    // @internal
+    #[test]
+    fn test_network_checks_stop_at_invalid_relay_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = CliConfig {
+            data_dir: dir.path().to_path_buf(),
+            relay_url: "https://relay.example.com".to_string(),
+            ohttp_relay_url: None,
+            raw: false,
+            offline_queue_limit: None,
+        };
+
+        let checks = network_checks(&config);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, Status::Fail);
+        assert!(checks[0].hint.is_some());
+    }
+
+    // @internal
+    #[test]
+    fn test_valid_state_files_pass() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("aha_tracker.json"),
+            AhaMomentTracker::default().to_json().unwrap(),
+        )
+        .unwrap();
+        for (name, json) in [
+            (REVOCATION_LOG_FILE, "[]"),
+            (LABEL_RULES_FILE, "[]"),
+            (EXPIRY_FILE, "{}"),
+            (FIELD_TAGS_FILE, "{}"),
+            (TRASH_FILE, "[]"),
+            (WATCH_FILE, "[]"),
+            (LAST_SYNC_FILE, "{}"),
+        ] {
+            fs::write(dir.path().join(name), json).unwrap();
+        }
+
+        let mut problems = Vec::new();
+        check_state_files(dir.path(), &mut problems);
+        assert!(problems.is_empty());
+    }
+
+    // @internal
+    #[test]
+    fn test_every_corrupt_state_file_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, _, _) in STATE_FILES {
+            fs::write(dir.path().join(name), "{not json").unwrap();
+        }
+
+        let mut problems = Vec::new();
+        check_state_files(dir.path(), &mut problems);
+        assert_eq!(problems.len(), STATE_FILES.len());
+        assert!(
+            problems
+                .iter()
+                .all(|p| matches!(p.repair, Some(Repair::Quarantine)))
+        );
+    }
+}
@@ -9,7 +9,7 @@
 use anyhow::{Result, bail};
 use dialoguer::Password;
 
-use crate::commands::common::{auth_mode_label, open_vauchi};
+use crate::commands::common::{auth_mode_label, open_vauchi, require_interactive};
 use crate::config::CliConfig;
 use crate::display;
 
@@ -17,6 +17,7 @@ use crate::display;
 pub fn setup(config: &CliConfig) -> Result<()> {
     let mut wb = open_vauchi(config)?;
 
+    require_interactive(None)?;
     if !wb.is_password_enabled()? {
         display::info("App password not set. Setting it up first...");
         let password = Password::new()
@@ -45,6 +46,21 @@ pub fn status(config: &CliConfig) -> Result<()> {
 
     let password_enabled = wb.is_password_enabled()?;
     let duress_enabled = wb.is_duress_enabled()?;
+    // Unreadable settings only hide the alert details, as before.
+    let settings = if duress_enabled {
+        wb.load_duress_settings().ok().flatten()
+    } else {
+        None
+    };
+
+    if config.raw {
+        return crate::raw::print_json(&serde_json::json!({
+            "password_enabled": password_enabled,
+            "duress_enabled": duress_enabled,
+            "alert_contact_count": settings.as_ref().map_or(0, |s| s.alert_contact_ids.len()),
+            "custom_alert_message": settings.as_ref().is_some_and(|s| !s.alert_message.is_empty()),
+        }));
+    }
 
     println!();
     println!(
@@ -60,7 +76,7 @@ pub fn status(config: &CliConfig) -> Result<()> {
         if duress_enabled { "ENABLED" } else { "NOT SET" }
     );
 
-    if duress_enabled && let Ok(Some(settings)) = wb.load_duress_settings() {
+    if let Some(settings) = settings {
         println!(
             "  Alert Contacts: {}",
             if settings.alert_contact_ids.is_empty() {
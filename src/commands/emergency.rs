@@ -6,17 +6,29 @@
 //!
 //! Configure and send emergency alerts to trusted contacts.
 
+use std::fs;
+
 use anyhow::{Result, bail};
 use dialoguer::{Confirm, Input};
 
-use crate::commands::common::open_vauchi;
+use crate::commands::common::{open_vauchi, require_interactive};
 use crate::config::CliConfig;
 use crate::display;
 
+/// Minimum time between two emergency broadcasts before `--force` is required.
+const SEND_COOLDOWN_SECS: u64 = 5 * 60;
+
+/// File (in the data directory) holding the Unix time of the last broadcast.
+///
+/// The emergency config itself lives in vauchi-core storage, so the CLI keeps
+/// its cooldown timestamp alongside it in the data directory.
+const LAST_SEND_FILE: &str = ".last_emergency_send";
+
 /// Configure emergency broadcast (set trusted contacts + message).
 pub fn configure(config: &CliConfig) -> Result<()> {
     let mut wb = open_vauchi(config)?;
 
+    require_interactive(None)?;
     let ids_input: String = Input::new()
         .with_prompt("Trusted contact IDs (comma-separated, max 10)")
         .interact_text()?;
@@ -48,13 +60,31 @@ pub fn configure(config: &CliConfig) -> Result<()> {
 }
 
 /// Send emergency broadcast to all trusted contacts.
-pub fn send(config: &CliConfig) -> Result<()> {
+///
+/// A second send within [`SEND_COOLDOWN_SECS`] is refused unless `force` is
+/// set, so a flaky relay does not lead to contacts being spammed.
+pub fn send(config: &CliConfig, force: bool) -> Result<()> {
     let mut wb = open_vauchi(config)?;
 
     if wb.load_emergency_config()?.is_none() {
         bail!("No emergency broadcast configured. Run 'vauchi emergency configure' first.");
     }
 
+    let now = crate::clock::unix_seconds();
+    if let Some(elapsed) = recent_send_elapsed(load_last_send(config), now) {
+        if !force {
+            bail!(
+                "An emergency alert was already sent {} ago. Use --force to send again.",
+                format_elapsed(elapsed)
+            );
+        }
+        display::warning(&format!(
+            "An emergency alert was already sent {} ago",
+            format_elapsed(elapsed)
+        ));
+    }
+
+    require_interactive(None)?;
     let confirmed = Confirm::new()
         .with_prompt("Send emergency alert to all trusted contacts?")
         .default(false)
@@ -66,6 +96,7 @@ pub fn send(config: &CliConfig) -> Result<()> {
     }
 
     let result = wb.send_emergency_broadcast()?;
+    save_last_send(config, now)?;
     display::success(&format!(
         "Emergency broadcast sent: {}/{} contacts reached",
         result.sent, result.total
@@ -80,6 +111,18 @@ pub fn status(config: &CliConfig) -> Result<()> {
 
     let config_opt = wb.load_emergency_config()?;
 
+    if config.raw {
+        return crate::raw::print_json(&match &config_opt {
+            Some(cfg) => serde_json::json!({
+                "configured": true,
+                "trusted_contact_count": cfg.trusted_contact_ids.len(),
+                "custom_message": !cfg.is_default_message(),
+                "include_location": cfg.include_location,
+            }),
+            None => serde_json::json!({ "configured": false }),
+        });
+    }
+
     println!();
     match config_opt {
         Some(cfg) => {
@@ -117,7 +160,76 @@ pub fn disable(config: &CliConfig) -> Result<()> {
     }
 
     wb.delete_emergency_config()?;
+    let _ = fs::remove_file(config.data_dir.join(LAST_SEND_FILE));
     display::success("Emergency broadcast disabled");
 
     Ok(())
 }
+
+fn load_last_send(config: &CliConfig) -> Option<u64> {
+    fs::read_to_string(config.data_dir.join(LAST_SEND_FILE))
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+}
+
+fn save_last_send(config: &CliConfig, timestamp: u64) -> Result<()> {
+    crate::config::write_restricted(&config.data_dir.join(LAST_SEND_FILE), timestamp.to_string())
+}
+
+/// Returns the seconds since the last send if it falls inside the cooldown.
+fn recent_send_elapsed(last_send: Option<u64>, now: u64) -> Option<u64> {
+    let elapsed = now.saturating_sub(last_send?);
+    (elapsed < SEND_COOLDOWN_SECS).then_some(elapsed)
+}
+
+fn format_elapsed(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else {
+        format!("{}m {}s", secs / 60, secs % 60)
+    }
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_send_within_cooldown_is_blocked() {
+        assert_eq!(recent_send_elapsed(Some(1_000), 1_060), Some(60));
+    }
+
+    // @internal
+    #[test]
+    fn test_send_after_cooldown_is_allowed() {
+        assert_eq!(
+            recent_send_elapsed(Some(1_000), 1_000 + SEND_COOLDOWN_SECS),
+            None
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_first_send_is_allowed() {
+        assert_eq!(recent_send_elapsed(None, 1_000), None);
+    }
+
+    // @internal
+    #[test]
+    fn test_last_send_round_trips_through_data_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = CliConfig {
+            data_dir: temp.path().to_path_buf(),
+            relay_url: "wss://test.example.com".to_string(),
+            ohttp_relay_url: None,
+            raw: false,
+            offline_queue_limit: None,
+        };
+
+        assert_eq!(load_last_send(&config), None);
+        save_last_send(&config, 1_234).unwrap();
+        assert_eq!(load_last_send(&config), Some(1_234));
+    }
+}
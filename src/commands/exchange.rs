@@ -18,10 +18,12 @@ use vauchi_core::exchange::{
     ProximityConfidence, UsbRole,
 };
 use vauchi_core::types::{AhaMomentTracker, AhaMomentType};
-use vauchi_core::{Command, Event};
+use vauchi_core::{Command, Event, Vauchi};
 use zeroize::Zeroizing;
 
 use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
+use crate::commands::field_expiry;
+use crate::commands::labels;
 use crate::config::CliConfig;
 use crate::display;
 
@@ -72,17 +74,51 @@ fn load_pending_qr(config: &CliConfig) -> Result<(Zeroizing<[u8; 32]>, ExchangeQ
 /// Uses ExchangeSession state machine with ManualConfirmationVerifier
 /// since CLI doesn't have audio hardware for proximity verification.
 pub fn start(config: &CliConfig, locale: &str) -> Result<()> {
+    let session = start_session(config)?;
+    let (qr_data, qr_image) = match session.qr() {
+        Some(qr) => (qr.to_data_string(), qr.to_qr_image_string()),
+        None => bail!("QR code not generated"),
+    };
+
+    display::info(&display::t("cli.cmd.exchange.share_with_user", locale));
+    println!();
+    println!("{}", qr_image);
+    println!();
+    println!(
+        "{}",
+        display::t("cli.cmd.exchange.share_data_string", locale)
+    );
+    println!("  {}", qr_data);
+    println!();
+
+    display::info(&display::t("cli.cmd.exchange.after_complete", locale));
+
+    Ok(())
+}
+
+/// Starts a contact exchange and prints only the `ExchangeQR` data string.
+///
+/// This is the exact payload `exchange start` encodes in its QR, for
+/// external tools that render their own code. The session is saved just
+/// like `start`, so `exchange complete` works afterwards.
+pub fn payload(config: &CliConfig) -> Result<()> {
+    let session = start_session(config)?;
+    let qr = session
+        .qr()
+        .ok_or_else(|| anyhow::anyhow!("QR code not generated"))?;
+    println!("{}", qr.to_data_string());
+    Ok(())
+}
+
+/// Creates a QR exchange session for the current card and persists it.
+fn start_session(config: &CliConfig) -> Result<ExchangeSession> {
     let wb = open_vauchi(config)?;
 
     let identity = wb
         .identity()
         .ok_or_else(|| anyhow::anyhow!("No identity found"))?;
 
-    let our_card = wb
-        .storage()
-        .contacts()
-        .load_own_card()?
-        .unwrap_or_else(|| ContactCard::new(identity.display_name()));
+    let our_card = outgoing_card(config, &wb, identity.display_name())?;
 
     let verifier = ManualConfirmationVerifier::new();
 
@@ -101,26 +137,8 @@ pub fn start(config: &CliConfig, locale: &str) -> Result<()> {
         .apply(ExchangeEvent::StartQR)
         .map_err(|e| anyhow::anyhow!("Failed to generate QR: {:?}", e))?;
 
-    let (qr_data, qr_image) = match session.qr() {
-        Some(qr) => (qr.to_data_string(), qr.to_qr_image_string()),
-        None => bail!("QR code not generated"),
-    };
     save_pending_qr(config, &session)?;
-
-    display::info(&display::t("cli.cmd.exchange.share_with_user", locale));
-    println!();
-    println!("{}", qr_image);
-    println!();
-    println!(
-        "{}",
-        display::t("cli.cmd.exchange.share_data_string", locale)
-    );
-    println!("  {}", qr_data);
-    println!();
-
-    display::info(&display::t("cli.cmd.exchange.after_complete", locale));
-
-    Ok(())
+    Ok(session)
 }
 
 /// Completes a contact exchange with received data.
@@ -130,8 +148,9 @@ pub fn start(config: &CliConfig, locale: &str) -> Result<()> {
 /// both sides derive the channel from the same two ephemeral keys.
 ///
 /// After creating the contact, queues our initial card for delivery
-/// and runs a sync to send it immediately.
-pub fn complete(config: &CliConfig, data: &str, _locale: &str) -> Result<()> {
+/// and runs a sync to send it immediately. With `no_relay`, the relay is
+/// not contacted and the card stays queued for the next `vauchi sync`.
+pub fn complete(config: &CliConfig, data: &str, no_relay: bool, _locale: &str) -> Result<()> {
     let mut wb = open_vauchi(config)?;
 
     // Capture exchange events (ContactAdded) for the activity log.
@@ -149,11 +168,7 @@ pub fn complete(config: &CliConfig, data: &str, _locale: &str) -> Result<()> {
         .identity()
         .ok_or_else(|| anyhow::anyhow!("No identity found"))?;
 
-    let our_card = wb
-        .storage()
-        .contacts()
-        .load_own_card()?
-        .unwrap_or_else(|| ContactCard::new(identity.display_name()));
+    let our_card = outgoing_card(config, &wb, identity.display_name())?;
 
     let verifier = ManualConfirmationVerifier::new();
 
@@ -211,6 +226,9 @@ pub fn complete(config: &CliConfig, data: &str, _locale: &str) -> Result<()> {
         .build_exchange_ratchet(&contact)
         .map_err(|e| anyhow::anyhow!("failed to build exchange ratchet: {e:?}"))?;
     wb.save_exchanged_contact(&contact, &ratchet, is_initiator)?;
+    field_expiry::hide_expired_from(config, &wb, &contact_id)?;
+    labels::apply_rules_after_exchange(config, &wb, &contact_id);
+
     fs::remove_file(config.data_dir.join(PENDING_QR_FILE))
         .context("Failed to remove completed QR exchange state")?;
 
@@ -227,6 +245,10 @@ pub fn complete(config: &CliConfig, data: &str, _locale: &str) -> Result<()> {
     // The initial card establishes the responder's receive chain so
     // both parties can send updates.
     match wb.queue_initial_card_for_contact(&contact_id) {
+        Ok(()) if no_relay => {
+            display::info("Queued your card without contacting the relay.");
+            display::info("Run 'vauchi sync' to send it once the relay is reachable.");
+        }
         Ok(()) => {
             if let Err(e) = wb.connect() {
                 display::warning(&format!("Could not connect to relay: {e}"));
@@ -272,11 +294,7 @@ pub fn usb_exchange(config: &CliConfig, address: &str) -> Result<()> {
         .identity()
         .ok_or_else(|| anyhow::anyhow!("No identity found"))?;
 
-    let our_card = wb
-        .storage()
-        .contacts()
-        .load_own_card()?
-        .unwrap_or_else(|| ContactCard::new(identity.display_name()));
+    let our_card = outgoing_card(config, &wb, identity.display_name())?;
 
     let verifier = ManualConfirmationVerifier::new();
 
@@ -355,6 +373,8 @@ pub fn usb_exchange(config: &CliConfig, address: &str) -> Result<()> {
         .build_exchange_ratchet(&contact)
         .map_err(|e| anyhow::anyhow!("failed to build exchange ratchet: {e:?}"))?;
     wb.save_exchanged_contact(&contact, &ratchet, ratchet_is_initiator)?;
+    field_expiry::hide_expired_from(config, &wb, &contact_id)?;
+    labels::apply_rules_after_exchange(config, &wb, &contact_id);
 
     match wb.queue_initial_card_for_contact(&contact_id) {
         Ok(()) => {
@@ -391,11 +411,7 @@ pub fn usb_listen(config: &CliConfig, port: u16) -> Result<()> {
         .identity()
         .ok_or_else(|| anyhow::anyhow!("No identity found"))?;
 
-    let our_card = wb
-        .storage()
-        .contacts()
-        .load_own_card()?
-        .unwrap_or_else(|| ContactCard::new(identity.display_name()));
+    let our_card = outgoing_card(config, &wb, identity.display_name())?;
 
     let verifier = ManualConfirmationVerifier::new();
 
@@ -479,6 +495,8 @@ pub fn usb_listen(config: &CliConfig, port: u16) -> Result<()> {
         .build_exchange_ratchet(&contact)
         .map_err(|e| anyhow::anyhow!("failed to build exchange ratchet: {e:?}"))?;
     wb.save_exchanged_contact(&contact, &ratchet, ratchet_is_initiator)?;
+    field_expiry::hide_expired_from(config, &wb, &contact_id)?;
+    labels::apply_rules_after_exchange(config, &wb, &contact_id);
 
     match wb.queue_initial_card_for_contact(&contact_id) {
         Ok(()) => {
@@ -502,6 +520,21 @@ pub fn usb_listen(config: &CliConfig, port: u16) -> Result<()> {
     Ok(())
 }
 
+/// Loads our card for an exchange, without fields whose `--expires` date
+/// has passed.
+fn outgoing_card(config: &CliConfig, wb: &Vauchi, display_name: &str) -> Result<ContactCard> {
+    let card = wb
+        .storage()
+        .contacts()
+        .load_own_card()?
+        .unwrap_or_else(|| ContactCard::new(display_name));
+    field_expiry::without_expired(
+        &card,
+        &field_expiry::ExpiryStore::load(config)?,
+        wb.clock().unix_seconds(),
+    )
+}
+
 fn load_aha_tracker(config: &CliConfig) -> AhaMomentTracker {
     let path = config.data_dir.join("aha_tracker.json");
     fs::read_to_string(&path)
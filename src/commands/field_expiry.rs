@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Expiry dates for temporary card fields.
+//!
+//! Core fields carry no expiry, so `card add --expires` records it in a
+//! local JSON file keyed by field ID. Expired fields are left out of the
+//! card sent during an exchange and hidden from existing contacts on the
+//! next sync, until `card prune-expired` removes them.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use vauchi_core::{ContactCard, Vauchi};
+
+use crate::commands::common::{load_json_state, save_json_state};
+use crate::config::CliConfig;
+
+pub(crate) const EXPIRY_FILE: &str = ".field_expiry.json";
+
+/// Expiry of one field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct FieldExpiry {
+    /// Unix seconds from which the field counts as expired.
+    pub(crate) expires_at: u64,
+    /// Whether the field has already been hidden from existing contacts.
+    #[serde(default)]
+    pub(crate) hidden: bool,
+}
+
+/// Field expiries keyed by field ID.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct ExpiryStore(BTreeMap<String, FieldExpiry>);
+
+impl ExpiryStore {
+    /// Loads the store. A corrupt file is an error rather than empty,
+    /// which would share expired fields again.
+    pub(crate) fn load(config: &CliConfig) -> Result<Self> {
+        load_json_state(config, EXPIRY_FILE, "field expiry file")
+    }
+
+    pub(crate) fn save(&self, config: &CliConfig) -> Result<()> {
+        save_json_state(config, EXPIRY_FILE, self)
+    }
+
+    pub(crate) fn set(&mut self, field_id: &str, expires_at: u64) {
+        self.0.insert(
+            field_id.to_string(),
+            FieldExpiry {
+                expires_at,
+                hidden: false,
+            },
+        );
+    }
+
+    pub(crate) fn get(&self, field_id: &str) -> Option<&FieldExpiry> {
+        self.0.get(field_id)
+    }
+
+    pub(crate) fn remove(&mut self, field_id: &str) {
+        self.0.remove(field_id);
+    }
+
+    pub(crate) fn is_expired(&self, field_id: &str, now: u64) -> bool {
+        self.get(field_id).is_some_and(|e| e.expires_at <= now)
+    }
+}
+
+/// Parses an `--expires` date (`YYYY-MM-DD`).
+///
+/// The field stays valid through that day (UTC) and expires at the start
+/// of the next one.
+pub(crate) fn parse_expiry_date(s: &str) -> Result<u64, String> {
+    let date = NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("invalid date '{}': expected YYYY-MM-DD", s))?;
+    let next_day = date
+        .succ_opt()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .ok_or_else(|| format!("date '{}' is out of range", s))?;
+    u64::try_from(next_day.and_utc().timestamp())
+        .map_err(|_| format!("date '{}' is before 1970", s))
+}
+
+/// Formats an expiry timestamp as the last day the field is valid.
+pub(crate) fn format_expiry(expires_at: u64) -> String {
+    chrono::DateTime::from_timestamp(expires_at.saturating_sub(1) as i64, 0)
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| expires_at.to_string())
+}
+
+/// Returns `card` without its expired fields.
+pub(crate) fn without_expired(
+    card: &ContactCard,
+    store: &ExpiryStore,
+    now: u64,
+) -> Result<ContactCard> {
+    if !card.fields().iter().any(|f| store.is_expired(f.id(), now)) {
+        return Ok(card.clone());
+    }
+    let mut outgoing = ContactCard::new(card.display_name());
+    for field in card.fields() {
+        if !store.is_expired(field.id(), now) {
+            outgoing.add_field(field.clone())?;
+        }
+    }
+    Ok(outgoing)
+}
+
+/// Hides newly expired fields from every contact and re-propagates.
+///
+/// Each field is hidden once; returns how many fields were hidden.
+pub(crate) fn hide_expired(config: &CliConfig, wb: &Vauchi) -> Result<usize> {
+    let mut store = ExpiryStore::load(config)?;
+    let now = wb.clock().unix_seconds();
+    let due: Vec<String> = store
+        .0
+        .iter()
+        .filter(|(_, e)| e.expires_at <= now && !e.hidden)
+        .map(|(id, _)| id.clone())
+        .collect();
+    if due.is_empty() {
+        return Ok(0);
+    }
+
+    let contacts = wb.list_contacts()?;
+    for field_id in &due {
+        hide_from_contacts(wb, contacts.iter().map(|c| c.id()), field_id)?;
+        if let Some(expiry) = store.0.get_mut(field_id) {
+            expiry.hidden = true;
+        }
+    }
+    store.save(config)?;
+    Ok(due.len())
+}
+
+/// Hides every expired field from one contact, e.g. one just added by an
+/// exchange, before our card is sent to it.
+pub(crate) fn hide_expired_from(config: &CliConfig, wb: &Vauchi, contact_id: &str) -> Result<()> {
+    if wb.get_contact(contact_id)?.is_none() {
+        return Ok(());
+    }
+    let store = ExpiryStore::load(config)?;
+    let now = wb.clock().unix_seconds();
+    for (field_id, expiry) in &store.0 {
+        if expiry.expires_at <= now {
+            hide_from_contacts(wb, std::iter::once(contact_id), field_id)?;
+        }
+    }
+    Ok(())
+}
+
+fn hide_from_contacts<'a>(
+    wb: &Vauchi,
+    contact_ids: impl Iterator<Item = &'a str>,
+    field_id: &str,
+) -> Result<()> {
+    for contact_id in contact_ids {
+        wb.set_contact_visibility_override_and_repropagate(contact_id, field_id, false)?;
+    }
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vauchi_core::{ContactField, FieldType};
+
+    // @internal
+    #[test]
+    fn test_parse_expiry_date_expires_after_the_day() {
+        // 2026-01-01T00:00:00Z is 1767225600.
+        assert_eq!(parse_expiry_date("2025-12-31"), Ok(1_767_225_600));
+        assert_eq!(format_expiry(1_767_225_600), "2025-12-31");
+        assert!(parse_expiry_date("31.12.2025").is_err());
+        assert!(parse_expiry_date("2025-02-30").is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_without_expired_drops_only_expired_fields() {
+        let mut card = ContactCard::new("Alice");
+        let phone = ContactField::new(FieldType::Phone, "Temp", "+1555", 0);
+        let email = ContactField::new(FieldType::Email, "Work", "a@example.com", 0);
+        let phone_id = phone.id().to_string();
+        card.add_field(phone).unwrap();
+        card.add_field(email).unwrap();
+
+        let mut store = ExpiryStore::default();
+        store.set(&phone_id, 100);
+
+        let before = without_expired(&card, &store, 99).unwrap();
+        assert_eq!(before.fields().len(), 2);
+
+        let after = without_expired(&card, &store, 100).unwrap();
+        let labels: Vec<_> = after.fields().iter().map(|f| f.label()).collect();
+        assert_eq!(labels, ["Work"]);
+        assert_eq!(after.display_name(), "Alice");
+    }
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Short custom tags shown next to card field icons.
+//!
+//! Core fields have no room for a personal marker, so `card add --tag`
+//! records it in a local JSON file keyed by field ID. Tags are only shown
+//! locally and never sent to contacts.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::common::{load_json_state, save_json_state};
+use crate::config::CliConfig;
+
+pub(crate) const FIELD_TAGS_FILE: &str = ".field_tags.json";
+
+/// Longest tag accepted, in characters, so it fits beside the icon.
+const MAX_TAG_CHARS: usize = 8;
+
+/// Field tags keyed by field ID.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct FieldTags(BTreeMap<String, String>);
+
+impl FieldTags {
+    pub(crate) fn load(config: &CliConfig) -> Result<Self> {
+        load_json_state(config, FIELD_TAGS_FILE, "field tags file")
+    }
+
+    pub(crate) fn save(&self, config: &CliConfig) -> Result<()> {
+        save_json_state(config, FIELD_TAGS_FILE, self)
+    }
+
+    pub(crate) fn set(&mut self, field_id: &str, tag: &str) {
+        self.0.insert(field_id.to_string(), tag.to_string());
+    }
+
+    pub(crate) fn get(&self, field_id: &str) -> Option<&str> {
+        self.0.get(field_id).map(String::as_str)
+    }
+
+    /// Removes a field's tag, returning whether it had one.
+    pub(crate) fn remove(&mut self, field_id: &str) -> bool {
+        self.0.remove(field_id).is_some()
+    }
+}
+
+/// Parses a `--tag` value: up to 8 characters, without whitespace.
+pub(crate) fn parse_tag(s: &str) -> Result<String, String> {
+    let tag = s.trim();
+    if tag.is_empty() {
+        return Err("tag must not be empty".to_string());
+    }
+    if tag.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(format!("tag '{}' must be a single word", tag));
+    }
+    if tag.chars().count() > MAX_TAG_CHARS {
+        return Err(format!(
+            "tag '{}' is too long: use at most {} characters",
+            tag, MAX_TAG_CHARS
+        ));
+    }
+    Ok(tag.to_string())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_parse_tag_accepts_short_words_only() {
+        assert_eq!(parse_tag(" biz ").unwrap(), "biz");
+        assert_eq!(parse_tag("🏠home").unwrap(), "🏠home");
+        assert!(parse_tag("").is_err());
+        assert!(parse_tag("two words").is_err());
+        assert!(parse_tag("toolongtag").is_err());
+    }
+}
@@ -10,7 +10,9 @@ use std::fs;
 use std::path::Path;
 
 use anyhow::{Result, bail};
+use clap::ValueEnum;
 use dialoguer::Input;
+use serde::Serialize;
 use vauchi_core::Vauchi;
 use vauchi_core::api::{
     ConsentManager, ConsentType, DeletionManager, ShredManager, ShredReport, ShredToken,
@@ -20,7 +22,9 @@ use vauchi_core::network::{HttpTransportAdapter, RelayClient, RelayClientConfig,
 use vauchi_core::storage::DeletionState;
 use vauchi_core::storage::secure::SecureStorage;
 
-use crate::commands::common::open_vauchi;
+use crate::commands::common::{
+    atomic_write, atomic_write_restricted, csv_escape, open_vauchi, require_interactive,
+};
 use crate::config::CliConfig;
 use crate::display;
 
@@ -28,41 +32,207 @@ use crate::display;
 ///
 /// If `password` is provided, uses core's encrypted export envelope
 /// (Argon2id + HKDF domain separation + XChaCha20-Poly1305).
-pub fn export_data(config: &CliConfig, output: &Path, password: Option<&str>) -> Result<()> {
+///
+/// `include_devices` and `include_labels` add the device registry and the
+/// visibility labels to the plaintext export under `devices` / `labels`.
+pub fn export_data(
+    config: &CliConfig,
+    output: &Path,
+    password: Option<&str>,
+    include_devices: bool,
+    include_labels: bool,
+) -> Result<()> {
     let wb = open_vauchi(config)?;
 
     if let Some(pw) = password {
+        if include_devices || include_labels {
+            bail!("--include-devices and --include-labels are not supported with encryption yet");
+        }
         let encrypted = export_encrypted(wb.storage(), pw)?;
-        fs::write(output, &encrypted)?;
+        atomic_write(output, &encrypted)?;
         display::success(&format!("Encrypted GDPR data export saved to {:?}", output));
     } else {
         let export = export_all_data(wb.storage())?;
-        let json = serde_json::to_string_pretty(&export)?;
+        let mut value = serde_json::to_value(&export)?;
+
+        let mut extras = Vec::new();
+        if let Some(obj) = value.as_object_mut() {
+            if include_devices {
+                let devices = export_devices(&wb)?;
+                extras.push(format!("devices: {}", devices.len()));
+                obj.insert("devices".to_string(), serde_json::to_value(devices)?);
+            }
+            if include_labels {
+                let labels = export_labels(&wb)?;
+                extras.push(format!("labels: {}", labels.len()));
+                obj.insert("labels".to_string(), serde_json::to_value(labels)?);
+            }
+        }
+
+        let json = serde_json::to_string_pretty(&value)?;
         display::warning(
             "Exporting without encryption. Consider using --encrypt to protect sensitive data.",
         );
-        fs::write(output, &json)?;
+        atomic_write_restricted(output, &json)?;
         display::success(&format!("GDPR data export saved to {:?}", output));
 
-        display::info(&format!(
-            "Export version: {}, contacts: {}, exported at: {}",
+        let mut summary = format!(
+            "Export version: {}, contacts: {}",
             export.version,
-            export.contacts.len(),
-            export.exported_at
+            export.contacts.len()
+        );
+        for extra in &extras {
+            summary.push_str(&format!(", {}", extra));
+        }
+        summary.push_str(&format!(", exported at: {}", export.exported_at));
+        display::info(&summary);
+    }
+
+    Ok(())
+}
+
+/// Device registry entry included in a GDPR export.
+#[derive(serde::Serialize)]
+struct DeviceExport {
+    device_id: String,
+    device_name: String,
+    active: bool,
+}
+
+/// Visibility label included in a GDPR export.
+#[derive(serde::Serialize)]
+struct LabelExport {
+    id: String,
+    name: String,
+    contact_ids: Vec<String>,
+    visible_field_ids: Vec<String>,
+    created_at: u64,
+    modified_at: u64,
+}
+
+/// Collects the device registry for the export (empty for a single device).
+fn export_devices(wb: &Vauchi) -> Result<Vec<DeviceExport>> {
+    let registry = wb.storage().device().load_device_registry()?;
+    Ok(registry
+        .map(|registry| {
+            registry
+                .all_devices()
+                .iter()
+                .map(|device| DeviceExport {
+                    device_id: hex::encode(&device.device_id),
+                    device_name: device.device_name.clone(),
+                    active: device.is_active(),
+                })
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Collects all visibility labels for the export.
+fn export_labels(wb: &Vauchi) -> Result<Vec<LabelExport>> {
+    let labels = wb.storage().labels().load_all_groups()?;
+    Ok(labels
+        .iter()
+        .map(|label| LabelExport {
+            id: label.id().to_string(),
+            name: label.name().to_string(),
+            contact_ids: label.contacts().iter().cloned().collect(),
+            visible_field_ids: label.visible_fields().iter().cloned().collect(),
+            created_at: label.created_at(),
+            modified_at: label.modified_at(),
+        })
+        .collect())
+}
+
+/// Grace period core applies when scheduling a deletion.
+const DEFAULT_DELETION_GRACE_SECS: u64 = 7 * 86400;
+
+/// Shortest grace period `schedule-deletion --grace` accepts.
+const MIN_DELETION_GRACE_SECS: u64 = 86400;
+
+pub(crate) const DELETION_GRACE_FILE: &str = ".deletion_grace.json";
+
+/// Grace period chosen with `schedule-deletion --grace`.
+///
+/// Core always schedules seven days out, but the CLI gates execution on
+/// `execute_at` itself, so a custom window is kept locally next to the
+/// schedule it belongs to.
+#[derive(Debug, PartialEq, Eq, Serialize, serde::Deserialize)]
+pub(crate) struct DeletionGrace {
+    scheduled_at: u64,
+    grace_secs: u64,
+}
+
+/// Parses a `--grace` duration, rejecting anything under a day.
+pub(crate) fn parse_grace(s: &str) -> Result<u64, String> {
+    let secs = crate::commands::card::parse_duration(s)?;
+    if secs < MIN_DELETION_GRACE_SECS {
+        return Err(format!(
+            "grace period '{}' is too short: the minimum is 1 day",
+            s
         ));
     }
+    Ok(secs)
+}
 
+fn format_grace(secs: u64) -> String {
+    if secs % 86400 == 0 {
+        format!("{} days", secs / 86400)
+    } else {
+        format!("{} days, {} hours", secs / 86400, (secs % 86400) / 3600)
+    }
+}
+
+fn save_grace(config: &CliConfig, grace: &DeletionGrace) -> Result<()> {
+    crate::config::write_restricted(
+        &config.data_dir.join(DELETION_GRACE_FILE),
+        serde_json::to_string_pretty(grace)?,
+    )?;
     Ok(())
 }
 
-/// Schedules identity deletion with 7-day grace period.
-pub fn schedule_deletion(config: &CliConfig) -> Result<()> {
+fn clear_grace(config: &CliConfig) {
+    let _ = fs::remove_file(config.data_dir.join(DELETION_GRACE_FILE));
+}
+
+/// Applies a custom grace period to a scheduled deletion's `execute_at`.
+///
+/// A grace file left over from an earlier schedule is ignored.
+fn with_grace(config: &CliConfig, state: DeletionState) -> DeletionState {
+    let DeletionState::Scheduled {
+        scheduled_at,
+        execute_at,
+    } = state
+    else {
+        return state;
+    };
+    let grace: Option<DeletionGrace> =
+        fs::read_to_string(config.data_dir.join(DELETION_GRACE_FILE))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok());
+    let execute_at = match grace {
+        Some(grace) if grace.scheduled_at == scheduled_at => {
+            scheduled_at.saturating_add(grace.grace_secs)
+        }
+        _ => execute_at,
+    };
+    DeletionState::Scheduled {
+        scheduled_at,
+        execute_at,
+    }
+}
+
+/// Schedules identity deletion after a grace period (7 days by default).
+pub fn schedule_deletion(config: &CliConfig, grace_secs: Option<u64>) -> Result<()> {
     let wb = open_vauchi(config)?;
+    let grace = format_grace(grace_secs.unwrap_or(DEFAULT_DELETION_GRACE_SECS));
 
+    require_interactive(None)?;
     let confirm: String = Input::new()
-        .with_prompt(
-            "This will schedule your identity for deletion in 7 days. Type 'delete' to confirm",
-        )
+        .with_prompt(format!(
+            "This will schedule your identity for deletion in {}. Type 'delete' to confirm",
+            grace
+        ))
         .interact_text()?;
 
     if confirm.to_lowercase() != "delete" {
@@ -73,16 +243,28 @@ pub fn schedule_deletion(config: &CliConfig) -> Result<()> {
     let manager = DeletionManager::new(wb.storage());
     manager.schedule_deletion()?;
 
-    let state = manager.deletion_state()?;
+    clear_grace(config);
+    if let Some(grace_secs) = grace_secs
+        && let DeletionState::Scheduled { scheduled_at, .. } = manager.deletion_state()?
+    {
+        save_grace(
+            config,
+            &DeletionGrace {
+                scheduled_at,
+                grace_secs,
+            },
+        )?;
+    }
+
+    let state = with_grace(config, manager.deletion_state()?);
     if let DeletionState::Scheduled {
         scheduled_at,
         execute_at,
     } = state
     {
-        let days = (execute_at - scheduled_at) / 86400;
         display::warning(&format!(
-            "Identity deletion scheduled. You have {} days to cancel.",
-            days
+            "Identity deletion scheduled. You have {} to cancel.",
+            format_grace(execute_at.saturating_sub(scheduled_at))
         ));
         display::info("Run 'vauchi gdpr cancel-deletion' to cancel.");
     }
@@ -95,16 +277,120 @@ pub fn cancel_deletion(config: &CliConfig) -> Result<()> {
     let wb = open_vauchi(config)?;
     let manager = DeletionManager::new(wb.storage());
     manager.cancel_deletion()?;
+    clear_grace(config);
 
     display::success("Identity deletion cancelled.");
     Ok(())
 }
 
-/// Shows current deletion state.
-pub fn deletion_status(config: &CliConfig) -> Result<()> {
+/// File format for `--output` on the GDPR status commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+/// Deletion state as written by `deletion-status --output`.
+#[derive(Serialize)]
+struct DeletionStateRow {
+    state: &'static str,
+    scheduled_at: Option<u64>,
+    execute_at: Option<u64>,
+    executed_at: Option<u64>,
+}
+
+impl From<&DeletionState> for DeletionStateRow {
+    fn from(state: &DeletionState) -> Self {
+        let mut row = Self {
+            state: "unknown",
+            scheduled_at: None,
+            execute_at: None,
+            executed_at: None,
+        };
+        match state {
+            DeletionState::None => row.state = "none",
+            DeletionState::Scheduled {
+                scheduled_at,
+                execute_at,
+            } => {
+                row.state = "scheduled";
+                row.scheduled_at = Some(*scheduled_at);
+                row.execute_at = Some(*execute_at);
+            }
+            DeletionState::Executed { executed_at } => {
+                row.state = "executed";
+                row.executed_at = Some(*executed_at);
+            }
+            _ => {}
+        }
+        row
+    }
+}
+
+/// Consent log entry as written by `consent-status --output`.
+#[derive(Serialize)]
+struct ConsentRow {
+    consent_type: String,
+    granted: bool,
+    timestamp: u64,
+    policy_version: Option<String>,
+}
+
+/// Writes `rows` to `path` as a JSON array or CSV with `header`.
+fn write_report<T: Serialize>(
+    path: &Path,
+    format: ReportFormat,
+    rows: &[T],
+    header: &str,
+    to_csv_row: impl Fn(&T) -> String,
+) -> Result<()> {
+    let content = match format {
+        ReportFormat::Json => serde_json::to_string_pretty(rows)?,
+        ReportFormat::Csv => {
+            let mut csv = format!("{}\n", header);
+            for row in rows {
+                csv.push_str(&to_csv_row(row));
+                csv.push('\n');
+            }
+            csv
+        }
+    };
+    atomic_write_restricted(path, content)?;
+    display::success(&format!(
+        "Wrote {} record(s) to {}",
+        rows.len(),
+        path.display()
+    ));
+    Ok(())
+}
+
+fn csv_opt(value: Option<u64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Shows current deletion state, or writes it to `output` in `format`.
+pub fn deletion_status(config: &CliConfig, output: Option<(&Path, ReportFormat)>) -> Result<()> {
     let wb = open_vauchi(config)?;
     let manager = DeletionManager::new(wb.storage());
-    let state = manager.deletion_state()?;
+    let state = with_grace(config, manager.deletion_state()?);
+
+    if let Some((path, format)) = output {
+        return write_report(
+            path,
+            format,
+            &[DeletionStateRow::from(&state)],
+            "state,scheduled_at,execute_at,executed_at",
+            |r| {
+                format!(
+                    "{},{},{},{}",
+                    r.state,
+                    csv_opt(r.scheduled_at),
+                    csv_opt(r.execute_at),
+                    csv_opt(r.executed_at)
+                )
+            },
+        );
+    }
 
     match state {
         DeletionState::None => {
@@ -124,6 +410,10 @@ pub fn deletion_status(config: &CliConfig) -> Result<()> {
                 "Deletion scheduled at {} — {} days, {} hours remaining.",
                 scheduled_at, days, hours
             ));
+            println!(
+                "  Grace period: {}",
+                format_grace(execute_at.saturating_sub(scheduled_at))
+            );
             display::info("Run 'vauchi gdpr cancel-deletion' to cancel.");
         }
         DeletionState::Executed { executed_at } => {
@@ -137,11 +427,60 @@ pub fn deletion_status(config: &CliConfig) -> Result<()> {
     Ok(())
 }
 
-/// Shows consent status for all consent types.
-pub fn consent_status(config: &CliConfig) -> Result<()> {
+/// Shows consent records, optionally limited to a time window or one type.
+///
+/// `since` and `until` are ages in seconds: only records newer than `since`
+/// and older than `until` are shown. With `output`, the records are written
+/// to that file in the given format instead.
+pub fn consent_status(
+    config: &CliConfig,
+    since: Option<u64>,
+    until: Option<u64>,
+    type_str: Option<&str>,
+    output: Option<(&Path, ReportFormat)>,
+) -> Result<()> {
+    let consent_type = type_str.map(parse_consent_type).transpose()?;
+
     let wb = open_vauchi(config)?;
     let manager = ConsentManager::new(wb.storage());
-    let records = manager.export_consent_log_with_version()?;
+    let now = wb.clock().unix_seconds();
+    let earliest = since.map(|s| now.saturating_sub(s));
+    let latest = until.map(|u| now.saturating_sub(u));
+
+    let records: Vec<_> = manager
+        .export_consent_log_with_version()?
+        .into_iter()
+        .filter(|r| consent_type.as_ref().is_none_or(|t| r.consent_type == *t))
+        .filter(|r| earliest.is_none_or(|e| r.timestamp >= e))
+        .filter(|r| latest.is_none_or(|l| r.timestamp <= l))
+        .collect();
+
+    if let Some((path, format)) = output {
+        let rows: Vec<ConsentRow> = records
+            .iter()
+            .map(|r| ConsentRow {
+                consent_type: format!("{:?}", r.consent_type),
+                granted: r.granted,
+                timestamp: r.timestamp,
+                policy_version: r.policy_version.clone(),
+            })
+            .collect();
+        return write_report(
+            path,
+            format,
+            &rows,
+            "consent_type,granted,timestamp,policy_version",
+            |r| {
+                format!(
+                    "{},{},{},{}",
+                    csv_escape(&r.consent_type),
+                    r.granted,
+                    r.timestamp,
+                    csv_escape(r.policy_version.as_deref().unwrap_or(""))
+                )
+            },
+        );
+    }
 
     if records.is_empty() {
         display::info("No consent records found.");
@@ -256,7 +595,7 @@ pub async fn execute_deletion(config: &CliConfig) -> Result<()> {
     let identity = config.import_local_identity()?;
 
     let manager = DeletionManager::new(wb.storage());
-    let state = manager.deletion_state()?;
+    let state = with_grace(config, manager.deletion_state()?);
     let token = match state {
         DeletionState::Scheduled {
             scheduled_at,
@@ -284,6 +623,7 @@ pub async fn execute_deletion(config: &CliConfig) -> Result<()> {
         _ => bail!("Unknown deletion state."),
     };
 
+    require_interactive(None)?;
     let confirm: String = Input::new()
         .with_prompt(
             "This will permanently destroy all data and notify contacts. Type 'EXECUTE' to confirm",
@@ -327,6 +667,7 @@ pub async fn panic_shred(config: &CliConfig) -> Result<()> {
     let wb = open_vauchi(config)?;
     let identity = config.import_local_identity()?;
 
+    require_interactive(None)?;
     let confirm: String = Input::new()
         .with_prompt("EMERGENCY: This will immediately destroy ALL data. Type 'PANIC' to confirm")
         .interact_text()?;
@@ -416,3 +757,67 @@ fn parse_consent_type(s: &str) -> Result<ConsentType> {
         )
     })
 }
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_parse_grace_enforces_minimum() {
+        assert_eq!(parse_grace("14d"), Ok(14 * 86400));
+        assert_eq!(parse_grace("24h"), Ok(86400));
+        assert!(parse_grace("1h").unwrap_err().contains("minimum is 1 day"));
+        assert!(parse_grace("soon").is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_custom_grace_sets_execute_at() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = CliConfig {
+            data_dir: dir.path().to_path_buf(),
+            relay_url: "wss://test.example.com".to_string(),
+            ohttp_relay_url: None,
+            raw: false,
+            offline_queue_limit: None,
+        };
+        let identity =
+            vauchi_core::Identity::create("TestUser", crate::clock::shared().unix_seconds());
+        config.save_local_identity(&identity).unwrap();
+
+        let wb = open_vauchi(&config).unwrap();
+        let manager = DeletionManager::new(wb.storage());
+        manager.schedule_deletion().unwrap();
+        let DeletionState::Scheduled { scheduled_at, .. } = manager.deletion_state().unwrap()
+        else {
+            panic!("deletion should be scheduled");
+        };
+
+        // A grace file from an earlier schedule does not apply.
+        let stale = DeletionGrace {
+            scheduled_at: scheduled_at - 1,
+            grace_secs: 2 * 86400,
+        };
+        save_grace(&config, &stale).unwrap();
+        let DeletionState::Scheduled { execute_at, .. } =
+            with_grace(&config, manager.deletion_state().unwrap())
+        else {
+            panic!("deletion should be scheduled");
+        };
+        assert_eq!(execute_at, scheduled_at + DEFAULT_DELETION_GRACE_SECS);
+
+        let grace = DeletionGrace {
+            scheduled_at,
+            grace_secs: 2 * 86400,
+        };
+        save_grace(&config, &grace).unwrap();
+        let DeletionState::Scheduled { execute_at, .. } =
+            with_grace(&config, manager.deletion_state().unwrap())
+        else {
+            panic!("deletion should be scheduled");
+        };
+        assert_eq!(execute_at, scheduled_at + 2 * 86400);
+    }
+}
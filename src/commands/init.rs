@@ -14,8 +14,33 @@ use vauchi_core::{Vauchi, VauchiConfig};
 use crate::config::CliConfig;
 use crate::display;
 
+/// Maximum display-name length, in characters.
+const MAX_NAME_LEN: usize = 64;
+
+/// Applies the display-name policy, returning the trimmed name.
+///
+/// Names must be non-blank after trimming, at most [`MAX_NAME_LEN`]
+/// characters, and free of control characters.
+fn validate_display_name(name: &str) -> Result<&str> {
+    let trimmed = name.trim();
+
+    if trimmed.is_empty() {
+        bail!("Display name cannot be empty");
+    }
+    if trimmed.chars().count() > MAX_NAME_LEN {
+        bail!("Display name is too long (max {} characters)", MAX_NAME_LEN);
+    }
+    if trimmed.chars().any(char::is_control) {
+        bail!("Display name cannot contain control characters");
+    }
+
+    Ok(trimmed)
+}
+
 /// Creates a new identity.
 pub fn run(name: &str, force: bool, config: &CliConfig, locale: &str) -> Result<()> {
+    let name = validate_display_name(name)?;
+
     if config.is_initialized() && !force {
         bail!(
             "Vauchi is already initialized in {:?}. Use --force to overwrite or --data-dir for a different location.",
@@ -56,6 +81,7 @@ pub fn run(name: &str, force: bool, config: &CliConfig, locale: &str) -> Result<
         .identity()
         .ok_or_else(|| anyhow::anyhow!("Identity not found after creation"))?;
     config.save_local_identity(identity)?;
+    config.save_relay()?;
 
     let public_id = wb.public_id()?;
 
@@ -63,8 +89,65 @@ pub fn run(name: &str, force: bool, config: &CliConfig, locale: &str) -> Result<
     println!();
     println!("  Public ID: {}", public_id);
     println!("  Data dir:  {:?}", config.data_dir);
+    println!("  Relay:     {}", config.relay_url);
     println!();
     display::info("Add contact info with: vauchi card add <type> <label> <value>");
 
     Ok(())
 }
+
+/// Creates a throwaway identity in memory, for demos and tests.
+///
+/// Nothing is written to the data directory, so an existing identity is
+/// left untouched and the next invocation starts without this one.
+pub fn run_ephemeral(name: &str, config: &CliConfig) -> Result<()> {
+    let name = validate_display_name(name)?;
+
+    let mut wb = Vauchi::in_memory()?;
+    wb.create_identity(name)?;
+    let public_id = wb.public_id()?;
+
+    display::success(&format!("Identity created: {}", name));
+    println!();
+    println!("  Public ID: {}", public_id);
+    println!("  Storage:   in memory (discarded on exit)");
+    println!();
+    if config.is_initialized() {
+        display::info(&format!(
+            "Your saved identity in {:?} was not changed.",
+            config.data_dir
+        ));
+    }
+    display::warning("Nothing was saved. Later commands will not see this identity.");
+
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_validate_display_name_trims() {
+        assert_eq!(
+            validate_display_name("  Alice Smith \t").unwrap(),
+            "Alice Smith"
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_validate_display_name_length_counts_chars() {
+        assert!(validate_display_name(&"é".repeat(MAX_NAME_LEN)).is_ok());
+        assert!(validate_display_name(&"é".repeat(MAX_NAME_LEN + 1)).is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_validate_display_name_rejects_inner_newline() {
+        let err = validate_display_name("Alice\nSmith").unwrap_err();
+        assert!(err.to_string().contains("control characters"));
+    }
+}
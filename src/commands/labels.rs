@@ -6,10 +6,16 @@
 //!
 //! Manage visibility labels for organizing contacts.
 
-use anyhow::{Result, anyhow};
-use vauchi_core::Vauchi;
+use std::path::Path;
 
-use crate::commands::common::open_vauchi;
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use vauchi_core::{FieldType, FieldVisibility, Vauchi};
+
+use crate::commands::common::{
+    atomic_write, load_json_state, open_vauchi, run_batch, save_json_state,
+};
+use crate::commands::contacts::find_contact;
 use crate::config::CliConfig;
 use crate::display;
 
@@ -19,11 +25,16 @@ fn find_label(wb: &Vauchi, label_name: &str) -> Result<vauchi_core::contact::Gro
         .ok_or_else(|| anyhow!("Label not found: {}", label_name))
 }
 
-/// List all labels.
-pub fn list(config: &CliConfig, locale: &str) -> Result<()> {
+/// List all labels, or with `count_only` just how many there are.
+pub fn list(config: &CliConfig, count_only: bool, locale: &str) -> Result<()> {
     let wb = open_vauchi(config)?;
     let labels = wb.storage().labels().load_all_groups()?;
 
+    if count_only {
+        println!("{}", labels.len());
+        return Ok(());
+    }
+
     if labels.is_empty() {
         display::info("No labels defined. Create one with 'vauchi labels create <name>'");
         display::info(&format!(
@@ -149,56 +160,121 @@ pub fn rename(config: &CliConfig, label_name: &str, new_name: &str) -> Result<()
 }
 
 /// Delete a label.
-pub fn delete(config: &CliConfig, label_name: &str) -> Result<()> {
+///
+/// With `dry_run`, only reports the label and how many contacts it holds.
+pub fn delete(config: &CliConfig, label_name: &str, dry_run: bool) -> Result<()> {
     let wb = open_vauchi(config)?;
     let label = find_label(&wb, label_name)?;
 
+    if dry_run {
+        display::info(&format!(
+            "Dry run: would delete label '{}' ({}) with {} contact(s) and {} visible field(s)",
+            label.name(),
+            label.id(),
+            label.contacts().len(),
+            label.visible_fields().len()
+        ));
+        return Ok(());
+    }
+
     let name = label.name().to_string();
     wb.storage().labels().delete_group(label.id())?;
     display::success(&format!("Deleted label '{}'", name));
     Ok(())
 }
 
-/// Add a contact to a label.
-pub fn add_contact(config: &CliConfig, label_name: &str, contact_name: &str) -> Result<()> {
+/// Add contacts to a label.
+///
+/// Contacts are matched by name or ID prefix; see
+/// [`run_batch`](crate::commands::common::run_batch) for how failures are
+/// handled.
+pub fn add_contacts(
+    config: &CliConfig,
+    label_name: &str,
+    contact_names: &[String],
+    fail_fast: bool,
+) -> Result<()> {
     let wb = open_vauchi(config)?;
     let label = find_label(&wb, label_name)?;
 
-    let contact = wb
-        .find_contact_fuzzy(contact_name)?
-        .into_iter()
-        .next()
-        .ok_or_else(|| anyhow!("Contact not found: {}", contact_name))?;
-
-    wb.storage()
-        .labels()
-        .add_contact_to_group(label.id(), contact.id())?;
-    display::success(&format!(
-        "Added '{}' to label '{}'",
-        contact.display_name(),
-        label.name()
-    ));
-    Ok(())
+    run_batch(contact_names, fail_fast, |contact_name| {
+        let contact = find_contact(&wb, contact_name)?;
+        wb.storage()
+            .labels()
+            .add_contact_to_group(label.id(), contact.id())?;
+        display::success(&format!(
+            "Added '{}' to label '{}'",
+            contact.display_name(),
+            label.name()
+        ));
+        Ok(())
+    })
 }
 
-/// Remove a contact from a label.
-pub fn remove_contact(config: &CliConfig, label_name: &str, contact_name: &str) -> Result<()> {
+/// Remove contacts from a label.
+pub fn remove_contacts(
+    config: &CliConfig,
+    label_name: &str,
+    contact_names: &[String],
+    fail_fast: bool,
+) -> Result<()> {
     let wb = open_vauchi(config)?;
     let label = find_label(&wb, label_name)?;
 
-    let contact = wb
-        .find_contact_fuzzy(contact_name)?
-        .into_iter()
-        .next()
-        .ok_or_else(|| anyhow!("Contact not found: {}", contact_name))?;
+    run_batch(contact_names, fail_fast, |contact_name| {
+        let contact = find_contact(&wb, contact_name)?;
+        wb.storage()
+            .labels()
+            .remove_contact_from_group(label.id(), contact.id())?;
+        display::success(&format!(
+            "Removed '{}' from label '{}'",
+            contact.display_name(),
+            label.name()
+        ));
+        Ok(())
+    })
+}
+
+/// Moves a contact from one label to another in one step.
+///
+/// The contact is added to `to` before it leaves `from`; if leaving fails,
+/// the addition is undone so the contact is never left in neither label.
+pub fn move_contact(config: &CliConfig, contact_name: &str, from: &str, to: &str) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let contact = find_contact(&wb, contact_name)?;
+    let from = find_label(&wb, from)?;
+    let to = find_label(&wb, to)?;
+
+    if from.id() == to.id() {
+        bail!("'{}' and '{}' are the same label", from.name(), to.name());
+    }
+    let in_label =
+        |label: &vauchi_core::contact::Group| label.contacts().iter().any(|id| id == contact.id());
+    if !in_label(&from) {
+        bail!(
+            "'{}' is not in label '{}'",
+            contact.display_name(),
+            from.name()
+        );
+    }
+
+    let labels = wb.storage().labels();
+    let already_in_target = in_label(&to);
+    if !already_in_target {
+        labels.add_contact_to_group(to.id(), contact.id())?;
+    }
+    if let Err(e) = labels.remove_contact_from_group(from.id(), contact.id()) {
+        if !already_in_target {
+            labels.remove_contact_from_group(to.id(), contact.id())?;
+        }
+        return Err(e.into());
+    }
 
-    wb.storage()
-        .labels()
-        .remove_contact_from_group(label.id(), contact.id())?;
     display::success(&format!(
-        "Removed '{}' from label '{}'",
+        "Moved '{}' from label '{}' to '{}'",
         contact.display_name(),
-        label.name()
+        from.name(),
+        to.name()
     ));
     Ok(())
 }
@@ -259,6 +335,65 @@ pub fn hide_field(config: &CliConfig, label_name: &str, field_label: &str) -> Re
     Ok(())
 }
 
+/// Reports contacts whose per-contact overrides hide a field their label shows.
+pub fn check(config: &CliConfig) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let labels = wb.storage().labels().load_all_groups()?;
+    let contacts = wb.storage().contacts().list_contacts()?;
+    let Some(card) = wb.storage().contacts().load_own_card()? else {
+        display::info("No contact card found; nothing to check.");
+        return Ok(());
+    };
+
+    let mut conflicts = 0;
+    for label in &labels {
+        for contact in contacts
+            .iter()
+            .filter(|c| label.contacts().iter().any(|id| id == c.id()))
+        {
+            let Some(rules) = contact.visibility_rules() else {
+                continue;
+            };
+            for field in card
+                .fields()
+                .iter()
+                .filter(|f| label.visible_fields().iter().any(|id| id == f.id()))
+            {
+                let hidden = match rules.get(field.id()) {
+                    FieldVisibility::Everyone => false,
+                    FieldVisibility::Contacts(allowed) => {
+                        !allowed.contains(&contact.id().to_string())
+                    }
+                    FieldVisibility::Nobody => true,
+                    // A rule this CLI cannot read is not reported as a conflict.
+                    _ => continue,
+                };
+                if hidden {
+                    conflicts += 1;
+                    println!(
+                        "  {}: label '{}' shows '{}', but it is hidden from this contact",
+                        contact.display_name(),
+                        label.name(),
+                        field.label()
+                    );
+                }
+            }
+        }
+    }
+
+    if conflicts == 0 {
+        display::success("No conflicts between label and per-contact visibility");
+    } else {
+        println!();
+        display::warning(&format!(
+            "{} conflict(s). Use 'vauchi contacts unhide <contact> <field>' to follow the label.",
+            conflicts
+        ));
+    }
+
+    Ok(())
+}
+
 fn format_timestamp(ts: u64) -> String {
     use std::time::{Duration, UNIX_EPOCH};
     let dt = UNIX_EPOCH + Duration::from_secs(ts);
@@ -280,3 +415,442 @@ fn format_timestamp(ts: u64) -> String {
         format!("{} days ago", elapsed / 86400)
     }
 }
+
+/// Portable label definitions written by `labels export`.
+///
+/// Fields are referenced by their label rather than ID, since field IDs are
+/// specific to one identity. Contact membership is not included.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct LabelScheme {
+    version: u32,
+    labels: Vec<LabelDefinition>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct LabelDefinition {
+    name: String,
+    visible_fields: Vec<String>,
+}
+
+const LABEL_SCHEME_VERSION: u32 = 1;
+
+/// Export label names and field visibility to a JSON file.
+pub fn export(config: &CliConfig, output: &Path) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let labels = wb.storage().labels().load_all_groups()?;
+    let card = wb.storage().contacts().load_own_card()?;
+
+    let definitions: Vec<LabelDefinition> = labels
+        .iter()
+        .map(|label| LabelDefinition {
+            name: label.name().to_string(),
+            visible_fields: card
+                .iter()
+                .flat_map(|c| c.fields())
+                .filter(|f| label.visible_fields().iter().any(|id| id == f.id()))
+                .map(|f| f.label().to_string())
+                .collect(),
+        })
+        .collect();
+
+    let scheme = LabelScheme {
+        version: LABEL_SCHEME_VERSION,
+        labels: definitions,
+    };
+    atomic_write(output, serde_json::to_string_pretty(&scheme)?)?;
+
+    display::success(&format!(
+        "Exported {} label(s) to {}",
+        scheme.labels.len(),
+        output.display()
+    ));
+    Ok(())
+}
+
+/// Import label definitions, skipping existing names unless `overwrite` is set.
+pub fn import(config: &CliConfig, input: &Path, overwrite: bool) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let json = std::fs::read_to_string(input)
+        .with_context(|| format!("Failed to read {}", input.display()))?;
+    let scheme: LabelScheme = serde_json::from_str(&json).context("Invalid label export file")?;
+    if scheme.version != LABEL_SCHEME_VERSION {
+        return Err(anyhow!(
+            "Unsupported label export version: {}",
+            scheme.version
+        ));
+    }
+
+    let card = wb
+        .storage()
+        .contacts()
+        .load_own_card()?
+        .ok_or_else(|| anyhow!("No contact card found"))?;
+    let existing = wb.storage().labels().load_all_groups()?;
+
+    let mut imported = 0;
+    for definition in &scheme.labels {
+        let created;
+        let label = match existing
+            .iter()
+            .find(|l| l.name().eq_ignore_ascii_case(&definition.name))
+        {
+            Some(label) if !overwrite => {
+                display::info(&format!(
+                    "Skipping existing label '{}' (use --overwrite to replace)",
+                    label.name()
+                ));
+                continue;
+            }
+            Some(label) => label,
+            None => {
+                created = wb.storage().labels().create_group(&definition.name)?;
+                &created
+            }
+        };
+
+        for field in card.fields() {
+            let visible = definition
+                .visible_fields
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(field.label()));
+            let currently_visible = label.visible_fields().iter().any(|id| id == field.id());
+            if visible != currently_visible {
+                wb.storage().labels().set_group_field_visibility(
+                    label.id(),
+                    field.id(),
+                    visible,
+                )?;
+            }
+        }
+
+        for name in &definition.visible_fields {
+            if !card
+                .fields()
+                .iter()
+                .any(|f| f.label().eq_ignore_ascii_case(name))
+            {
+                display::warning(&format!(
+                    "Label '{}': field '{}' not found in your card",
+                    definition.name, name
+                ));
+            }
+        }
+
+        imported += 1;
+    }
+
+    display::success(&format!("Imported {} label(s)", imported));
+    Ok(())
+}
+
+/// Local file holding auto-apply rules; core has no label rules.
+pub(crate) const LABEL_RULES_FILE: &str = ".label_rules.json";
+
+/// Adds contacts with a card field value containing `field_contains`
+/// (case-insensitive) to the label.
+///
+/// A rule text starting with `@` matches the end of email values, so
+/// `@company.com` matches `bob@company.com` but not `bob@company.com.evil`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LabelRule {
+    label_id: String,
+    field_contains: String,
+    /// Field type the rule is limited to, as given to `card add`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    field_type: Option<String>,
+    /// Also applied to new contacts on exchange and sync, not only by
+    /// `labels auto-apply`.
+    #[serde(default)]
+    auto: bool,
+}
+
+impl LabelRule {
+    fn matches<'a>(&self, fields: impl IntoIterator<Item = (FieldType, &'a str)>) -> bool {
+        let scope = self
+            .field_type
+            .as_deref()
+            .and_then(FieldType::from_alias)
+            .map(|(field_type, _)| field_type);
+        let needle = self.field_contains.to_lowercase();
+        fields
+            .into_iter()
+            .filter(|(field_type, _)| scope.is_none_or(|scope| scope == *field_type))
+            .any(|(field_type, value)| {
+                let value = value.to_lowercase();
+                if field_type == FieldType::Email && needle.starts_with('@') {
+                    value.ends_with(&needle)
+                } else {
+                    value.contains(&needle)
+                }
+            })
+    }
+
+    fn describe(&self) -> String {
+        match &self.field_type {
+            Some(field_type) => format!("{} contains '{}'", field_type, self.field_contains),
+            None => format!("field contains '{}'", self.field_contains),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct LabelRules(Vec<LabelRule>);
+
+impl LabelRules {
+    fn load(config: &CliConfig) -> Result<Self> {
+        load_json_state(config, LABEL_RULES_FILE, "label rules")
+    }
+
+    fn save(&self, config: &CliConfig) -> Result<()> {
+        save_json_state(config, LABEL_RULES_FILE, self)
+    }
+}
+
+/// Add a rule that auto-applies a label to contacts with a matching field.
+///
+/// With `field_type`, only fields of that type are matched. With `auto`,
+/// the rule also runs on contacts added by exchange or sync.
+pub fn rule_add(
+    config: &CliConfig,
+    label_name: &str,
+    field_contains: &str,
+    field_type: Option<&str>,
+    auto: bool,
+) -> Result<()> {
+    if field_contains.trim().is_empty() {
+        bail!("--field-contains must not be empty");
+    }
+    if let Some(field_type) = field_type
+        && FieldType::from_alias(field_type).is_none()
+    {
+        bail!(
+            "Unknown field type: {}. Use: email, phone, website, address, social, or custom",
+            field_type
+        );
+    }
+    let wb = open_vauchi(config)?;
+    let label = find_label(&wb, label_name)?;
+
+    let rule = LabelRule {
+        label_id: label.id().to_string(),
+        field_contains: field_contains.to_string(),
+        field_type: field_type.map(str::to_lowercase),
+        auto,
+    };
+    let mut rules = LabelRules::load(config)?;
+    if rules.0.contains(&rule) {
+        display::info("This rule already exists.");
+        return Ok(());
+    }
+    let description = rule.describe();
+    rules.0.push(rule);
+    rules.save(config)?;
+
+    display::success(&format!(
+        "Contacts whose {} will be added to '{}'",
+        description,
+        label.name()
+    ));
+    if auto {
+        display::info("New contacts are checked on exchange and sync.");
+    }
+    display::info("Apply it to existing contacts with: vauchi labels auto-apply");
+    Ok(())
+}
+
+/// List auto-apply rules.
+pub fn rule_list(config: &CliConfig) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let rules = LabelRules::load(config)?;
+    if rules.0.is_empty() {
+        display::info(
+            "No rules defined. Add one with 'vauchi labels rule add <label> --field-contains <text>'",
+        );
+        return Ok(());
+    }
+
+    let labels = wb.storage().labels().load_all_groups()?;
+    for (i, rule) in rules.0.iter().enumerate() {
+        let name = labels
+            .iter()
+            .find(|l| l.id() == rule.label_id)
+            .map(|l| l.name())
+            .unwrap_or("(deleted label)");
+        println!(
+            "  {}. {} -> {}{}",
+            i + 1,
+            rule.describe(),
+            name,
+            if rule.auto { " (auto)" } else { "" }
+        );
+    }
+    Ok(())
+}
+
+/// Remove an auto-apply rule by its number in `labels rule list`.
+pub fn rule_remove(config: &CliConfig, number: usize) -> Result<()> {
+    let mut rules = LabelRules::load(config)?;
+    if number == 0 || number > rules.0.len() {
+        bail!("No rule {}. See 'vauchi labels rule list'", number);
+    }
+    let rule = rules.0.remove(number - 1);
+    rules.save(config)?;
+    display::success(&format!("Removed rule: {}", rule.describe()));
+    Ok(())
+}
+
+/// Add contacts matching the auto-apply rules to their labels.
+pub fn auto_apply(config: &CliConfig) -> Result<()> {
+    let wb = open_vauchi(config)?;
+    let contact_ids: Vec<String> = wb
+        .list_contacts()?
+        .iter()
+        .map(|c| c.id().to_string())
+        .collect();
+
+    let applied = apply_rules(config, &wb, &contact_ids, false)?;
+    for (contact, label) in &applied {
+        display::success(&format!("Added '{}' to label '{}'", contact, label));
+    }
+    if applied.is_empty() {
+        display::info("No contacts to add.");
+    }
+    Ok(())
+}
+
+/// Runs the `--auto` rules on a contact just added by an exchange.
+///
+/// Best-effort: the exchange already succeeded, so a failure is only
+/// reported.
+pub(crate) fn apply_rules_after_exchange(config: &CliConfig, wb: &Vauchi, contact_id: &str) {
+    match apply_rules(config, wb, &[contact_id.to_string()], true) {
+        Ok(applied) => {
+            for (contact, label) in applied {
+                display::info(&format!("Added '{}' to label '{}'", contact, label));
+            }
+        }
+        Err(e) => display::warning(&format!("Could not apply label rules: {e}")),
+    }
+}
+
+/// Adds the given contacts to the labels whose rules they match.
+///
+/// With `auto_only`, only rules added with `--auto` are used. Returns
+/// `(contact name, label name)` for each contact added; contacts already
+/// in a label, and rules whose label was deleted, are skipped.
+pub(crate) fn apply_rules(
+    config: &CliConfig,
+    wb: &Vauchi,
+    contact_ids: &[String],
+    auto_only: bool,
+) -> Result<Vec<(String, String)>> {
+    let rules = LabelRules::load(config)?;
+    let rules: Vec<&LabelRule> = rules.0.iter().filter(|r| r.auto || !auto_only).collect();
+    if rules.is_empty() || contact_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let labels = wb.storage().labels();
+    let groups = labels.load_all_groups()?;
+    let mut applied = Vec::new();
+    for contact_id in contact_ids {
+        let Some(contact) = wb.get_contact(contact_id)? else {
+            continue;
+        };
+        let fields: Vec<(FieldType, &str)> = contact
+            .card()
+            .fields()
+            .iter()
+            .map(|f| (f.field_type(), f.value()))
+            .collect();
+
+        let mut matched: Vec<&vauchi_core::contact::Group> = Vec::new();
+        for rule in &rules {
+            if let Some(group) = groups.iter().find(|g| g.id() == rule.label_id)
+                && !matched.iter().any(|g| g.id() == group.id())
+                && rule.matches(fields.iter().copied())
+            {
+                matched.push(group);
+            }
+        }
+
+        for group in matched {
+            if group.contacts().iter().any(|id| id == contact.id()) {
+                continue;
+            }
+            labels.add_contact_to_group(group.id(), contact.id())?;
+            applied.push((contact.display_name().to_string(), group.name().to_string()));
+        }
+    }
+
+    Ok(applied)
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_label_rule_matches_field_values_case_insensitively() {
+        let rule = LabelRule {
+            label_id: "work".to_string(),
+            field_contains: "@Company.com".to_string(),
+            field_type: None,
+            auto: false,
+        };
+
+        assert!(rule.matches([
+            (FieldType::Phone, "+41 79 123 45 67"),
+            (FieldType::Email, "bob@company.COM"),
+        ]));
+        assert!(!rule.matches([(FieldType::Email, "bob@home.example")]));
+    }
+
+    // @internal
+    #[test]
+    fn test_label_rule_matches_email_domain_by_suffix() {
+        let rule = LabelRule {
+            label_id: "work".to_string(),
+            field_contains: "@company.com".to_string(),
+            field_type: None,
+            auto: false,
+        };
+
+        assert!(!rule.matches([(FieldType::Email, "bob@company.com.evil.example")]));
+        assert!(rule.matches([(FieldType::Website, "https://x.example/@company.com.au")]));
+    }
+
+    // @internal
+    #[test]
+    fn test_label_rule_scoped_to_field_type() {
+        let rule = LabelRule {
+            label_id: "swiss".to_string(),
+            field_contains: "+41".to_string(),
+            field_type: Some("phone".to_string()),
+            auto: false,
+        };
+
+        assert!(rule.matches([(FieldType::Phone, "+41 79 123 45 67")]));
+        assert!(!rule.matches([(FieldType::Custom, "+41 79 123 45 67")]));
+    }
+
+    // @internal
+    #[test]
+    fn test_label_scheme_round_trips_through_json() {
+        let scheme = LabelScheme {
+            version: LABEL_SCHEME_VERSION,
+            labels: vec![LabelDefinition {
+                name: "Family".to_string(),
+                visible_fields: vec!["Mobile".to_string()],
+            }],
+        };
+
+        let json = serde_json::to_string(&scheme).unwrap();
+        assert!(!json.contains("contacts"));
+        assert_eq!(serde_json::from_str::<LabelScheme>(&json).unwrap(), scheme);
+    }
+}
@@ -14,14 +14,23 @@ pub mod device;
 pub(crate) mod device_link_persistence;
 pub mod device_replacement;
 pub mod diag;
+pub(crate) mod dns_check;
+pub mod doctor;
 pub mod duress;
 pub mod emergency;
 pub mod exchange;
+pub(crate) mod field_expiry;
+pub(crate) mod field_tags;
 pub mod gdpr;
 pub mod init;
 pub mod labels;
 pub mod onboarding;
+pub(crate) mod phone;
+pub mod qr;
 pub mod recovery;
+pub mod relay;
+pub mod social;
 pub mod support;
 pub mod sync;
 pub mod tags;
+pub mod whoami;
@@ -0,0 +1,201 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! E.164 normalization for phone card fields.
+//!
+//! There is no phone-number metadata dependency, so this only knows each
+//! region's calling code and dialing prefixes. It rewrites the number into
+//! `+<country code><national number>` without checking that the national
+//! number is actually assigned.
+
+use anyhow::{Result, bail};
+
+/// Dialing rules for a region.
+struct Region {
+    code: &'static str,
+    calling_code: &'static str,
+    /// Prefix dropped from national numbers (e.g. the `0` in `030 123`).
+    trunk_prefix: Option<&'static str>,
+    /// Prefix for dialing abroad, replaced by `+`.
+    international_prefix: &'static str,
+}
+
+const fn region(
+    code: &'static str,
+    calling_code: &'static str,
+    trunk_prefix: Option<&'static str>,
+    international_prefix: &'static str,
+) -> Region {
+    Region {
+        code,
+        calling_code,
+        trunk_prefix,
+        international_prefix,
+    }
+}
+
+const REGIONS: &[Region] = &[
+    region("AT", "43", Some("0"), "00"),
+    region("AU", "61", Some("0"), "0011"),
+    region("BE", "32", Some("0"), "00"),
+    region("BR", "55", Some("0"), "00"),
+    region("CA", "1", Some("1"), "011"),
+    region("CH", "41", Some("0"), "00"),
+    region("DE", "49", Some("0"), "00"),
+    region("DK", "45", None, "00"),
+    region("ES", "34", None, "00"),
+    region("FI", "358", Some("0"), "00"),
+    region("FR", "33", Some("0"), "00"),
+    region("GB", "44", Some("0"), "00"),
+    region("IE", "353", Some("0"), "00"),
+    region("IN", "91", Some("0"), "00"),
+    region("IT", "39", None, "00"),
+    region("JP", "81", Some("0"), "010"),
+    region("MX", "52", None, "00"),
+    region("NL", "31", Some("0"), "00"),
+    region("NO", "47", None, "00"),
+    region("NZ", "64", Some("0"), "00"),
+    region("PL", "48", None, "00"),
+    region("PT", "351", None, "00"),
+    region("SE", "46", Some("0"), "00"),
+    region("US", "1", Some("1"), "011"),
+];
+
+/// Rewrites a phone number in E.164 form (`+41791234567`).
+///
+/// Numbers starting with `+` or the region's international prefix already
+/// carry a country code; other numbers are read as national numbers of
+/// `region` (an ISO 3166 code such as `CH`), falling back to the region of
+/// the current locale.
+pub(crate) fn to_e164(value: &str, region: Option<&str>) -> Result<String> {
+    let value = value.trim();
+    let (has_plus, rest) = match value.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    if !rest
+        .chars()
+        .all(|c| c.is_ascii_digit() || " -./()".contains(c))
+    {
+        bail!("'{}' is not a phone number", value);
+    }
+    let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+
+    let number = if has_plus {
+        digits
+    } else {
+        let region = match region {
+            Some(code) => find_region(code)?,
+            None => match locale_region() {
+                Some(code) => find_region(&code)?,
+                None => bail!(
+                    "'{}' has no country code. Pass --region (e.g. --region CH) or write it with a leading +",
+                    value
+                ),
+            },
+        };
+        match digits.strip_prefix(region.international_prefix) {
+            Some(international) => international.to_string(),
+            None => {
+                let national = region
+                    .trunk_prefix
+                    .and_then(|prefix| digits.strip_prefix(prefix))
+                    .unwrap_or(&digits);
+                format!("{}{}", region.calling_code, national)
+            }
+        }
+    };
+
+    // E.164 allows at most 15 digits; anything much shorter lacks a
+    // subscriber number.
+    if !(7..=15).contains(&number.len()) {
+        bail!("'{}' is not a valid phone number", value);
+    }
+    Ok(format!("+{}", number))
+}
+
+fn find_region(code: &str) -> Result<&'static Region> {
+    match REGIONS.iter().find(|r| r.code.eq_ignore_ascii_case(code)) {
+        Some(region) => Ok(region),
+        None => bail!(
+            "Unknown region '{}'. Supported: {}",
+            code,
+            REGIONS
+                .iter()
+                .map(|r| r.code)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Region of the current locale, from `LC_ALL`, `LC_TELEPHONE` or `LANG`.
+fn locale_region() -> Option<String> {
+    crate::config::env_locale("LC_TELEPHONE").and_then(|value| territory(&value))
+}
+
+/// Extracts the territory from a POSIX locale such as `de_CH.UTF-8`.
+fn territory(locale: &str) -> Option<String> {
+    let territory = locale.split(['.', '@']).next()?.split_once(['_', '-'])?.1;
+    (territory.len() == 2 && territory.chars().all(|c| c.is_ascii_alphabetic()))
+        .then(|| territory.to_uppercase())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_national_numbers_gain_country_code() {
+        let cases = [
+            ("079 123 45 67", "CH", "+41791234567"),
+            ("030 1234567", "DE", "+49301234567"),
+            ("(202) 555-0100", "US", "+12025550100"),
+            ("1 202 555 0100", "us", "+12025550100"),
+            ("020 7946 0958", "GB", "+442079460958"),
+            ("06 12 34 56 78", "FR", "+33612345678"),
+            ("06 1234 5678", "IT", "+390612345678"),
+        ];
+        for (input, region, expected) in cases {
+            assert_eq!(to_e164(input, Some(region)).unwrap(), expected, "{input}");
+        }
+    }
+
+    // @internal
+    #[test]
+    fn test_international_numbers_keep_their_country_code() {
+        assert_eq!(
+            to_e164("+41 79 123 45 67", Some("US")).unwrap(),
+            "+41791234567"
+        );
+        assert_eq!(
+            to_e164("0041 79 123 45 67", Some("DE")).unwrap(),
+            "+41791234567"
+        );
+        assert_eq!(
+            to_e164("011 44 20 7946 0958", Some("US")).unwrap(),
+            "+442079460958"
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_rejects_invalid_numbers_and_regions() {
+        assert!(to_e164("call me", Some("CH")).is_err());
+        assert!(to_e164("+41 79", None).is_err());
+        assert!(to_e164("+1 234 567 890 123 456", None).is_err());
+        assert!(to_e164("079 123 45 67", Some("ZZ")).is_err());
+    }
+
+    // @internal
+    #[test]
+    fn test_territory_from_posix_locale() {
+        assert_eq!(territory("de_CH.UTF-8"), Some("CH".to_string()));
+        assert_eq!(territory("en-us"), Some("US".to_string()));
+        assert_eq!(territory("C.UTF-8"), None);
+        assert_eq!(territory("fr"), None);
+    }
+}
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! QR Command
+//!
+//! Renders arbitrary text (a URL, a Wi-Fi string, ...) as a QR code.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use qrcode::QrCode;
+use qrcode::render::{svg, unicode};
+
+use crate::commands::common::atomic_write;
+use crate::display;
+
+/// Prints `text` as a terminal QR code, optionally also writing an SVG file.
+pub fn run(text: &str, output: Option<&Path>) -> Result<()> {
+    let code = encode(text)?;
+
+    println!("{}", render_terminal(&code));
+
+    if let Some(path) = output {
+        let image = code.render::<svg::Color>().min_dimensions(256, 256).build();
+        atomic_write(path, image)?;
+        display::success(&format!("QR code saved to {}", path.display()));
+    }
+
+    Ok(())
+}
+
+fn encode(text: &str) -> Result<QrCode> {
+    QrCode::new(text.as_bytes())
+        .with_context(|| format!("Text is too long for a QR code ({} bytes)", text.len()))
+}
+
+/// Renders with half-height blocks so the code stays square in a terminal.
+fn render_terminal(code: &QrCode) -> String {
+    code.render::<unicode::Dense1x2>()
+        .dark_color(unicode::Dense1x2::Light)
+        .light_color(unicode::Dense1x2::Dark)
+        .build()
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_short_text_renders() {
+        let code = encode("https://vauchi.app").unwrap();
+        assert!(!render_terminal(&code).is_empty());
+    }
+
+    // @internal
+    #[test]
+    fn test_text_over_capacity_fails() {
+        let err = encode(&"x".repeat(5000)).unwrap_err();
+        assert!(err.to_string().contains("too long"));
+    }
+}
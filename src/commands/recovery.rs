@@ -7,6 +7,7 @@
 //! Contact recovery via social vouching.
 
 use std::fs;
+use std::time::Duration;
 
 use anyhow::{Result, bail};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
@@ -15,9 +16,10 @@ use vauchi_core::recovery::{
     RecoveryClaim, RecoveryProof, RecoverySettings, RecoveryVoucher, VerificationResult,
 };
 
-use crate::commands::common::open_vauchi;
+use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
 use crate::config::CliConfig;
 use crate::display;
+use crate::lock::DataDirLock;
 
 /// Creates a recovery claim for the current identity.
 ///
@@ -308,6 +310,86 @@ pub fn status(config: &CliConfig) -> Result<()> {
     Ok(())
 }
 
+/// Syncs every `interval_secs` until the recovery proof reaches its
+/// voucher threshold, printing progress whenever it changes.
+///
+/// Runs until the threshold is met or the user presses Ctrl-C. The data
+/// directory lock is only held while syncing, so vouchers can be added
+/// with `vauchi recovery add-voucher` from another terminal meanwhile. A
+/// failed sync is reported and retried on the next cycle; a cycle in which
+/// another command holds the lock skips the sync.
+pub fn watch_status(config: &CliConfig, interval_secs: u64) -> Result<()> {
+    if interval_secs == 0 {
+        bail!("Interval must be at least one second");
+    }
+
+    let claim_path = config.data_dir.join(".pending_recovery_claim");
+    let proof_path = config.data_dir.join(".recovery_proof");
+
+    if !proof_path.exists() {
+        if !claim_path.exists() {
+            bail!("No recovery in progress. Start with: vauchi recovery claim <old-pk>");
+        }
+        let claim = RecoveryClaim::from_bytes(&fs::read(&claim_path)?)?;
+        if claim.is_expired(crate::clock::shared().unix_seconds()) {
+            bail!("Recovery claim has expired. Create a new claim: vauchi recovery claim <old-pk>");
+        }
+    }
+
+    let threshold = RecoverySettings::default().recovery_threshold();
+
+    display::info(&format!(
+        "Waiting for vouchers, syncing every {}s. Press Ctrl-C to stop.",
+        interval_secs
+    ));
+    display::info("Add vouchers from another terminal: vauchi recovery add-voucher <voucher>");
+    println!();
+
+    let mut last_progress = String::new();
+    loop {
+        if let Ok(_lock) = DataDirLock::acquire(&config.data_dir) {
+            sync_once(config)?;
+        }
+
+        let proof = if proof_path.exists() {
+            Some(RecoveryProof::from_bytes(&fs::read(&proof_path)?)?)
+        } else {
+            None
+        };
+        let progress = match &proof {
+            Some(proof) => format!("{}/{}", proof.voucher_count(), proof.threshold()),
+            None => format!("0/{}", threshold),
+        };
+        if progress != last_progress {
+            println!("  Vouchers: {}", progress);
+            last_progress = progress;
+        }
+
+        if proof.as_ref().is_some_and(RecoveryProof::is_complete) {
+            println!();
+            display::success("Recovery threshold reached!");
+            display::info("Share it with your contacts: vauchi recovery proof show");
+            return Ok(());
+        }
+
+        std::thread::sleep(Duration::from_secs(interval_secs));
+    }
+}
+
+/// Runs one sync for `watch_status`, warning instead of failing when the
+/// relay cannot be reached.
+fn sync_once(config: &CliConfig) -> Result<()> {
+    let mut wb = open_vauchi(config)?;
+    let event_rx = register_activity_log_handler(&wb);
+    if let Err(e) = wb.connect() {
+        display::warning(&format!("Connection failed: {e}"));
+    } else if let Err(e) = wb.sync() {
+        display::warning(&format!("Sync failed: {e}"));
+    }
+    drain_activity_log(&wb, event_rx);
+    Ok(())
+}
+
 /// Shows the recovery proof (for sharing with contacts).
 pub fn proof_show(config: &CliConfig) -> Result<()> {
     let proof_path = config.data_dir.join(".recovery_proof");
@@ -502,8 +584,41 @@ pub fn settings_show(config: &CliConfig) -> Result<()> {
     Ok(())
 }
 
+/// Highest recovery threshold the CLI accepts.
+const MAX_RECOVERY_THRESHOLD: u32 = 10;
+
+/// Checks that `verification` is within 1..=`recovery` and `recovery` is
+/// within 1..=10.
+fn validate_thresholds(recovery: u32, verification: u32) -> Result<()> {
+    if recovery < 1 {
+        bail!("--recovery must be at least 1 (got {})", recovery);
+    }
+    if recovery > MAX_RECOVERY_THRESHOLD {
+        bail!(
+            "--recovery must be at most {} (got {})",
+            MAX_RECOVERY_THRESHOLD,
+            recovery
+        );
+    }
+    if verification < 1 {
+        bail!("--verification must be at least 1 (got {})", verification);
+    }
+    if verification > recovery {
+        bail!(
+            "--verification ({}) cannot exceed --recovery ({})",
+            verification,
+            recovery
+        );
+    }
+    Ok(())
+}
+
 /// Sets recovery settings.
-pub fn settings_set(_config: &CliConfig, recovery: u32, verification: u32) -> Result<()> {
+///
+/// Rejects out-of-range or inconsistent thresholds, and warns when the
+/// recovery threshold is more than the number of trusted contacts.
+pub fn settings_set(config: &CliConfig, recovery: u32, verification: u32) -> Result<()> {
+    validate_thresholds(recovery, verification)?;
     let _settings = RecoverySettings::new(recovery, verification)?;
 
     // TODO: Persist settings via core API when implemented
@@ -513,5 +628,16 @@ pub fn settings_set(_config: &CliConfig, recovery: u32, verification: u32) -> Re
         recovery, verification
     ));
 
+    if config.is_initialized()
+        && let Ok(wb) = open_vauchi(config)
+        && let Ok(readiness) = wb.get_recovery_readiness()
+        && (readiness.trusted_count as u64) < u64::from(recovery)
+    {
+        display::warning(&format!(
+            "You have fewer trusted contacts ({}) than the new recovery threshold ({}).",
+            readiness.trusted_count, recovery
+        ));
+    }
+
     Ok(())
 }
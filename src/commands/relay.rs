@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Relay Commands
+//!
+//! Diagnose connectivity to the configured relay, and switch relays.
+
+use anyhow::{Result, bail};
+
+use crate::commands::common::open_vauchi;
+use crate::config::{self, CliConfig};
+use crate::display;
+
+/// Connects to the relay and reports whether it is reachable.
+///
+/// Only the connection handshake (OHTTP key bootstrap) is exercised; no
+/// messages are fetched or sent.
+pub fn status(config: &CliConfig) -> Result<()> {
+    let mut wb = open_vauchi(config)?;
+
+    println!();
+    println!("  Relay:       {}", config.relay_url);
+    if let Some(ohttp) = &config.ohttp_relay_url {
+        println!("  OHTTP relay: {}", ohttp);
+    }
+
+    match wb.connect() {
+        Ok(_) => {
+            println!("  Status:      reachable");
+            println!();
+            wb.disconnect();
+            display::success("Relay handshake succeeded");
+        }
+        Err(e) => {
+            println!("  Status:      unreachable");
+            println!();
+            display::warning(&format!("Relay unreachable: {e}"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Saves `url` as the default relay for this data directory.
+///
+/// The URL must use `ws://` or `wss://`. With `check`, the relay handshake
+/// must succeed before anything is saved.
+pub fn set(config: &CliConfig, url: &str, check: bool) -> Result<()> {
+    config::validate_relay_url(url)?;
+
+    let new_config = CliConfig {
+        relay_url: url.to_string(),
+        ..config.clone()
+    };
+
+    if check {
+        let mut wb = open_vauchi(&new_config)?;
+        if let Err(e) = wb.connect() {
+            bail!("Relay unreachable, not saved: {e}");
+        }
+        wb.disconnect();
+        display::success("Relay handshake succeeded");
+    }
+
+    std::fs::create_dir_all(&config.data_dir)?;
+    new_config.save_relay()?;
+    display::success(&format!("Default relay set to {}", url));
+
+    if std::env::var("VAUCHI_RELAY_URL").is_ok_and(|v| !v.is_empty()) {
+        display::warning("VAUCHI_RELAY_URL is set and overrides the saved relay.");
+    }
+
+    Ok(())
+}
+
+/// Prints the relay in effect and where it came from.
+pub fn show(config: &CliConfig) -> Result<()> {
+    let env_relay = std::env::var("VAUCHI_RELAY_URL").ok();
+    let source = config::relay_source(&config.relay_url, env_relay.as_deref(), &config.data_dir);
+
+    if config.raw {
+        return crate::raw::print_json(&serde_json::json!({
+            "relay_url": config.relay_url,
+            "source": source.describe(),
+            "ohttp_relay_url": config.ohttp_relay_url,
+        }));
+    }
+
+    println!();
+    println!("  Relay:       {}", config.relay_url);
+    println!("  Source:      {}", source.describe());
+    if let Some(ohttp) = &config.ohttp_relay_url {
+        println!("  OHTTP relay: {}", ohttp);
+    }
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Social Network Commands
+//!
+//! Validate social usernames before relying on their profile URLs.
+
+use anyhow::{Result, bail};
+use clap::ValueEnum;
+use vauchi_core::SocialNetworkRegistry;
+
+use crate::display;
+
+/// Output format for `social url`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum UrlFormat {
+    /// The plain profile URL
+    Text,
+    /// The URL followed by a terminal QR code
+    Qr,
+}
+
+/// Username rules for a social network.
+struct UsernameRule {
+    min_len: usize,
+    max_len: usize,
+    /// Extra characters allowed besides ASCII letters and digits.
+    extra_chars: &'static str,
+    /// Whether the extra characters may start or end the username.
+    edge_extras: bool,
+}
+
+/// Fallback for networks without specific rules: anything URL-safe.
+const DEFAULT_RULE: UsernameRule = UsernameRule {
+    min_len: 1,
+    max_len: 100,
+    extra_chars: "-_.",
+    edge_extras: true,
+};
+
+/// Returns the username rule for a network ID (case-insensitive).
+fn username_rule(network: &str) -> UsernameRule {
+    let (min_len, max_len, extra_chars, edge_extras) = match network.to_lowercase().as_str() {
+        "github" => (1, 39, "-", false),
+        "gitlab" => (2, 255, "-_.", false),
+        "twitter" | "x" => (1, 15, "_", true),
+        "instagram" => (1, 30, "_.", true),
+        "linkedin" => (3, 100, "-", true),
+        "reddit" => (3, 20, "-_", true),
+        "telegram" => (5, 32, "_", false),
+        _ => return DEFAULT_RULE,
+    };
+    UsernameRule {
+        min_len,
+        max_len,
+        extra_chars,
+        edge_extras,
+    }
+}
+
+/// Checks `username` against the network's rules, returning the reason it is invalid.
+fn check_username(network: &str, username: &str) -> Option<String> {
+    let rule = username_rule(network);
+    let len = username.chars().count();
+
+    if len < rule.min_len || len > rule.max_len {
+        return Some(format!(
+            "must be {}-{} characters long",
+            rule.min_len, rule.max_len
+        ));
+    }
+
+    if let Some(bad) = username
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !rule.extra_chars.contains(*c))
+    {
+        return Some(format!("contains invalid character {:?}", bad));
+    }
+
+    if !rule.edge_extras {
+        let is_extra = |c: Option<char>| c.is_some_and(|c| rule.extra_chars.contains(c));
+        if is_extra(username.chars().next()) || is_extra(username.chars().last()) {
+            return Some(format!(
+                "must not start or end with any of {:?}",
+                rule.extra_chars
+            ));
+        }
+    }
+
+    None
+}
+
+/// Prints the profile URL for a username, as text or as a QR code.
+///
+/// An unknown network is reported but does not fail the command.
+pub fn url(network: &str, username: &str, format: UrlFormat) -> Result<()> {
+    let registry = SocialNetworkRegistry::with_defaults();
+    let Some(url) = registry.profile_url(network, username) else {
+        display::warning(&format!("Unknown network: {}", network));
+        display::info("Use 'vauchi social list' to see available networks");
+        return Ok(());
+    };
+
+    match format {
+        UrlFormat::Text => println!("{}", url),
+        UrlFormat::Qr => {
+            println!("  URL: {}", url);
+            crate::commands::qr::run(&url, None)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validates a username for a network and prints the resulting profile URL.
+pub fn validate_url(network: &str, username: &str) -> Result<()> {
+    let registry = SocialNetworkRegistry::with_defaults();
+    let Some(url) = registry.profile_url(network, username) else {
+        display::info("Use 'vauchi social list' to see available networks");
+        bail!("Unknown network: {}", network);
+    };
+
+    println!("  URL: {}", url);
+
+    if let Some(reason) = check_username(network, username) {
+        bail!("Invalid {} username '{}': {}", network, username, reason);
+    }
+
+    display::success(&format!("Valid {} username", network));
+    Ok(())
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_valid_github_handle() {
+        assert_eq!(check_username("github", "octo-cat42"), None);
+        assert_eq!(check_username("GitHub", "octocat"), None);
+    }
+
+    // @internal
+    #[test]
+    fn test_rejects_handle_with_spaces() {
+        let reason = check_username("github", "octo cat").unwrap();
+        assert!(reason.contains("invalid character"));
+    }
+
+    // @internal
+    #[test]
+    fn test_rejects_github_leading_hyphen() {
+        assert!(check_username("github", "-octocat").is_some());
+    }
+
+    // @internal
+    #[test]
+    fn test_rejects_too_long_twitter_handle() {
+        assert!(check_username("twitter", "a_very_long_handle_here").is_some());
+    }
+
+    // @internal
+    #[test]
+    fn test_unknown_network_uses_default_rule() {
+        assert_eq!(check_username("someforum", "user.name_1"), None);
+        assert!(check_username("someforum", "user/name").is_some());
+    }
+}
@@ -7,17 +7,26 @@
 //! Synchronize with the relay server using the core OHTTP HTTP sync API.
 
 use std::fs;
+use std::io::IsTerminal;
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use indicatif::{ProgressBar, ProgressStyle};
+use serde_json::{Value, json};
 use vauchi_core::api::VauchiSyncOutcome;
 use vauchi_core::types::{AhaMomentTracker, AhaMomentType};
 
 use crate::commands::common::{drain_activity_log, open_vauchi, register_activity_log_handler};
+use crate::commands::contacts::{self, WatchList};
+use crate::commands::delivery;
+use crate::commands::field_expiry;
+use crate::commands::labels;
 use crate::config::CliConfig;
 use crate::display;
 
+/// File holding the summary of the most recent sync, for `sync summary`.
+pub(crate) const LAST_SYNC_FILE: &str = ".last_sync.json";
+
 /// Runs the sync command.
 ///
 /// Delegates to `Vauchi::connect()` + `sync()` for bidirectional sync
@@ -27,23 +36,48 @@ use crate::display;
 /// - Blob fetch, ratchet-based decrypt, and ACK
 /// - Outbound update encryption and delivery
 /// - C1/C2 timing enforcement
-pub fn run(config: &CliConfig) -> Result<()> {
+///
+/// Card updates from contacts watched with `contacts watch` are called
+/// out, and contacts added by this sync are run through the `labels rule`
+/// rules added with `--auto`. With `notify`, a desktop notification is
+/// shown when updates arrived, and for each watched contact that updated.
+/// With `trim_history`, acknowledged deliveries are dropped from the
+/// delivery history afterwards.
+/// With `jsonl`, progress is printed as one JSON object per line, ending
+/// with a `summary` object.
+pub fn run(config: &CliConfig, notify: bool, trim_history: bool, jsonl: bool) -> Result<()> {
+    let reporter = Reporter { jsonl };
     let mut wb = open_vauchi(config)?;
 
     // Sync is the primary source of background events in the CLI.
     let event_rx = register_activity_log_handler(&wb);
 
-    let spinner = ProgressBar::new_spinner();
-    spinner.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.green} {msg}")
-            .unwrap(),
+    // Queue the hiding of newly expired fields so this sync sends it.
+    let hidden = field_expiry::hide_expired(config, &wb)?;
+    if hidden > 0 {
+        reporter.report(
+            Level::Info,
+            "expired_fields_hidden",
+            &format!("Stopped sharing {hidden} expired field(s)"),
+            json!({ "count": hidden }),
+        );
+    }
+
+    contacts::purge_expired_trash(config, wb.clock().unix_seconds())?;
+
+    let spinner = progress_spinner(
+        config,
+        "{spinner:.green} {msg}",
+        format!("Connecting to {}...", config.relay_url),
     );
-    spinner.set_message(format!("Connecting to {}...", config.relay_url));
-    spinner.enable_steady_tick(Duration::from_millis(80));
 
-    wb.connect()
-        .map_err(|e| anyhow::anyhow!("Connection failed: {e}"))?;
+    if let Err(e) = wb.connect() {
+        spinner.finish_and_clear();
+        let summary = json!({ "outcome": "connection_failed", "error": e.to_string() });
+        save_last_sync(config, &summary);
+        reporter.event("summary", summary);
+        bail!("Connection failed: {e}");
+    }
 
     // Real clock on purpose: `start_time` brackets the sync operation so
     // the activity window below spans the sync's actual duration. The
@@ -55,24 +89,33 @@ pub fn run(config: &CliConfig) -> Result<()> {
         .as_secs();
 
     spinner.finish_and_clear();
-    display::success("Connected");
-
-    let sync_spinner = ProgressBar::new_spinner();
-    sync_spinner.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.blue} {msg}")
-            .unwrap(),
+    reporter.report(
+        Level::Success,
+        "connected",
+        "Connected",
+        json!({ "relay": config.relay_url }),
     );
-    sync_spinner.set_message("Syncing...");
-    sync_spinner.enable_steady_tick(Duration::from_millis(80));
 
-    let outcome = wb.sync().map_err(|e| anyhow::anyhow!("Sync failed: {e}"))?;
+    // `Vauchi::sync()` reports no per-item progress, so this phase stays
+    // indeterminate until core exposes contact/device counts as it goes.
+    let sync_spinner = progress_spinner(config, "{spinner:.blue} {msg}", "Syncing...".into());
+
+    let outcome = match wb.sync() {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            sync_spinner.finish_and_clear();
+            let summary = json!({ "outcome": "sync_failed", "error": e.to_string() });
+            save_last_sync(config, &summary);
+            reporter.event("summary", summary);
+            bail!("Sync failed: {e}");
+        }
+    };
 
     sync_spinner.finish_and_clear();
 
     drain_activity_log(&wb, event_rx);
 
-    match outcome {
+    let summary = match outcome {
         VauchiSyncOutcome::Ok {
             received,
             sent,
@@ -80,8 +123,13 @@ pub fn run(config: &CliConfig) -> Result<()> {
             errors,
             ..
         } => {
-            println!();
+            reporter.blank_line();
             let total = received + sent + acknowledged;
+            let counts = json!({
+                "received": received,
+                "sent": sent,
+                "acknowledged": acknowledged,
+            });
             if total > 0 {
                 let mut summary = format!("Sync complete: {received} received");
                 if sent > 0 {
@@ -90,26 +138,53 @@ pub fn run(config: &CliConfig) -> Result<()> {
                 if acknowledged > 0 {
                     summary.push_str(&format!(", {acknowledged} acknowledged"));
                 }
-                display::success(&summary);
+                reporter.report(Level::Success, "sync_complete", &summary, counts);
             } else {
-                display::info("Sync complete: No new messages or pending updates");
+                reporter.report(
+                    Level::Info,
+                    "sync_complete",
+                    "Sync complete: No new messages or pending updates",
+                    counts,
+                );
             }
             for err in &errors {
-                display::warning(&format!("Sync error: {err}"));
+                reporter.report(
+                    Level::Warning,
+                    "sync_error",
+                    &format!("Sync error: {err}"),
+                    json!({ "error": err.to_string() }),
+                );
+            }
+            if let Some(warning) = delivery::offline_queue_warning(config, &wb) {
+                reporter.report(
+                    Level::Warning,
+                    "offline_queue_near_capacity",
+                    &warning,
+                    json!({}),
+                );
+            }
+            if notify && let Some(message) = notification_message(received) {
+                send_notification(&message);
             }
 
             let mut tracker = load_aha_tracker(config);
+            let mut moments = Vec::new();
             if received > 0
                 && let Some(moment) = tracker.try_trigger(AhaMomentType::FirstUpdateReceived)
             {
-                display::display_aha_moment(&moment);
+                moments.push(moment);
             }
             if sent > 0
                 && let Some(moment) = tracker.try_trigger(AhaMomentType::FirstOutboundDelivered)
             {
-                display::display_aha_moment(&moment);
+                moments.push(moment);
             }
             save_aha_tracker(config, &tracker);
+            if !jsonl {
+                for moment in &moments {
+                    display::display_aha_moment(moment);
+                }
+            }
 
             // Real clock on purpose: pairs with `start_time` above to
             // measure the sync's wall-clock window; the injected test
@@ -119,30 +194,279 @@ pub fn run(config: &CliConfig) -> Result<()> {
                 .unwrap_or_default()
                 .as_secs();
             let activity = wb.activity_log_poll(start_time, now)?;
-            if !activity.is_empty() {
+
+            let watched = WatchList::load(config)?;
+            let rows = activity
+                .iter()
+                .map(|row| (row.category.as_str(), row.contact_id.as_deref()));
+            for contact_id in watched.updated(rows) {
+                let name = wb
+                    .get_contact(contact_id)?
+                    .map(|c| c.display_name().to_string())
+                    .unwrap_or_else(|| contact_id.to_string());
+                let message = format!("{name} updated their card");
+                reporter.report(
+                    Level::Notice,
+                    "watched_contact_updated",
+                    &message,
+                    json!({ "contact_id": contact_id }),
+                );
+                if notify {
+                    send_notification(&message);
+                }
+            }
+
+            // Opportunistically label contacts that arrived with this sync.
+            let added: Vec<String> = activity
+                .iter()
+                .filter(|row| row.category == "contact_added")
+                .filter_map(|row| row.contact_id.clone())
+                .collect();
+            for (contact, label) in labels::apply_rules(config, &wb, &added, true)? {
+                reporter.report(
+                    Level::Info,
+                    "label_auto_applied",
+                    &format!("Added {contact} to label '{label}'"),
+                    json!({ "contact": contact, "label": label }),
+                );
+            }
+
+            if jsonl {
+                for row in &activity {
+                    reporter.event(
+                        &row.category,
+                        json!({ "contact_id": row.contact_id, "created_at": row.created_at }),
+                    );
+                }
+            } else if !activity.is_empty() {
                 println!();
                 println!("{}", console::style("Recent Activity").bold().underlined());
                 for row in activity {
                     display::display_activity_row(&row);
                 }
             }
+
+            json!({
+                "outcome": "ok",
+                "received": received,
+                "sent": sent,
+                "acknowledged": acknowledged,
+                "errors": errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            })
         }
         VauchiSyncOutcome::TooSoon => {
-            display::info("Sync skipped: too soon since last sync");
+            reporter.report(
+                Level::Info,
+                "sync_skipped",
+                "Sync skipped: too soon since last sync",
+                json!({}),
+            );
+            json!({ "outcome": "too_soon" })
         }
         VauchiSyncOutcome::NotConnected => {
-            display::warning("Not connected to relay");
+            reporter.report(
+                Level::Warning,
+                "not_connected",
+                "Not connected to relay",
+                json!({}),
+            );
+            json!({ "outcome": "not_connected" })
         }
         VauchiSyncOutcome::NoIdentity => {
-            display::warning("No identity found. Run 'vauchi init <name>' first.");
+            reporter.report(
+                Level::Warning,
+                "no_identity",
+                "No identity found. Run 'vauchi init <name>' first.",
+                json!({}),
+            );
+            json!({ "outcome": "no_identity" })
+        }
+    };
+
+    if trim_history {
+        let removed = delivery::trim_acknowledged(&wb)?;
+        let counts = json!({ "removed": removed });
+        if removed > 0 {
+            reporter.report(
+                Level::Success,
+                "trimmed",
+                &format!("Trimmed {removed} acknowledged delivery record(s) from history"),
+                counts,
+            );
+        } else {
+            reporter.report(
+                Level::Info,
+                "trimmed",
+                "No delivery history to trim",
+                counts,
+            );
         }
     }
 
     wb.disconnect();
 
+    save_last_sync(config, &summary);
+    if jsonl {
+        reporter.event("summary", summary);
+    }
+
     Ok(())
 }
 
+/// Reprints the result of the most recent sync without syncing again.
+///
+/// With `--raw`, prints the stored summary object, which adds `synced_at`
+/// (unix seconds) to the `sync --jsonl` summary.
+pub fn summary(config: &CliConfig) -> Result<()> {
+    let Some(last) = fs::read_to_string(config.data_dir.join(LAST_SYNC_FILE))
+        .ok()
+        .and_then(|text| serde_json::from_str::<Value>(&text).ok())
+    else {
+        display::info("No sync has run yet. Run 'vauchi sync' first.");
+        return Ok(());
+    };
+
+    if config.raw {
+        return crate::raw::print_json(&last);
+    }
+
+    let synced_at = last["synced_at"]
+        .as_i64()
+        .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+        .map(|d| d.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let outcome = last["outcome"].as_str().unwrap_or("unknown");
+
+    println!();
+    println!("  Last sync: {}", synced_at);
+    println!("  Outcome:   {}", outcome);
+    if outcome == "ok" {
+        println!(
+            "  Received:  {}, sent: {}, acknowledged: {}",
+            last["received"], last["sent"], last["acknowledged"]
+        );
+    }
+    if let Some(error) = last["error"].as_str() {
+        println!("  Error:     {}", error);
+    }
+    for error in last["errors"].as_array().into_iter().flatten() {
+        println!("  Error:     {}", error.as_str().unwrap_or_default());
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Records `summary` as the most recent sync result.
+///
+/// Best effort: a sync is not failed because its summary could not be kept.
+fn save_last_sync(config: &CliConfig, summary: &Value) {
+    let mut record = summary.clone();
+    record["synced_at"] = crate::clock::shared().unix_seconds().into();
+    if let Ok(json) = serde_json::to_string_pretty(&record) {
+        let _ = crate::config::write_restricted(&config.data_dir.join(LAST_SYNC_FILE), json);
+    }
+}
+
+/// Severity of a status line in the terminal output.
+enum Level {
+    Success,
+    Info,
+    Warning,
+    /// Stands out from the other lines, e.g. for a watched contact.
+    Notice,
+}
+
+/// Sends sync progress to the terminal, or to stdout as JSON Lines.
+struct Reporter {
+    jsonl: bool,
+}
+
+impl Reporter {
+    /// Reports a status line, or with `jsonl` an `event` object carrying
+    /// `message` and the members of `data`.
+    fn report(&self, level: Level, event: &str, message: &str, mut data: Value) {
+        if self.jsonl {
+            data["message"] = message.into();
+            self.event(event, data);
+            return;
+        }
+        match level {
+            Level::Success => display::success(message),
+            Level::Info => display::info(message),
+            Level::Warning => display::warning(message),
+            Level::Notice => display::notice(message),
+        }
+    }
+
+    /// Prints `data` as one JSON line tagged with `event` (JSON Lines only).
+    fn event(&self, event: &str, mut data: Value) {
+        if !self.jsonl {
+            return;
+        }
+        data["event"] = event.into();
+        println!("{data}");
+    }
+
+    fn blank_line(&self) {
+        if !self.jsonl {
+            println!();
+        }
+    }
+}
+
+/// Creates a ticking spinner, or a hidden one for `--raw` and non-TTY runs
+/// so piped output carries no progress artifacts.
+fn progress_spinner(config: &CliConfig, template: &str, message: String) -> ProgressBar {
+    if config.raw || !std::io::stderr().is_terminal() {
+        return ProgressBar::hidden();
+    }
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(ProgressStyle::default_spinner().template(template).unwrap());
+    spinner.set_message(message);
+    spinner.enable_steady_tick(Duration::from_millis(80));
+    spinner
+}
+
+/// Summarizes a completed sync for a desktop notification, or `None` when
+/// nothing arrived.
+fn notification_message(received: usize) -> Option<String> {
+    if received == 0 {
+        return None;
+    }
+    Some(format!("{received} new update(s) from your contacts"))
+}
+
+/// Shows a desktop notification using the platform's notifier.
+///
+/// Best-effort: headless sessions and missing notifiers are silently
+/// skipped so `--notify` never fails a sync.
+fn send_notification(message: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut c = std::process::Command::new("osascript");
+        c.arg("-e").arg(format!(
+            "display notification {:?} with title \"Vauchi\"",
+            message
+        ));
+        c
+    } else if cfg!(target_os = "windows") {
+        return;
+    } else {
+        if std::env::var_os("DISPLAY").is_none() && std::env::var_os("WAYLAND_DISPLAY").is_none() {
+            return;
+        }
+        let mut c = std::process::Command::new("notify-send");
+        c.arg("Vauchi").arg(message);
+        c
+    };
+
+    let _ = command
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status();
+}
+
 /// Load the aha moment tracker from the data directory.
 fn load_aha_tracker(config: &CliConfig) -> AhaMomentTracker {
     let path = config.data_dir.join("aha_tracker.json");
@@ -159,3 +483,43 @@ fn save_aha_tracker(config: &CliConfig, tracker: &AhaMomentTracker) {
         let _ = crate::config::write_restricted(&path, json);
     }
 }
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_notification_skipped_when_nothing_received() {
+        assert_eq!(notification_message(0), None);
+    }
+
+    // @internal
+    #[test]
+    fn test_trim_with_no_deliveries_removes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = CliConfig {
+            data_dir: dir.path().to_path_buf(),
+            relay_url: "wss://test.example.com".to_string(),
+            ohttp_relay_url: None,
+            raw: false,
+            offline_queue_limit: None,
+        };
+        let identity =
+            vauchi_core::Identity::create("TestUser", crate::clock::shared().unix_seconds());
+        config.save_local_identity(&identity).unwrap();
+
+        let wb = open_vauchi(&config).unwrap();
+        assert_eq!(delivery::trim_acknowledged(&wb).unwrap(), 0);
+    }
+
+    // @internal
+    #[test]
+    fn test_notification_summarizes_received_updates() {
+        assert_eq!(
+            notification_message(2).as_deref(),
+            Some("2 new update(s) from your contacts")
+        );
+    }
+}
@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Whoami Command
+//!
+//! Shows who you are to Vauchi and, optionally, hands out your card in one
+//! step.
+
+use std::path::Path;
+
+use anyhow::Result;
+use vauchi_core::contact_card::vcard::export_vcard;
+
+use crate::commands::common::{atomic_write, open_vauchi};
+use crate::commands::field_expiry;
+use crate::config::CliConfig;
+use crate::display;
+
+/// Shows your name, public ID and this device.
+///
+/// With `export_card`, also writes your card as a vCard to that path; with
+/// `qr`, prints the vCard as a QR code to scan with any phone. Fields whose
+/// `--expires` date has passed are left out of both, as in an exchange.
+pub fn run(config: &CliConfig, export_card: Option<&Path>, qr: bool) -> Result<()> {
+    let wb = open_vauchi(config)?;
+
+    let identity = wb
+        .identity()
+        .ok_or_else(|| anyhow::anyhow!("No identity found"))?;
+    let card = wb
+        .own_card()?
+        .ok_or_else(|| anyhow::anyhow!("No contact card found"))?;
+
+    println!();
+    println!("  Name:      {}", card.display_name());
+    println!("  Public ID: {}", wb.public_id()?);
+    println!("  Device:    {}", identity.device_info().device_name());
+    println!("  Fields:    {}", card.fields().len());
+    println!();
+
+    if export_card.is_none() && !qr {
+        display::info("Share your card with: vauchi whoami --qr");
+        return Ok(());
+    }
+
+    let shared = field_expiry::without_expired(
+        &card,
+        &field_expiry::ExpiryStore::load(config)?,
+        wb.clock().unix_seconds(),
+    )?;
+    let vcard = export_vcard(&shared);
+
+    if let Some(path) = export_card {
+        atomic_write(path, &vcard)?;
+        display::success(&format!("Card saved to {}", path.display()));
+    }
+
+    if qr {
+        crate::commands::qr::run(&vcard, None)?;
+        display::info("Scan with a phone camera to add this card to its contacts.");
+    }
+
+    Ok(())
+}
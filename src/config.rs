@@ -34,6 +34,96 @@ pub fn write_restricted(path: &Path, data: impl AsRef<[u8]>) -> anyhow::Result<(
     Ok(())
 }
 
+/// Relay used when none is given on the command line, in the environment,
+/// or saved by `init`.
+pub const DEFAULT_RELAY_URL: &str = "wss://relay.vauchi.app";
+
+/// File in the data directory holding the relay chosen at `init`.
+const SAVED_RELAY_FILE: &str = ".relay_url";
+
+/// Where the relay URL for this run came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelaySource {
+    Flag,
+    Env,
+    Saved,
+    Default,
+}
+
+impl RelaySource {
+    /// Human-readable name of the source.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Flag => "--relay flag",
+            Self::Env => "VAUCHI_RELAY_URL",
+            Self::Saved => "saved config",
+            Self::Default => "default",
+        }
+    }
+}
+
+/// Resolves the relay URL for this run.
+///
+/// Precedence: `--relay` flag, then `VAUCHI_RELAY_URL` (both arrive as
+/// `explicit`), then the relay saved by `init` or `relay set` in
+/// `data_dir`, then [`DEFAULT_RELAY_URL`].
+pub fn resolve_relay_url(explicit: Option<String>, data_dir: &Path) -> String {
+    explicit
+        .or_else(|| saved_relay_url(data_dir))
+        .unwrap_or_else(|| DEFAULT_RELAY_URL.to_string())
+}
+
+/// Works out which source [`resolve_relay_url`] took `relay_url` from.
+///
+/// The flag and the environment variable reach the CLI as one value, so a
+/// `--relay` that repeats `env_relay` is reported as the environment.
+pub fn relay_source(relay_url: &str, env_relay: Option<&str>, data_dir: &Path) -> RelaySource {
+    let env_relay = env_relay.filter(|s| !s.is_empty());
+    if env_relay == Some(relay_url) {
+        return RelaySource::Env;
+    }
+    if env_relay.is_some() {
+        return RelaySource::Flag;
+    }
+    match saved_relay_url(data_dir) {
+        Some(saved) if saved == relay_url => RelaySource::Saved,
+        None if relay_url == DEFAULT_RELAY_URL => RelaySource::Default,
+        _ => RelaySource::Flag,
+    }
+}
+
+/// Checks that `url` is a `ws://` or `wss://` URL with a host.
+pub fn validate_relay_url(url: &str) -> Result<()> {
+    let Some(rest) = url
+        .strip_prefix("wss://")
+        .or_else(|| url.strip_prefix("ws://"))
+    else {
+        anyhow::bail!("Invalid relay URL '{}': use a ws:// or wss:// URL", url);
+    };
+    if rest.split('/').next().unwrap_or_default().is_empty() {
+        anyhow::bail!("Invalid relay URL '{}': missing host", url);
+    }
+    Ok(())
+}
+
+/// Returns the POSIX locale in effect for `category` (e.g. `LC_MESSAGES`).
+///
+/// Follows the precedence `LC_ALL`, `category`, `LANG`, skipping unset and
+/// empty variables.
+pub fn env_locale(category: &str) -> Option<String> {
+    ["LC_ALL", category, "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+fn saved_relay_url(data_dir: &Path) -> Option<String> {
+    std::fs::read_to_string(data_dir.join(SAVED_RELAY_FILE))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
 /// CLI configuration.
 #[derive(Debug, Clone)]
 pub struct CliConfig {
@@ -47,6 +137,9 @@ pub struct CliConfig {
     pub ohttp_relay_url: Option<String>,
     /// Output raw JSON instead of formatted text.
     pub raw: bool,
+    /// Queued updates from which `sync` and card edits warn that the offline
+    /// queue is full; `None` uses the capacity core reports.
+    pub offline_queue_limit: Option<u32>,
 }
 
 /// Key name used for SecureStorage (non-keychain path).
@@ -152,6 +245,20 @@ impl CliConfig {
         self.identity_path().exists()
     }
 
+    /// Saves the current relay so later commands default to it.
+    ///
+    /// Saving the default relay clears any previously saved one instead.
+    pub fn save_relay(&self) -> Result<()> {
+        let path = self.data_dir.join(SAVED_RELAY_FILE);
+        if self.relay_url == DEFAULT_RELAY_URL {
+            if path.exists() {
+                std::fs::remove_file(&path)?;
+            }
+            return Ok(());
+        }
+        write_restricted(&path, &self.relay_url)
+    }
+
     /// Returns the per-installation backup password for identity persistence.
     pub fn backup_password(&self) -> Result<String> {
         load_or_generate_backup_password(&self.data_dir)
@@ -268,6 +375,90 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    fn config_with_relay(data_dir: &Path, relay_url: &str) -> CliConfig {
+        CliConfig {
+            data_dir: data_dir.to_path_buf(),
+            relay_url: relay_url.to_string(),
+            ohttp_relay_url: None,
+            raw: false,
+            offline_queue_limit: None,
+        }
+    }
+
+    // @internal
+    #[test]
+    fn test_resolve_relay_prefers_explicit_over_saved() {
+        let dir = tempdir().unwrap();
+        config_with_relay(dir.path(), "ws://saved")
+            .save_relay()
+            .unwrap();
+
+        assert_eq!(
+            resolve_relay_url(Some("ws://flag".to_string()), dir.path()),
+            "ws://flag"
+        );
+        assert_eq!(resolve_relay_url(None, dir.path()), "ws://saved");
+    }
+
+    // @internal
+    #[test]
+    fn test_resolve_relay_falls_back_to_default() {
+        let dir = tempdir().unwrap();
+        assert_eq!(resolve_relay_url(None, dir.path()), DEFAULT_RELAY_URL);
+
+        config_with_relay(dir.path(), "ws://saved")
+            .save_relay()
+            .unwrap();
+        config_with_relay(dir.path(), DEFAULT_RELAY_URL)
+            .save_relay()
+            .unwrap();
+        assert_eq!(resolve_relay_url(None, dir.path()), DEFAULT_RELAY_URL);
+    }
+
+    // @internal
+    #[test]
+    fn test_relay_source_follows_precedence() {
+        let dir = tempdir().unwrap();
+        assert_eq!(
+            relay_source(DEFAULT_RELAY_URL, None, dir.path()),
+            RelaySource::Default
+        );
+        assert_eq!(
+            relay_source("ws://flag", None, dir.path()),
+            RelaySource::Flag
+        );
+        assert_eq!(
+            relay_source("ws://env", Some("ws://env"), dir.path()),
+            RelaySource::Env
+        );
+        assert_eq!(
+            relay_source("ws://flag", Some("ws://env"), dir.path()),
+            RelaySource::Flag
+        );
+
+        config_with_relay(dir.path(), "ws://saved")
+            .save_relay()
+            .unwrap();
+        assert_eq!(
+            relay_source("ws://saved", None, dir.path()),
+            RelaySource::Saved
+        );
+        assert_eq!(
+            relay_source(DEFAULT_RELAY_URL, None, dir.path()),
+            RelaySource::Flag
+        );
+    }
+
+    // @internal
+    #[test]
+    fn test_validate_relay_url_requires_ws_scheme_and_host() {
+        assert!(validate_relay_url("wss://relay.example.com").is_ok());
+        assert!(validate_relay_url("ws://127.0.0.1:8080/path").is_ok());
+        assert!(validate_relay_url("http://relay.example.com").is_err());
+        assert!(validate_relay_url("relay.example.com").is_err());
+        assert!(validate_relay_url("wss://").is_err());
+    }
+
     /// Creates a v2 backup encrypted with `password` without enforcing password
     /// strength. Tests use this to simulate legacy backups that were created
     /// before core started rejecting weak passwords.
@@ -295,6 +486,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let key = config.storage_key().expect("should create key");
@@ -314,6 +506,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let key1 = config.storage_key().expect("should create key");
@@ -372,6 +565,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let password = config.backup_password().expect("should generate password");
@@ -387,6 +581,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let pw1 = config.backup_password().unwrap();
@@ -403,12 +598,14 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
         let config2 = CliConfig {
             data_dir: temp2.path().to_path_buf(),
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let pw1 = config1.backup_password().unwrap();
@@ -424,6 +621,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let identity = Identity::create("Test User", crate::clock::shared().unix_seconds());
@@ -454,6 +652,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let identity = Identity::create("Test User", crate::clock::shared().unix_seconds());
@@ -471,6 +670,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
 
         let identity = Identity::create("Migration User", crate::clock::shared().unix_seconds());
@@ -503,6 +703,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
         let key1 = config1.storage_key().expect("should create key");
 
@@ -511,6 +712,7 @@ mod tests {
             relay_url: "ws://localhost:8080".to_string(),
             ohttp_relay_url: None,
             raw: false,
+            offline_queue_limit: None,
         };
         let key2 = config2.storage_key().expect("should load key");
 
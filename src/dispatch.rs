@@ -8,10 +8,12 @@ use std::io;
 
 use anyhow::Result;
 use clap::CommandFactory;
-use clap_complete::generate;
+use clap_complete::{Shell, generate};
+use clap_complete_nushell::Nushell;
 
 use crate::args::*;
 use crate::commands;
+use crate::commands::common::check_overwrite;
 use crate::config::CliConfig;
 use crate::display;
 
@@ -23,16 +25,73 @@ pub(crate) async fn run(
     locale: &str,
 ) -> Result<()> {
     match command {
-        Commands::Init { name, force } => {
-            commands::init::run(&name, force, config, locale)?;
+        Commands::Init {
+            name,
+            force,
+            ephemeral,
+        } => {
+            if ephemeral {
+                commands::init::run_ephemeral(&name, config)?;
+            } else {
+                commands::init::run(&name, force, config, locale)?;
+            }
         }
         Commands::Card(cmd) => match cmd {
-            CardCommands::Show => commands::card::show(config)?,
+            CardCommands::Show {
+                fields,
+                mask,
+                age,
+                stale_after,
+                qr_each,
+            } => {
+                let stale_after =
+                    age.then(|| stale_after.unwrap_or(commands::card::DEFAULT_STALE_AFTER_SECS));
+                commands::card::show(config, &fields, mask, stale_after, qr_each)?
+            }
+            CardCommands::Qr {
+                label,
+                output,
+                force,
+            } => {
+                if let Some(path) = &output {
+                    check_overwrite(path, force)?;
+                }
+                commands::card::qr(config, &label, output.as_deref())?
+            }
             CardCommands::Add {
                 field_type,
                 label,
                 value,
+                stdin,
+                from_clipboard,
+                validate_dns,
+                strict,
+                replace,
+                visibility,
+                expires,
+                normalize,
+                region,
+                tag,
+                prompt_missing,
+                prompt_values,
             } => {
+                let value = if stdin {
+                    Some(commands::card::read_stdin_value()?)
+                } else if from_clipboard {
+                    Some(commands::card::read_clipboard()?)
+                } else {
+                    value
+                };
+
+                let (field_type, label, value) = if prompt_missing {
+                    let (t, l, v) =
+                        commands::card::prompt_missing(field_type, label, value, prompt_values)?;
+                    (t, Some(l), Some(v))
+                } else {
+                    // clap requires TYPE unless --prompt-missing is given
+                    (field_type.unwrap_or_default(), label, value)
+                };
+
                 // Social fields support interactive prompting when label/value
                 // are omitted: `vauchi card add social`
                 let is_social = vauchi_core::FieldType::from_alias(&field_type)
@@ -41,7 +100,17 @@ pub(crate) async fn run(
 
                 match (label, value) {
                     (Some(l), Some(v)) => {
-                        commands::card::add(config, &field_type, &l, &v)?;
+                        let options = commands::card::AddFieldOptions {
+                            validate_dns,
+                            strict,
+                            replace,
+                            visibility,
+                            expires,
+                            normalize,
+                            region: region.as_deref(),
+                            tag: tag.as_deref(),
+                        };
+                        commands::card::add(config, &field_type, &l, &v, &options)?;
                     }
                     (None, None) if is_social => {
                         commands::card::add_social_interactive(config)?;
@@ -61,6 +130,11 @@ pub(crate) async fn run(
             CardCommands::Remove { label } => {
                 commands::card::remove(config, &label)?;
             }
+            CardCommands::PruneExpired => commands::card::prune_expired(config)?,
+            CardCommands::Lint { fix } => commands::card::lint(config, fix)?,
+            CardCommands::Reorder { label, up, .. } => {
+                commands::card::reorder(config, &label, up)?;
+            }
             CardCommands::Edit { label, value } => {
                 commands::card::edit(config, &label, &value)?;
             }
@@ -70,8 +144,9 @@ pub(crate) async fn run(
         },
         Commands::Exchange(cmd) => match cmd {
             ExchangeSubcommand::Start => commands::exchange::start(config, locale)?,
-            ExchangeSubcommand::Complete { data } => {
-                commands::exchange::complete(config, &data, locale)?;
+            ExchangeSubcommand::Payload => commands::exchange::payload(config)?,
+            ExchangeSubcommand::Complete { data, no_relay } => {
+                commands::exchange::complete(config, &data, no_relay, locale)?;
             }
             ExchangeSubcommand::Usb { address } => {
                 commands::exchange::usb_exchange(config, &address)?;
@@ -85,19 +160,33 @@ pub(crate) async fn run(
                 offset,
                 limit,
                 archived,
+                count_only,
             } => {
-                if archived {
+                if count_only {
+                    commands::contacts::count(config, pin, offset, limit, archived)?;
+                } else if archived {
                     commands::contacts::list_archived(config, locale)?;
                 } else {
                     commands::contacts::list(config, pin, offset, limit, locale)?;
                 }
             }
-            ContactCommands::Show { id } => commands::contacts::show(config, pin, &id)?,
+            ContactCommands::Show {
+                id,
+                raw_keys,
+                history,
+            } => commands::contacts::show(config, pin, &id, raw_keys, history)?,
             ContactCommands::Search { query } => {
                 commands::contacts::search(config, pin, &query, locale)?
             }
-            ContactCommands::Remove { id } => commands::contacts::remove(config, &id)?,
-            ContactCommands::Verify { id } => commands::contacts::verify(config, &id)?,
+            ContactCommands::Remove { id, dry_run } => {
+                commands::contacts::remove(config, &id, dry_run)?
+            }
+            ContactCommands::ResendCard { id } => commands::contacts::resend_card(config, &id)?,
+            ContactCommands::Verify { id, list, batch } => match (id, batch) {
+                (_, Some(file)) => commands::contacts::verify_batch(config, &file)?,
+                (Some(id), None) if !list => commands::contacts::verify(config, &id)?,
+                _ => commands::contacts::list_unverified(config)?,
+            },
             ContactCommands::Hide { contact, field } => {
                 commands::contacts::hide_field(config, &contact, &field)?;
             }
@@ -107,9 +196,16 @@ pub(crate) async fn run(
             ContactCommands::Visibility { contact } => {
                 commands::contacts::show_visibility(config, &contact, locale)?;
             }
-            ContactCommands::Open { contact, field } => {
+            ContactCommands::ShowShared { contact } => {
+                commands::contacts::show_shared(config, &contact)?;
+            }
+            ContactCommands::Open {
+                contact,
+                field,
+                copy,
+            } => {
                 if let Some(field_label) = field {
-                    commands::contacts::open_field(config, &contact, &field_label)?;
+                    commands::contacts::open_field(config, &contact, &field_label, copy)?;
                 } else {
                     commands::contacts::open_interactive(config, &contact)?;
                 }
@@ -144,12 +240,89 @@ pub(crate) async fn run(
             ContactCommands::Unfavorite { id } => {
                 commands::contacts::unfavorite(config, &id)?;
             }
-            ContactCommands::Export { id, output } => {
-                commands::contacts::export(config, &id, output.to_str().unwrap())?;
+            ContactCommands::Watch { id } => {
+                commands::contacts::watch(config, &id)?;
+            }
+            ContactCommands::Unwatch { id } => {
+                commands::contacts::unwatch(config, &id)?;
+            }
+            ContactCommands::MoveLabel { contact, from, to } => {
+                commands::labels::move_contact(config, &contact, &from, &to)?;
+            }
+            ContactCommands::Export {
+                output_dir: Some(dir),
+                since,
+                format,
+                vcard_version,
+                force,
+                ..
+            } => {
+                commands::contacts::export_by_label(
+                    config,
+                    &dir,
+                    since,
+                    format,
+                    vcard_version,
+                    force,
+                )?;
+            }
+            ContactCommands::Export {
+                id,
+                output,
+                output_file,
+                qr_sheet,
+                label,
+                anonymize,
+                since,
+                format,
+                vcard_version,
+                force,
+                ..
+            } => match (qr_sheet, id, output, output_file.zip(since)) {
+                (Some(sheet), id, _, _) => {
+                    check_overwrite(&sheet, force)?;
+                    commands::contacts::export_qr_sheet(
+                        config,
+                        id.as_deref(),
+                        label.as_deref(),
+                        since,
+                        &sheet,
+                    )?;
+                }
+                (None, _, _, Some((path, since))) => {
+                    check_overwrite(&path, force)?;
+                    commands::contacts::export_since(config, since, &path, format, vcard_version)?;
+                }
+                (None, Some(id), Some(output), None) => {
+                    check_overwrite(&output, force)?;
+                    commands::contacts::export(
+                        config,
+                        &id,
+                        output.to_str().unwrap(),
+                        anonymize,
+                        format,
+                        vcard_version,
+                    )?;
+                }
+                _ => unreachable!("clap requires id and output without --qr-sheet or --output"),
+            },
+            ContactCommands::ExportKeys {
+                output,
+                format,
+                force,
+            } => {
+                check_overwrite(&output, force)?;
+                commands::contacts::export_keys(config, &output, format)?;
+            }
+            ContactCommands::Graph { format } => {
+                commands::contacts::graph(config, format)?;
             }
             ContactCommands::ImportVcf { file } => {
                 commands::contacts::import_vcf(config, &file)?;
             }
+            ContactCommands::ImportBackup { file, password } => {
+                commands::contacts::import_from_backup(config, &file, password.as_deref())?;
+            }
             ContactCommands::AddNote { id, note } => {
                 commands::contacts::add_note(config, &id, &note)?;
             }
@@ -168,6 +341,9 @@ pub(crate) async fn run(
             ContactCommands::Duplicates => {
                 commands::contacts::duplicates(config)?;
             }
+            ContactCommands::Dedupe => {
+                commands::contacts::dedupe(config)?;
+            }
             ContactCommands::DismissDuplicate { contact1, contact2 } => {
                 commands::contacts::dismiss_duplicate(config, &contact1, &contact2)?;
             }
@@ -177,6 +353,12 @@ pub(crate) async fn run(
             ContactCommands::Limit { set } => {
                 commands::contacts::limit(config, set, locale)?;
             }
+            ContactCommands::Birthdays { within } => {
+                commands::contacts::birthdays(config, within)?;
+            }
+            ContactCommands::PruneUnverified { older_than, yes } => {
+                commands::contacts::prune_unverified(config, older_than, yes)?;
+            }
             ContactCommands::Delete { id, yes } => {
                 commands::contacts::delete(config, &id, yes)?;
             }
@@ -186,42 +368,71 @@ pub(crate) async fn run(
             ContactCommands::Unarchive { id } => {
                 commands::contacts::unarchive(config, &id)?;
             }
+            ContactCommands::Restore { id } => {
+                commands::contacts::restore(config, &id)?;
+            }
+            ContactCommands::Trash(cmd) => match cmd {
+                ContactTrashCommands::List => commands::contacts::list_trash(config)?,
+                ContactTrashCommands::Empty => commands::contacts::empty_trash(config)?,
+            },
         },
         Commands::Social(cmd) => match cmd {
             SocialCommands::List { query } => {
                 display::display_social_networks(query.as_deref(), locale);
             }
-            SocialCommands::Url { network, username } => {
-                use vauchi_core::SocialNetworkRegistry;
-                let registry = SocialNetworkRegistry::with_defaults();
-                match registry.profile_url(&network, &username) {
-                    Some(url) => println!("{}", url),
-                    None => {
-                        display::warning(&format!("Unknown network: {}", network));
-                        display::info("Use 'vauchi social list' to see available networks");
-                    }
-                }
+            SocialCommands::Url {
+                network,
+                username,
+                format,
+            } => {
+                commands::social::url(&network, &username, format)?;
+            }
+            SocialCommands::ValidateUrl { network, username } => {
+                commands::social::validate_url(&network, &username)?;
             }
         },
         Commands::Device(cmd) => match cmd {
-            DeviceCommands::List => commands::device::list(config, locale)?,
+            DeviceCommands::List { revoked, all } => {
+                commands::device::list(config, revoked, all, locale)?
+            }
             DeviceCommands::Info => commands::device::info(config)?,
-            DeviceCommands::Link => commands::device::link(config)?,
+            DeviceCommands::Link { transport } => {
+                commands::device::link(config, transport.file()?)?
+            }
             DeviceCommands::Join {
                 qr_data,
+                transport,
                 device_name,
                 yes,
-            } => commands::device::join(config, &qr_data, device_name.as_deref(), yes)?,
+            } => {
+                let file = transport.file()?;
+                let qr_data = commands::device::link_input(qr_data, file)?;
+                commands::device::join(config, &qr_data, device_name.as_deref(), yes, file)?
+            }
             DeviceCommands::Complete {
                 request,
+                transport,
                 yes,
                 replace,
-            } => commands::device::complete(config, &request, yes, replace)?,
+            } => {
+                let file = transport.file()?;
+                let request = commands::device::link_input(request, file)?;
+                commands::device::complete(config, &request, yes, replace, file)?
+            }
             DeviceCommands::Decommission { yes } => commands::device::decommission(config, yes)?,
-            DeviceCommands::Finish { response } => commands::device::finish(config, &response)?,
-            DeviceCommands::Revoke { device_id, yes } => {
-                commands::device::revoke(config, &device_id, yes)?
+            DeviceCommands::Finish {
+                response,
+                transport,
+            } => {
+                let response = commands::device::link_input(response, transport.file()?)?;
+                commands::device::finish(config, &response)?
             }
+            DeviceCommands::Revoke {
+                device_id,
+                reason,
+                yes,
+            } => commands::device::revoke(config, &device_id, reason.as_deref(), yes)?,
+            DeviceCommands::History => commands::device::history(config)?,
             DeviceCommands::Replace(cmd) => match cmd {
                 DeviceReplaceCommands::Setup => {
                     commands::device_replacement::run_setup()?;
@@ -235,25 +446,60 @@ pub(crate) async fn run(
             },
         },
         Commands::Labels(cmd) => match cmd {
-            LabelCommands::List => commands::labels::list(config, locale)?,
+            LabelCommands::List { count_only } => {
+                commands::labels::list(config, count_only, locale)?
+            }
             LabelCommands::Create { name } => commands::labels::create(config, &name)?,
             LabelCommands::Show { label } => commands::labels::show(config, &label, locale)?,
             LabelCommands::Rename { label, new_name } => {
                 commands::labels::rename(config, &label, &new_name)?
             }
-            LabelCommands::Delete { label } => commands::labels::delete(config, &label)?,
-            LabelCommands::AddContact { label, contact } => {
-                commands::labels::add_contact(config, &label, &contact)?
-            }
-            LabelCommands::RemoveContact { label, contact } => {
-                commands::labels::remove_contact(config, &label, &contact)?
+            LabelCommands::Delete { label, dry_run } => {
+                commands::labels::delete(config, &label, dry_run)?
             }
+            LabelCommands::AddContact {
+                label,
+                contacts,
+                fail_fast,
+            } => commands::labels::add_contacts(config, &label, &contacts, fail_fast)?,
+            LabelCommands::RemoveContact {
+                label,
+                contacts,
+                fail_fast,
+            } => commands::labels::remove_contacts(config, &label, &contacts, fail_fast)?,
             LabelCommands::ShowField { label, field } => {
                 commands::labels::show_field(config, &label, &field)?
             }
             LabelCommands::HideField { label, field } => {
                 commands::labels::hide_field(config, &label, &field)?
             }
+            LabelCommands::Check => commands::labels::check(config)?,
+            LabelCommands::Rule(cmd) => match cmd {
+                LabelRuleCommands::Add {
+                    label,
+                    field_contains,
+                    field_type,
+                    auto,
+                } => commands::labels::rule_add(
+                    config,
+                    &label,
+                    &field_contains,
+                    field_type.as_deref(),
+                    auto,
+                )?,
+                LabelRuleCommands::List => commands::labels::rule_list(config)?,
+                LabelRuleCommands::Remove { number } => {
+                    commands::labels::rule_remove(config, number)?
+                }
+            },
+            LabelCommands::AutoApply => commands::labels::auto_apply(config)?,
+            LabelCommands::Export { output, force } => {
+                check_overwrite(&output, force)?;
+                commands::labels::export(config, &output)?
+            }
+            LabelCommands::Import { input, overwrite } => {
+                commands::labels::import(config, &input, overwrite)?
+            }
         },
         Commands::Tags(cmd) => match cmd {
             TagCommands::List => commands::tags::list(config)?,
@@ -274,7 +520,13 @@ pub(crate) async fn run(
             RecoveryCommands::AddVoucher { voucher } => {
                 commands::recovery::add_voucher(config, &voucher)?
             }
-            RecoveryCommands::Status => commands::recovery::status(config)?,
+            RecoveryCommands::Status { watch, interval } => {
+                if watch {
+                    commands::recovery::watch_status(config, interval)?
+                } else {
+                    commands::recovery::status(config)?
+                }
+            }
             RecoveryCommands::Proof => commands::recovery::proof_show(config)?,
             RecoveryCommands::Verify { proof } => commands::recovery::verify(config, &proof)?,
             RecoveryCommands::Settings(settings_cmd) => match settings_cmd {
@@ -289,43 +541,98 @@ pub(crate) async fn run(
         },
         Commands::Delivery(cmd) => match cmd {
             DeliveryCommands::Status => commands::delivery::status(config)?,
-            DeliveryCommands::List { status } => {
-                commands::delivery::list(config, status.as_deref())?
+            DeliveryCommands::List { status, count_only } => {
+                commands::delivery::list(config, status.as_deref(), count_only)?
+            }
+            DeliveryCommands::Stats { by_recipient } => {
+                commands::delivery::stats(config, by_recipient)?
             }
             DeliveryCommands::Retry => commands::delivery::retry(config)?,
             DeliveryCommands::Cleanup => commands::delivery::cleanup(config)?,
+            DeliveryCommands::Explain { message_id } => {
+                commands::delivery::explain(config, &message_id)?
+            }
             DeliveryCommands::Translate { reason } => commands::delivery::translate(&reason)?,
         },
-        Commands::Sync => {
-            commands::sync::run(config)?;
+        Commands::Sync {
+            command: Some(SyncCommands::Summary),
+            ..
+        } => commands::sync::summary(config)?,
+        Commands::Sync {
+            command: None,
+            notify,
+            trim_delivery_history,
+            jsonl,
+        } => {
+            commands::sync::run(config, notify, trim_delivery_history, jsonl)?;
         }
+        Commands::Relay(cmd) => match cmd {
+            RelayCommands::Status => commands::relay::status(config)?,
+            RelayCommands::Show => commands::relay::show(config)?,
+            RelayCommands::Set { url, check } => commands::relay::set(config, &url, check)?,
+        },
+        Commands::Doctor { network: true, .. } => commands::doctor::network(config)?,
+        Commands::Doctor { repair, .. } => commands::doctor::run(config, repair)?,
         Commands::Activity { since } => {
             commands::activity::run(config, since.unwrap_or(60))?;
         }
-        Commands::Export { output, full } => {
-            if full {
-                commands::backup::export_full(config, &output)?;
+        Commands::Export {
+            output,
+            full,
+            split,
+            password,
+            force,
+            verify,
+        } => {
+            let password = password.as_deref();
+            if split {
+                commands::backup::export_split(config, &output, password, force, verify)?;
             } else {
-                commands::backup::export(config, &output)?;
+                check_overwrite(&output, force)?;
+                if full {
+                    commands::backup::export_full(config, &output, password)?;
+                } else {
+                    commands::backup::export(config, &output, password, verify)?;
+                }
             }
         }
-        Commands::Import { input, full } => {
+        Commands::Import {
+            input,
+            full,
+            split,
+            password,
+        } => {
+            let password = password.as_deref();
             if full {
-                commands::backup::import_full(config, &input)?;
+                commands::backup::import_full(config, &input, password)?;
+            } else if split {
+                commands::backup::import_split(config, &input, password)?;
             } else {
-                commands::backup::import(config, &input)?;
+                commands::backup::import(config, &input, password)?;
             }
         }
         Commands::Completions { shell } => {
             let mut cmd = Cli::command();
-            generate(shell, &mut cmd, "vauchi", &mut io::stdout());
+            let out = &mut io::stdout();
+            match shell {
+                CompletionShell::Bash => generate(Shell::Bash, &mut cmd, "vauchi", out),
+                CompletionShell::Elvish => generate(Shell::Elvish, &mut cmd, "vauchi", out),
+                CompletionShell::Fish => generate(Shell::Fish, &mut cmd, "vauchi", out),
+                CompletionShell::Nushell => generate(Nushell, &mut cmd, "vauchi", out),
+                CompletionShell::Powershell => generate(Shell::PowerShell, &mut cmd, "vauchi", out),
+                CompletionShell::Zsh => generate(Shell::Zsh, &mut cmd, "vauchi", out),
+            }
         }
         Commands::Gdpr(cmd) => match cmd {
             GdprCommands::Export {
                 output,
                 encrypt,
                 password,
+                include_devices,
+                include_labels,
+                force,
             } => {
+                check_overwrite(&output, force)?;
                 let password = if let Some(pw) = password {
                     // Hidden --password flag or env var (for scripted/test use)
                     Some(pw)
@@ -338,7 +645,13 @@ pub(crate) async fn run(
                 } else {
                     None
                 };
-                commands::gdpr::export_data(config, &output, password.as_deref())?;
+                commands::gdpr::export_data(
+                    config,
+                    &output,
+                    password.as_deref(),
+                    include_devices,
+                    include_labels,
+                )?;
             }
             GdprCommands::ExecuteDeletion => {
                 commands::gdpr::execute_deletion(config).await?;
@@ -346,17 +659,42 @@ pub(crate) async fn run(
             GdprCommands::PanicShred => {
                 commands::gdpr::panic_shred(config).await?;
             }
-            GdprCommands::ScheduleDeletion => {
-                commands::gdpr::schedule_deletion(config)?;
+            GdprCommands::ScheduleDeletion { grace } => {
+                commands::gdpr::schedule_deletion(config, grace)?;
             }
             GdprCommands::CancelDeletion => {
                 commands::gdpr::cancel_deletion(config)?;
             }
-            GdprCommands::DeletionStatus => {
-                commands::gdpr::deletion_status(config)?;
+            GdprCommands::DeletionStatus {
+                output,
+                format,
+                force,
+            } => {
+                if let Some(path) = &output {
+                    check_overwrite(path, force)?;
+                }
+                let output = output.as_deref().map(|path| (path, format));
+                commands::gdpr::deletion_status(config, output)?;
             }
-            GdprCommands::ConsentStatus => {
-                commands::gdpr::consent_status(config)?;
+            GdprCommands::ConsentStatus {
+                since,
+                until,
+                consent_type,
+                output,
+                format,
+                force,
+            } => {
+                if let Some(path) = &output {
+                    check_overwrite(path, force)?;
+                }
+                let output = output.as_deref().map(|path| (path, format));
+                commands::gdpr::consent_status(
+                    config,
+                    since,
+                    until,
+                    consent_type.as_deref(),
+                    output,
+                )?;
             }
             GdprCommands::GrantConsent { consent_type } => {
                 commands::gdpr::grant_consent(config, &consent_type)?;
@@ -382,7 +720,7 @@ pub(crate) async fn run(
         },
         Commands::Emergency(cmd) => match cmd {
             EmergencyCommands::Configure => commands::emergency::configure(config)?,
-            EmergencyCommands::Send => commands::emergency::send(config)?,
+            EmergencyCommands::Send { force } => commands::emergency::send(config, force)?,
             EmergencyCommands::Status => commands::emergency::status(config)?,
             EmergencyCommands::Disable => commands::emergency::disable(config)?,
         },
@@ -401,6 +739,16 @@ pub(crate) async fn run(
             }
         },
         Commands::SupportUs => commands::support::run(locale),
+        Commands::Qr {
+            text,
+            output,
+            force,
+        } => {
+            if let Some(path) = &output {
+                check_overwrite(path, force)?;
+            }
+            commands::qr::run(&text, output.as_deref())?
+        }
         Commands::Diag(cmd) => match cmd {
             commands::diag::DiagCommands::Trace { file } => commands::diag::trace(&file, locale)?,
             commands::diag::DiagCommands::AnimatedQr(qr_cmd) => match qr_cmd {
@@ -414,6 +762,16 @@ pub(crate) async fn run(
         Commands::Onboarding => {
             commands::onboarding::run()?;
         }
+        Commands::Whoami {
+            export_card,
+            qr,
+            force,
+        } => {
+            if let Some(path) = &export_card {
+                check_overwrite(path, force)?;
+            }
+            commands::whoami::run(config, export_card.as_deref(), qr)?;
+        }
     }
 
     Ok(())
@@ -6,17 +6,65 @@
 //!
 //! Terminal output formatting and styling.
 
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
 use console::{Style, style};
 use tabled::{
     Table, Tabled,
-    settings::{Alignment, Modify, Style as TableStyle, object::Columns},
+    settings::{Alignment, Modify, Style as TableStyle, Width, object::Columns},
 };
 use vauchi_app::notification_types::ActivityLogEntry as AppActivityEntry;
 use vauchi_core::storage::ActivityLogRow;
-use vauchi_core::{Contact, ContactCard, FieldType, SocialNetworkRegistry};
+use vauchi_core::{Contact, ContactCard, ContactField, FieldType, SocialNetworkRegistry};
+
+/// Global `--quiet` gate for decorative status lines.
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppresses [`success`], [`warning`], [`info`] and [`notice`] output when
+/// `quiet` is set.
+///
+/// Errors and explicitly requested data (including `--raw` JSON) still print.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Global `--max-width`; 0 means the detected terminal width.
+static MAX_WIDTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Width used when none is given and stdout is not a terminal.
+const FALLBACK_MAX_WIDTH: usize = 80;
+
+/// Caps the width of boxes, rules, wrapped text and tables.
+///
+/// `None` uses the terminal width, detected when output is printed.
+pub fn set_max_width(width: Option<usize>) {
+    MAX_WIDTH.store(width.unwrap_or(0), Ordering::Relaxed);
+}
+
+fn max_width() -> usize {
+    match MAX_WIDTH.load(Ordering::Relaxed) {
+        0 => console::Term::stdout()
+            .size_checked()
+            .map(|(_, cols)| usize::from(cols))
+            .unwrap_or(FALLBACK_MAX_WIDTH),
+        width => width,
+    }
+}
+
+/// Returns `preferred`, shrunk to fit within the maximum output width.
+fn width(preferred: usize) -> usize {
+    preferred.min(max_width())
+}
 
 /// Prints a success message.
 pub fn success(msg: &str) {
+    if is_quiet() {
+        return;
+    }
     println!("{} {}", style("✓").green().bold(), msg);
 }
 
@@ -27,14 +75,32 @@ pub fn error(msg: &str) {
 
 /// Prints a warning message.
 pub fn warning(msg: &str) {
+    if is_quiet() {
+        return;
+    }
     println!("{} {}", style("⚠").yellow().bold(), msg);
 }
 
 /// Prints an info message.
 pub fn info(msg: &str) {
+    if is_quiet() {
+        return;
+    }
     println!("{} {}", style("ℹ").blue().bold(), msg);
 }
 
+/// Prints a message that stands out from the other status lines.
+pub fn notice(msg: &str) {
+    if is_quiet() {
+        return;
+    }
+    println!(
+        "{} {}",
+        style("★").yellow().bold(),
+        style(msg).yellow().bold()
+    );
+}
+
 /// Returns the platform-neutral icon token for a field type.
 ///
 /// Delegates to [`FieldType::icon`] in `vauchi-core` so the CLI never
@@ -46,8 +112,63 @@ pub fn field_icon(field_type: FieldType) -> &'static str {
 
 /// Displays a contact card in a formatted box.
 pub fn display_card(card: &ContactCard) {
-    let name = card.display_name();
-    let width = 50;
+    let fields: Vec<&ContactField> = card.fields().iter().collect();
+    display_card_fields(card.display_name(), &fields, false, |_| None);
+}
+
+/// Partially redacts a field value for screen-sharing.
+///
+/// Emails keep the first character of the local part and domain plus the
+/// top-level domain (`a***@b***.com`), phones keep their last four digits,
+/// and every other type keeps only its first character.
+pub fn mask_value(field_type: FieldType, value: &str) -> String {
+    fn first_char_masked(s: &str) -> String {
+        match s.chars().next() {
+            Some(c) => format!("{}***", c),
+            None => "***".to_string(),
+        }
+    }
+
+    match field_type {
+        FieldType::Email => match value.split_once('@') {
+            Some((local, domain)) => {
+                let (host, tld) = match domain.rsplit_once('.') {
+                    Some((host, tld)) => (host, format!(".{}", tld)),
+                    None => (domain, String::new()),
+                };
+                format!(
+                    "{}@{}{}",
+                    first_char_masked(local),
+                    first_char_masked(host),
+                    tld
+                )
+            }
+            None => first_char_masked(value),
+        },
+        FieldType::Phone => {
+            let digits: Vec<char> = value.chars().filter(|c| c.is_ascii_digit()).collect();
+            if digits.len() <= 4 {
+                "***".to_string()
+            } else {
+                let last4: String = digits[digits.len() - 4..].iter().collect();
+                format!("***{}", last4)
+            }
+        }
+        _ => first_char_masked(value),
+    }
+}
+
+/// Displays a card header and the given subset of its fields in a formatted box.
+///
+/// With `mask`, field values are partially redacted via [`mask_value`].
+/// A custom tag returned by `tag_of` is shown after the field's type icon.
+pub fn display_card_fields(
+    name: &str,
+    fields: &[&ContactField],
+    mask: bool,
+    tag_of: impl Fn(&ContactField) -> Option<String>,
+) {
+    let width = width(50);
     let registry = SocialNetworkRegistry::with_defaults();
 
     println!("{}", "─".repeat(width));
@@ -56,38 +177,34 @@ pub fn display_card(card: &ContactCard) {
 
     println!("{}", "─".repeat(width));
 
-    if card.fields().is_empty() {
+    if fields.is_empty() {
         println!("  {}", style("(no fields)").dim());
     } else {
-        for field in card.fields() {
-            let icon = field_icon(field.field_type());
+        for field in fields {
+            let icon = match tag_of(*field) {
+                Some(tag) => format!("{} {}", field_icon(field.field_type()), tag),
+                None => field_icon(field.field_type()).to_string(),
+            };
             let label_style = Style::new().dim();
+            let value = if mask {
+                mask_value(field.field_type(), field.value())
+            } else {
+                field.value().to_string()
+            };
+
+            println!(
+                "  {:6} {:12} {}",
+                icon,
+                label_style.apply_to(field.label()),
+                value
+            );
 
-            if field.field_type() == FieldType::Social {
+            // A profile URL would reveal the username, so skip it when masking.
+            if field.field_type() == FieldType::Social && !mask {
                 let label_lower = field.label().to_lowercase();
                 if let Some(url) = registry.profile_url(&label_lower, field.value()) {
-                    println!(
-                        "  {:6} {:12} {}",
-                        icon,
-                        label_style.apply_to(field.label()),
-                        field.value()
-                    );
                     println!("         {:12} {}", "", style(&url).dim().underlined());
-                } else {
-                    println!(
-                        "  {:6} {:12} {}",
-                        icon,
-                        label_style.apply_to(field.label()),
-                        field.value()
-                    );
                 }
-            } else {
-                println!(
-                    "  {:6} {:12} {}",
-                    icon,
-                    label_style.apply_to(field.label()),
-                    field.value()
-                );
             }
         }
     }
@@ -146,6 +263,38 @@ pub fn display_contact_details(contact: &Contact) {
     println!();
 }
 
+/// Displays a contact's cryptographic identity data (`contacts show --raw-keys`).
+pub fn display_contact_keys(contact: &Contact) {
+    println!("  {}", style("Keys").bold());
+    match contact.public_key() {
+        Some(pk) => println!("  Public Key:  {}", hex::encode(pk)),
+        None => println!("  Public Key:  {}", style("(none, imported contact)").dim()),
+    }
+    println!("  Fingerprint: {}", contact.fingerprint());
+    let exchanged = chrono::DateTime::from_timestamp(contact.exchange_timestamp() as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "unknown".into());
+    println!(
+        "  Exchanged:   {} ({})",
+        exchanged,
+        contact.exchange_timestamp()
+    );
+    println!();
+}
+
+/// Displays a contact's activity log entries, oldest first.
+pub fn display_contact_history(rows: &[ActivityLogRow]) {
+    println!("  {}", style("History").bold());
+    if rows.is_empty() {
+        println!("  {}", style("(no recorded activity)").dim());
+    }
+    for row in rows {
+        print!("  ");
+        display_activity_row(row);
+    }
+    println!();
+}
+
 /// Displays the list of available social networks.
 pub fn display_social_networks(query: Option<&str>, locale: &str) {
     let registry = SocialNetworkRegistry::with_defaults();
@@ -174,7 +323,7 @@ pub fn display_social_networks(query: Option<&str>, locale: &str) {
 
     println!();
     println!("{}", style("Available Social Networks").bold());
-    println!("{}", "─".repeat(50));
+    println!("{}", "─".repeat(width(50)));
     println!();
 
     let mut printed = 0;
@@ -196,7 +345,7 @@ pub fn display_social_networks(query: Option<&str>, locale: &str) {
     }
 
     println!();
-    println!("{}", "─".repeat(50));
+    println!("{}", "─".repeat(width(50)));
     let cmd = style("vauchi card add social").cyan().to_string();
     let network_placeholder = style("<network>").yellow().to_string();
     let username_placeholder = style("<username>").yellow().to_string();
@@ -266,6 +415,7 @@ pub fn display_contacts_table(contacts: &[Contact]) {
     let table = Table::new(rows)
         .with(TableStyle::rounded())
         .with(Modify::new(Columns::first()).with(Alignment::right()))
+        .with(Width::truncate(max_width()))
         .to_string();
 
     println!("{}", table);
@@ -316,23 +466,31 @@ pub fn display_faqs(query: Option<&str>, locale: &str) {
         t("help.faq", locale)
     };
     println!("{}", style(title).bold());
-    println!("{}", "─".repeat(60));
+    println!("{}", "─".repeat(width(60)));
     println!();
 
     for faq in faqs {
-        println!("{}", style(&faq.question).cyan().bold());
-        for line in wrap_text(&faq.answer, 60) {
-            println!("  {}", line);
-        }
+        print_faq(&faq.question, &faq.answer);
         println!();
     }
 }
 
+/// Prints a FAQ question and its indented answer, wrapped to the output width.
+fn print_faq(question: &str, answer: &str) {
+    let width = width(60);
+    for line in wrap_text(question, width) {
+        println!("{}", style(line).cyan().bold());
+    }
+    for line in wrap_text(answer, width.saturating_sub(2)) {
+        println!("  {}", line);
+    }
+}
+
 /// Displays FAQ categories.
 pub fn display_faq_categories(locale: &str) {
     println!();
     println!("{}", style(t("help.faq", locale)).bold());
-    println!("{}", "─".repeat(40));
+    println!("{}", "─".repeat(width(40)));
     println!();
 
     let categories = [
@@ -355,7 +513,7 @@ pub fn display_faq_categories(locale: &str) {
     }
 
     println!();
-    println!("{}", "─".repeat(40));
+    println!("{}", "─".repeat(width(40)));
     let help_cmd = style("vauchi help category <name>").cyan().to_string();
     println!(
         "{}",
@@ -398,14 +556,11 @@ pub fn display_faqs_by_category(category_name: &str, locale: &str) {
         style(t("help.faq", locale)).bold(),
         style(cat.display_name()).cyan()
     );
-    println!("{}", "─".repeat(60));
+    println!("{}", "─".repeat(width(60)));
     println!();
 
     for faq in faqs {
-        println!("{}", style(&faq.question).cyan().bold());
-        for line in wrap_text(&faq.answer, 60) {
-            println!("  {}", line);
-        }
+        print_faq(&faq.question, &faq.answer);
         println!();
     }
 }
@@ -422,12 +577,9 @@ pub fn display_faq_by_id(id: &str, locale: &str) {
                 style(t("help.faq", locale)).bold(),
                 style(&faq.id).dim()
             );
-            println!("{}", "─".repeat(60));
+            println!("{}", "─".repeat(width(60)));
             println!();
-            println!("{}", style(&faq.question).cyan().bold());
-            for line in wrap_text(&faq.answer, 60) {
-                println!("  {}", line);
-            }
+            print_faq(&faq.question, &faq.answer);
             if !faq.related.is_empty() {
                 println!();
                 println!("  Related: {}", faq.related.join(", "));
@@ -445,7 +597,9 @@ use vauchi_core::aha_moments::AhaMoment;
 
 /// Displays an aha moment as a highlighted info box.
 pub fn display_aha_moment(moment: &AhaMoment) {
-    let border = "─".repeat(50);
+    // Inner width, between the two border characters.
+    let inner = width(52).saturating_sub(2);
+    let border = "─".repeat(inner);
     let top = format!("┌{}┐", border);
     let bottom = format!("└{}┘", border);
 
@@ -455,11 +609,11 @@ pub fn display_aha_moment(moment: &AhaMoment) {
         "│ {} {}{}│",
         style("★").magenta().bold(),
         style(moment.title()).magenta().bold(),
-        " ".repeat(50 - 3 - moment.title().len())
+        " ".repeat(inner.saturating_sub(3 + moment.title().len()))
     );
-    println!("│{}│", " ".repeat(50));
-    for line in wrap_text(&moment.message(), 46) {
-        let padding = 48 - line.len();
+    println!("│{}│", " ".repeat(inner));
+    for line in wrap_text(&moment.message(), inner.saturating_sub(4)) {
+        let padding = inner.saturating_sub(2 + line.len());
         println!("│  {}{}│", line, " ".repeat(padding));
     }
     println!("{}", style(&bottom).magenta());
@@ -570,6 +724,29 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn test_mask_value_email_keeps_first_chars_and_tld() {
+        assert_eq!(
+            mask_value(FieldType::Email, "alice@company.com"),
+            "a***@c***.com"
+        );
+    }
+
+    #[test]
+    fn test_mask_value_phone_keeps_last_four_digits() {
+        assert_eq!(mask_value(FieldType::Phone, "+1-555-123-4567"), "***4567");
+        assert_eq!(mask_value(FieldType::Phone, "123"), "***");
+    }
+
+    #[test]
+    fn test_mask_value_other_types_keep_first_char() {
+        assert_eq!(
+            mask_value(FieldType::Address, "1 Main St, Springfield"),
+            "1***"
+        );
+        assert_eq!(mask_value(FieldType::Website, ""), "***");
+    }
+
     #[test]
     fn test_wrap_text_empty_input() {
         let result = wrap_text("", 40);
@@ -641,6 +818,15 @@ mod tests {
             prop_assert!(lines.is_empty());
         }
 
+        /// Masked values never contain the full original value.
+        #[test]
+        fn prop_mask_value_hides_original(value in "[a-z0-9@.+-]{6,40}") {
+            for ft in [FieldType::Email, FieldType::Phone, FieldType::Custom] {
+                let masked = mask_value(ft, &value);
+                prop_assert!(!masked.contains(value.as_str()));
+            }
+        }
+
         /// Never panics on any input.
         #[test]
         fn prop_wrap_text_never_panics(
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2026 Mattia Egloff <mattia.egloff@pm.me>
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Data directory lock.
+//!
+//! Two vauchi processes writing the same data directory at once can corrupt
+//! the SQLite store or the JSON side files. Each run holds an advisory lock
+//! on `<data_dir>/.lock` until it exits; a second run fails fast instead of
+//! waiting.
+
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+
+/// Lock file name inside the data directory.
+const LOCK_FILE: &str = ".lock";
+
+/// Exclusive lock on a data directory, released when dropped.
+#[derive(Debug)]
+pub struct DataDirLock {
+    _file: File,
+}
+
+impl DataDirLock {
+    /// Takes the lock on `data_dir`, failing if another process holds it.
+    pub fn acquire(data_dir: &Path) -> Result<Self> {
+        let path = data_dir.join(LOCK_FILE);
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+
+        match file.try_lock() {
+            Ok(()) => Ok(Self { _file: file }),
+            Err(TryLockError::WouldBlock) => bail!(
+                "Another vauchi process is using {}. Wait for it to finish, or pass --no-lock for read-only commands.",
+                data_dir.display()
+            ),
+            Err(TryLockError::Error(e)) => {
+                Err(e).with_context(|| format!("Failed to lock {}", path.display()))
+            }
+        }
+    }
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_second_acquire_fails_while_held() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let held = DataDirLock::acquire(dir.path()).unwrap();
+        let err = DataDirLock::acquire(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("Another vauchi process"));
+
+        drop(held);
+        assert!(DataDirLock::acquire(dir.path()).is_ok());
+    }
+}
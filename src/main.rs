@@ -12,6 +12,7 @@ mod commands;
 mod config;
 mod dispatch;
 mod display;
+mod lock;
 mod raw;
 mod ui;
 
@@ -19,9 +20,9 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use clap::Parser;
-use vauchi_app::i18n::init as init_i18n;
+use vauchi_app::i18n::{Locale, init as init_i18n};
 
-use args::Cli;
+use args::{Cli, Commands, RecoveryCommands};
 use config::CliConfig;
 
 /// Try to load runtime locale files so user-visible strings can be translated.
@@ -65,12 +66,51 @@ fn try_init_i18n() {
     let _ = init_i18n(Path::new("locales"));
 }
 
+/// Picks the output locale from the environment when none was given.
+///
+/// Follows the POSIX precedence `LC_ALL`, `LC_MESSAGES`, `LANG`; unset,
+/// `C`/`POSIX` and unsupported locales fall back to English.
+fn detect_locale() -> String {
+    config::env_locale("LC_MESSAGES")
+        .map(|value| locale_code(&value))
+        .unwrap_or_else(|| "en".to_string())
+}
+
+/// Whether `command` takes the data directory lock itself.
+///
+/// `recovery status --watch` waits for vouchers added from another
+/// terminal, so it only holds the lock while it syncs.
+fn locks_itself(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Recovery(RecoveryCommands::Status { watch: true, .. })
+    )
+}
+
+/// Maps a POSIX locale such as `de_DE.UTF-8` to a supported locale code.
+fn locale_code(value: &str) -> String {
+    let language = value
+        .split(['_', '-', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    if Locale::from_code(&language).is_some() {
+        language
+    } else {
+        "en".to_string()
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     env_logger::init();
     try_init_i18n();
 
     let cli = Cli::parse();
+    let locale = cli.locale.unwrap_or_else(detect_locale);
+    display::set_quiet(cli.quiet);
+    display::set_max_width(cli.max_width.map(usize::from));
+    raw::set_pretty(cli.json_pretty);
 
     let data_dir = cli.data_dir.unwrap_or_else(|| {
         dirs::data_dir()
@@ -78,12 +118,39 @@ async fn main() -> Result<()> {
             .join("vauchi")
     });
 
+    let relay_url = config::resolve_relay_url(cli.relay, &data_dir);
+
     let config = CliConfig {
         data_dir,
-        relay_url: cli.relay,
+        relay_url,
         ohttp_relay_url: cli.ohttp_relay,
         raw: cli.raw,
+        offline_queue_limit: cli.offline_queue_limit,
+    };
+
+    // Before `init` there is no data directory to protect (or create yet).
+    let _lock = if cli.no_lock || !config.data_dir.is_dir() || locks_itself(&cli.command) {
+        None
+    } else {
+        Some(lock::DataDirLock::acquire(&config.data_dir)?)
     };
 
-    dispatch::run(cli.command, &config, cli.pin.as_deref(), &cli.locale).await
+    dispatch::run(cli.command, &config, cli.pin.as_deref(), &locale).await
+}
+
+// INLINE_TEST_REQUIRED: Binary crate without lib.rs - tests cannot be external
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @internal
+    #[test]
+    fn test_locale_code_maps_posix_locales() {
+        assert_eq!(locale_code("de_DE.UTF-8"), "de");
+        assert_eq!(locale_code("fr_CH"), "fr");
+        assert_eq!(locale_code("es"), "es");
+        assert_eq!(locale_code("C.UTF-8"), "en");
+        assert_eq!(locale_code("POSIX"), "en");
+        assert_eq!(locale_code("xx_YY.UTF-8"), "en");
+    }
 }
@@ -7,8 +7,10 @@
 //! Provides serializable views of core types that exclude internal/crypto
 //! fields. Used when `--raw` flag is passed.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use serde::Serialize;
-use vauchi_core::{Contact, ContactCard};
+use vauchi_core::{Contact, ContactCard, ContactField};
 
 /// Serializable view of a [`Contact`] — excludes crypto fields.
 #[derive(Serialize)]
@@ -51,21 +53,37 @@ impl From<&ContactCard> for CardJson {
     fn from(card: &ContactCard) -> Self {
         Self {
             display_name: card.display_name().to_string(),
-            fields: card
-                .fields()
-                .iter()
-                .map(|f| FieldJson {
-                    field_type: format!("{:?}", f.field_type()),
-                    label: f.label().to_string(),
-                    value: f.value().to_string(),
-                })
-                .collect(),
+            fields: card.fields().iter().map(FieldJson::from).collect(),
         }
     }
 }
 
-/// Print any serializable value as pretty JSON to stdout.
+impl From<&ContactField> for FieldJson {
+    fn from(f: &ContactField) -> Self {
+        Self {
+            field_type: format!("{:?}", f.field_type()),
+            label: f.label().to_string(),
+            value: f.value().to_string(),
+        }
+    }
+}
+
+/// Global `--json-pretty` switch for [`print_json`].
+static PRETTY: AtomicBool = AtomicBool::new(false);
+
+/// Makes [`print_json`] indent its output over several lines.
+pub(crate) fn set_pretty(pretty: bool) {
+    PRETTY.store(pretty, Ordering::Relaxed);
+}
+
+/// Print any serializable value as JSON to stdout: on one line, so it can
+/// be piped line by line, or indented with `--json-pretty`.
 pub(crate) fn print_json(value: &impl Serialize) -> anyhow::Result<()> {
-    println!("{}", serde_json::to_string_pretty(value)?);
+    let json = if PRETTY.load(Ordering::Relaxed) {
+        serde_json::to_string_pretty(value)?
+    } else {
+        serde_json::to_string(value)?
+    };
+    println!("{}", json);
     Ok(())
 }
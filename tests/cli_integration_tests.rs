@@ -29,19 +29,69 @@ impl CliTestContext {
         }
     }
 
-    /// Run a CLI command and return the output.
-    fn run(&self, args: &[&str]) -> Output {
+    /// A CLI command for this context, in English whatever the host locale.
+    fn command(&self, args: &[&str]) -> Command {
         let mut cmd = Command::new(env!("CARGO_BIN_EXE_vauchi"));
         cmd.arg("--data-dir")
             .arg(self.data_dir.path())
             .arg("--relay")
-            .arg(&self.relay_url);
-
-        for arg in args {
-            cmd.arg(arg);
+            .arg(&self.relay_url)
+            .args(args)
+            .env("VAUCHI_LOCALE", "en");
+        for var in ["LC_ALL", "LC_MESSAGES", "LANG"] {
+            cmd.env_remove(var);
         }
+        cmd
+    }
+
+    /// Run a CLI command and return the output.
+    fn run(&self, args: &[&str]) -> Output {
+        self.command(args)
+            .output()
+            .expect("Failed to execute command")
+    }
 
-        cmd.output().expect("Failed to execute command")
+    /// Run a CLI command with only the given locale variables set and
+    /// return stdout, asserting success.
+    fn run_with_locale_env(&self, args: &[&str], vars: &[(&str, &str)]) -> String {
+        let output = self
+            .command(args)
+            .env_remove("VAUCHI_LOCALE")
+            .envs(vars.iter().copied())
+            .output()
+            .expect("Failed to execute command");
+        assert!(
+            output.status.success(),
+            "Command {:?} failed.\nStderr: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
+
+    /// Run a CLI command with `input` piped to stdin and return the output.
+    fn run_with_stdin(&self, args: &[&str], input: &str) -> Output {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut child = self
+            .command(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("Failed to execute command");
+
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(input.as_bytes())
+            .expect("Failed to write stdin");
+
+        child
+            .wait_with_output()
+            .expect("Failed to wait for command")
     }
 
     /// Run a command and assert success
@@ -85,6 +135,39 @@ fn ctx() -> CliTestContext {
     CliTestContext::new()
 }
 
+/// Extracts the QR data line from `exchange start` output.
+fn exchange_data(output: &str) -> String {
+    output
+        .lines()
+        .map(str::trim)
+        .find(|line| {
+            line.len() >= 20
+                && line
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '+' | '/' | '='))
+        })
+        .expect("exchange output contains QR data")
+        .to_string()
+}
+
+/// Completes a QR exchange between two initialized contexts.
+fn exchange(alice: &CliTestContext, bob: &CliTestContext) {
+    let alice_data = exchange_data(&alice.run_success(&["exchange", "start"]));
+    let bob_data = exchange_data(&bob.run_success(&["exchange", "start"]));
+    bob.run_success(&["exchange", "complete", &alice_data]);
+    alice.run_success(&["exchange", "complete", &bob_data]);
+}
+
+/// Alice Smith and Bob Jones, initialized and exchanged with each other.
+fn exchanged_pair() -> (CliTestContext, CliTestContext) {
+    let alice = CliTestContext::new();
+    alice.init("Alice Smith");
+    let bob = CliTestContext::new();
+    bob.init("Bob Jones");
+    exchange(&alice, &bob);
+    (alice, bob)
+}
+
 // ===========================================================================
 // Identity Management Tests
 // Trace: features/identity_management.feature
@@ -117,33 +200,59 @@ mod identity_management {
 
     /// Trace: identity_management.feature - "Display name validation"
     // @scenario: identity_management:Display name validation
-    /// M-1: Verify that empty-name init either fails or creates empty identity.
+    /// Blank, over-long, and control-character names are each rejected
+    /// with a specific message.
+    #[rstest]
+    #[case::empty("", "cannot be empty")]
+    #[case::whitespace("   \t ", "cannot be empty")]
+    #[case::too_long(
+        "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklm",
+        "too long"
+    )]
+    #[case::control_char("Alice\u{7}Smith", "control characters")]
+    fn test_init_rejects_invalid_name(
+        ctx: CliTestContext,
+        #[case] name: &str,
+        #[case] expected: &str,
+    ) {
+        let stderr = ctx.run_failure(&["init", name]);
+        assert!(
+            stderr.contains(expected),
+            "Expected '{}' for name {:?}, got: {}",
+            expected,
+            name,
+            stderr
+        );
+        assert!(!ctx.data_dir.path().join("vauchi.db").exists());
+    }
+
+    /// Surrounding whitespace is trimmed from the display name.
     #[test]
-    fn test_init_empty_name_behavior() {
+    fn test_init_trims_name() {
         let ctx = CliTestContext::new();
-        let output = ctx.run(&["init", ""]);
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
+        ctx.init("  Alice Smith  ");
 
-        if output.status.success() {
-            // If accepted, the card should exist (even with empty name)
-            let card = ctx.run_success(&["card", "show"]);
-            assert!(
-                !card.is_empty(),
-                "Empty-name init succeeded but card show returned nothing"
-            );
-        } else {
-            // If rejected, stderr should mention the name issue
-            assert!(
-                stderr.contains("empty")
-                    || stderr.contains("name")
-                    || stderr.contains("invalid")
-                    || stderr.contains("required"),
-                "Empty-name init failed but stderr is unclear: stdout={}, stderr={}",
-                stdout,
-                stderr
-            );
-        }
+        let raw = ctx.run_success(&["--raw", "card", "show"]);
+        let card: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw card must be valid JSON");
+        assert_eq!(card["display_name"], "Alice Smith");
+    }
+
+    /// JSON output is compact by default and indented with `--json-pretty`.
+    #[test]
+    fn test_json_output_compact_unless_pretty() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let compact = ctx.run_success(&["--raw", "card", "show"]);
+        assert_eq!(compact.trim().lines().count(), 1, "got: {}", compact);
+
+        let pretty = ctx.run_success(&["--raw", "--json-pretty", "card", "show"]);
+        assert!(pretty.trim().lines().count() > 1, "got: {}", pretty);
+
+        let compact: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let pretty: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(compact, pretty);
     }
 
     /// Trace: identity_management.feature - Cannot re-initialize
@@ -171,6 +280,19 @@ mod identity_management {
         assert!(card_output.contains("Bob Jones"));
     }
 
+    /// An ephemeral identity lives only in the invoking process.
+    #[test]
+    fn test_init_ephemeral_leaves_nothing_on_disk() {
+        let ctx = CliTestContext::new();
+
+        let output = ctx.run_success(&["init", "--ephemeral", "Alice Smith"]);
+        assert!(output.contains("Identity created: Alice Smith"));
+        assert!(!ctx.data_dir.path().join("vauchi.db").exists());
+
+        let stderr = ctx.run_failure(&["card", "show"]);
+        assert!(stderr.contains("not initialized"));
+    }
+
     /// Trace: identity_management.feature - "Create encrypted identity backup"
     // @scenario: identity_management:Create encrypted identity backup
     /// Note: Skipped - export requires interactive password input via dialoguer
@@ -187,6 +309,25 @@ mod identity_management {
         assert!(backup_path.exists());
     }
 
+    /// `export --verify` restores the written backup before reporting success.
+    #[test]
+    fn test_export_verify_confirms_backup_restores() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let backup_path = ctx.data_dir.path().join("backup.json");
+        let output = ctx.run_success(&[
+            "export",
+            backup_path.to_str().unwrap(),
+            "--password",
+            "correct-horse-battery",
+            "--verify",
+        ]);
+
+        assert!(output.contains("Backup verified"), "got: {}", output);
+        assert!(backup_path.exists());
+    }
+
     /// Trace: identity_management.feature - "Restore identity from backup"
     // @scenario: identity_management:Restore identity from backup
     /// Note: Skipped - import requires interactive password input via dialoguer
@@ -226,6 +367,77 @@ mod identity_management {
             output
         );
     }
+
+    /// `--raw device info` prints stable machine fields with the full device ID.
+    #[test]
+    fn test_device_info_json_has_full_device_id() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["--raw", "device", "info"]);
+        let info: serde_json::Value =
+            serde_json::from_str(&output).expect("device info must be valid JSON");
+
+        let device_id = info["device_id"].as_str().expect("device_id is a string");
+        assert_eq!(device_id.len(), 64, "got: {}", device_id);
+        assert!(device_id.chars().all(|c| c.is_ascii_hexdigit()));
+        assert!(info["public_id"].as_str().is_some_and(|id| !id.is_empty()));
+        assert!(info["device_name"].is_string());
+        assert!(info["fingerprint"].is_string());
+    }
+
+    /// `doctor` reports a corrupted `aha_tracker.json`, and `--repair` resets
+    /// it without touching the identity.
+    #[test]
+    fn test_doctor_repairs_corrupt_aha_tracker() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        let tracker = ctx.data_dir.path().join("aha_tracker.json");
+        std::fs::write(&tracker, "{\"truncated\": ").unwrap();
+
+        let stderr = ctx.run_failure(&["doctor"]);
+        assert!(stderr.contains("1 problem(s) found"), "got: {}", stderr);
+
+        let output = ctx.run_success(&["doctor", "--repair"]);
+        assert!(output.contains("aha_tracker.json"), "got: {}", output);
+        assert!(output.contains("All problems repaired"), "got: {}", output);
+        assert!(
+            ctx.data_dir
+                .path()
+                .join("aha_tracker.json.corrupt")
+                .exists()
+        );
+        assert_ne!(
+            std::fs::read_to_string(&tracker).unwrap(),
+            "{\"truncated\": "
+        );
+
+        assert!(ctx.run_success(&["doctor"]).contains("No problems found"));
+        assert!(ctx.run_success(&["card", "show"]).contains("Alice Smith"));
+    }
+
+    /// `doctor --network` against an unreachable relay reports the failed
+    /// handshake with a hint, and still exits successfully.
+    #[test]
+    fn test_doctor_network_reports_unreachable_relay() {
+        let mut ctx = CliTestContext::new();
+        ctx.relay_url = "ws://127.0.0.1:9".to_string();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["doctor", "--network"]);
+        assert!(
+            output.contains("Handshake: relay unreachable"),
+            "got: {}",
+            output
+        );
+        assert!(output.contains("vauchi relay set"), "got: {}", output);
+
+        let raw = ctx.run_success(&["--raw", "doctor", "--network"]);
+        let checks: serde_json::Value = serde_json::from_str(&raw).unwrap();
+        let last = checks.as_array().unwrap().last().unwrap();
+        assert_eq!(last["check"], "Handshake");
+        assert_eq!(last["status"], "fail");
+    }
 }
 
 // ===========================================================================
@@ -271,6 +483,48 @@ mod contact_card_management {
         );
     }
 
+    /// `card add --prompt-missing` fills omitted arguments from `--values`
+    /// instead of blocking on a prompt.
+    #[test]
+    fn test_card_add_prompt_missing_with_values() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&[
+            "card",
+            "add",
+            "--prompt-missing",
+            "--values",
+            "email",
+            "Work",
+            "alice@work.com",
+        ]);
+
+        let card = ctx.run_success(&["card", "show"]);
+        assert!(card.contains("Work"), "Expected new field, got: {}", card);
+        assert!(card.contains("alice@work.com"));
+    }
+
+    /// `card qr` renders a single field as its actionable URI.
+    #[test]
+    fn test_card_qr_encodes_mailto_uri() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&["card", "add", "email", "Email", "alice@example.com"]);
+
+        let output = ctx.run_success(&["card", "qr", "Email"]);
+        assert!(
+            output.contains("mailto:alice@example.com"),
+            "Expected mailto URI, got: {}",
+            output
+        );
+        assert!(output.contains('█') || output.contains('▀') || output.contains('▄'));
+
+        let svg_path = ctx.data_dir.path().join("email.svg");
+        ctx.run_success(&["card", "qr", "email", "-o", svg_path.to_str().unwrap()]);
+        assert!(std::fs::read_to_string(&svg_path).unwrap().contains("<svg"));
+    }
+
     /// Trace: contact_card_management.feature - "Edit an existing field value"
     // @scenario: contact_card_management:Edit an existing field value
     #[test]
@@ -286,6 +540,42 @@ mod contact_card_management {
         assert!(!card.contains("+1-555-123-4567"));
     }
 
+    /// `card lint --fix` adds a scheme to a bare website and trims an email.
+    #[test]
+    fn test_card_lint_fix_normalizes_values() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&["card", "add", "website", "Site", "alice.dev"]);
+        ctx.run_success(&["card", "add", "email", "Work", "  alice@work.com  "]);
+
+        let report = ctx.run_success(&["card", "lint"]);
+        assert!(
+            report.contains("2 field(s) can be tidied"),
+            "got: {}",
+            report
+        );
+
+        let output = ctx.run_success(&["card", "lint", "--fix"]);
+        assert!(output.contains("Tidied 2 field(s)"), "got: {}", output);
+
+        let raw = ctx.run_success(&["--raw", "card", "show"]);
+        let card: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw card must be valid JSON");
+        let value = |label: &str| {
+            card["fields"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|f| f["label"] == label)
+                .map(|f| f["value"].as_str().unwrap().to_string())
+        };
+        assert_eq!(value("Site").as_deref(), Some("https://alice.dev"));
+        assert_eq!(value("Work").as_deref(), Some("alice@work.com"));
+
+        let output = ctx.run_success(&["card", "lint"]);
+        assert!(output.contains("All fields are tidy"), "got: {}", output);
+    }
+
     /// Trace: contact_card_management.feature - "Remove a field from contact card"
     // @scenario: contact_card_management:Remove a field from contact card
     #[test]
@@ -329,6 +619,215 @@ mod contact_card_management {
         assert!(card.contains("Work"));
         assert!(card.contains("Personal"));
     }
+
+    /// `card show --fields` limits output to the named labels.
+    #[test]
+    fn test_card_show_selected_fields() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["card", "add", "phone", "Mobile", "+1-555-123-4567"]);
+        ctx.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
+
+        let card = ctx.run_success(&["card", "show", "--fields", "mobile"]);
+        assert!(card.contains("Mobile"));
+        assert!(card.contains("+1-555-123-4567"));
+        assert!(!card.contains("alice@work.com"));
+
+        let card = ctx.run_success(&["card", "show", "--fields", "Mobile,Fax"]);
+        assert!(card.contains("Field 'Fax' not found"));
+
+        let output = ctx.run(&["--raw", "card", "show", "--fields", "Mobile,Fax"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value =
+            serde_json::from_str(&stdout).expect("raw card show must be valid JSON");
+        assert_eq!(json["fields"].as_array().map(Vec::len), Some(1));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("Field 'Fax' not found"), "got: {}", stderr);
+    }
+
+    /// `--quiet` hides success output but still reports errors.
+    #[test]
+    fn test_card_add_quiet() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["--quiet", "card", "add", "email", "Work", "a@b.com"]);
+        assert!(output.is_empty(), "Expected no output, got: {}", output);
+
+        let stderr = ctx.run_failure(&["-q", "card", "add", "nosuchtype", "Work", "x"]);
+        assert!(!stderr.is_empty(), "Errors must still be reported");
+    }
+
+    /// `card add --tag` shows the tag beside the field in `card show`.
+    #[test]
+    fn test_card_add_tag_is_shown() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["card", "add", "email", "Work", "a@b.com", "--tag", "biz"]);
+        ctx.run_success(&["card", "add", "email", "Home", "a@home.example"]);
+
+        let output = ctx.run_success(&["card", "show"]);
+        let tagged = output.lines().find(|l| l.contains("a@b.com")).unwrap();
+        assert!(tagged.contains("biz"), "got: {}", output);
+        let untagged = output
+            .lines()
+            .find(|l| l.contains("a@home.example"))
+            .unwrap();
+        assert!(!untagged.contains("biz"), "got: {}", output);
+
+        let stderr = ctx.run_failure(&["card", "add", "email", "Misc", "c@d.com", "--tag", "a b"]);
+        assert!(stderr.contains("single word"), "got: {}", stderr);
+    }
+
+    /// `card add --stdin` stores a multi-line value byte-for-byte, minus
+    /// the final newline.
+    #[test]
+    fn test_card_add_value_from_stdin() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let address = "1 \"Main\" St\nSpringfield\n";
+        let output = ctx.run_with_stdin(&["card", "add", "address", "Home", "--stdin"], address);
+        assert!(
+            output.status.success(),
+            "card add --stdin failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let raw = ctx.run_success(&["--raw", "card", "show"]);
+        let card: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw card must be valid JSON");
+        let value = card["fields"]
+            .as_array()
+            .expect("fields is an array")
+            .iter()
+            .find(|f| f["label"] == "Home")
+            .expect("Home field exists")["value"]
+            .clone();
+        assert_eq!(value, "1 \"Main\" St\nSpringfield");
+    }
+
+    /// `card add --stdin` rejects a positional value as well.
+    #[test]
+    fn test_card_add_stdin_conflicts_with_value() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_failure(&["card", "add", "address", "Home", "1 Main St", "--stdin"]);
+    }
+
+    /// A freshly added field shows a recent age and no stale warning.
+    #[test]
+    fn test_card_show_age_fresh_field() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["card", "add", "phone", "Mobile", "+1-555-123-4567"]);
+
+        let card = ctx.run_success(&["card", "show", "--age", "--stale-after", "30d"]);
+        assert!(card.contains("Last changed:"));
+        assert!(card.contains("just now") || card.contains("min ago"));
+        assert!(!card.contains("may be stale"));
+    }
+
+    /// `card reorder --up` swaps a field with the previous one; at the top it
+    /// is a no-op.
+    #[test]
+    fn test_card_reorder_nudges() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&["card", "add", "phone", "Mobile", "+1-555-123-4567"]);
+        ctx.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
+        ctx.run_success(&["card", "add", "website", "Blog", "https://alice.example"]);
+
+        ctx.run_success(&["card", "reorder", "Blog", "--up"]);
+        let card = ctx.run_success(&["card", "show"]);
+        let pos = |label: &str| card.find(label).expect("field shown");
+        assert!(pos("Mobile") < pos("Blog") && pos("Blog") < pos("Work"));
+
+        let top = ctx.run_success(&["card", "reorder", "Mobile", "--up"]);
+        assert!(top.contains("already at the top"));
+
+        ctx.run_failure(&["card", "reorder", "Mobile"]);
+    }
+
+    /// A second field with an existing label is rejected unless `--replace`.
+    #[test]
+    fn test_card_add_duplicate_label() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["card", "add", "phone", "Mobile", "+1-555-123-4567"]);
+
+        let stderr = ctx.run_failure(&["card", "add", "phone", "Mobile", "+1-555-000-0000"]);
+        assert!(stderr.contains("already exists"), "got: {}", stderr);
+        assert!(stderr.contains("card edit"));
+
+        ctx.run_success(&[
+            "card",
+            "add",
+            "phone",
+            "Mobile",
+            "+1-555-000-0000",
+            "--replace",
+        ]);
+        let card = ctx.run_success(&["card", "show"]);
+        assert!(card.contains("+1-555-000-0000"));
+        assert!(!card.contains("+1-555-123-4567"));
+        assert_eq!(card.matches("Mobile").count(), 1);
+    }
+
+    /// DNS validation is opt-in; `.invalid` domains never resolve (RFC 6761),
+    /// so this stays offline-safe.
+    #[test]
+    fn test_card_add_validate_dns() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let plain = ctx.run_success(&["card", "add", "email", "Old", "a@vauchi-test.invalid"]);
+        assert!(!plain.contains("DNS lookup failed"));
+
+        let warned = ctx.run_success(&[
+            "card",
+            "add",
+            "email",
+            "Work",
+            "a@vauchi-test.invalid",
+            "--validate-dns",
+        ]);
+        assert!(warned.contains("DNS lookup failed for 'vauchi-test.invalid'"));
+
+        let stderr = ctx.run_failure(&[
+            "card",
+            "add",
+            "website",
+            "Blog",
+            "https://vauchi-test.invalid/",
+            "--validate-dns",
+            "--strict",
+        ]);
+        assert!(stderr.contains("DNS lookup failed"));
+        assert!(!ctx.run_success(&["card", "show"]).contains("Blog"));
+    }
+
+    /// `card show --mask` keeps labels but redacts values.
+    #[test]
+    fn test_card_show_mask_redacts_values() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["card", "add", "phone", "Mobile", "+1-555-123-4567"]);
+        ctx.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
+
+        let card = ctx.run_success(&["card", "show", "--mask"]);
+        assert!(card.contains("Mobile"));
+        assert!(card.contains("Work"));
+        assert!(card.contains("***4567"));
+        assert!(!card.contains("+1-555-123-4567"));
+        assert!(!card.contains("alice@work.com"));
+    }
 }
 
 // ===========================================================================
@@ -339,20 +838,6 @@ mod contact_card_management {
 mod contact_exchange {
     use super::*;
 
-    fn exchange_data(output: &str) -> String {
-        output
-            .lines()
-            .map(str::trim)
-            .find(|line| {
-                line.len() >= 20
-                    && line
-                        .chars()
-                        .all(|c| c.is_alphanumeric() || matches!(c, '+' | '/' | '='))
-            })
-            .expect("exchange output contains QR data")
-            .to_string()
-    }
-
     /// Trace: contact_exchange.feature - "Generate exchange QR code"
     // @scenario: contact_exchange:Generate exchange QR code
     #[test]
@@ -406,21 +891,38 @@ mod contact_exchange {
         );
     }
 
-    /// A second in-person exchange refreshes the pair's channel instead of
-    /// leaving the newly-created QR exchange pending.
-    // @scenario: contact_exchange:Repeat in-person exchange of the same pair
+    /// `exchange payload` prints only the QR data, and it completes an
+    /// exchange exactly like the data shown by `exchange start`.
     #[test]
-    fn test_exchange_complete_rekeys_existing_contact() {
+    fn test_exchange_payload_completes_exchange() {
         let alice = CliTestContext::new();
         alice.init("Alice Smith");
         let bob = CliTestContext::new();
         bob.init("Bob Jones");
 
-        let alice_data = exchange_data(&alice.run_success(&["exchange", "start"]));
+        let alice_payload = alice.run_success(&["exchange", "payload"]);
+        let alice_payload = alice_payload.trim();
+        assert_eq!(alice_payload.lines().count(), 1);
+        assert!(vauchi_core::exchange::ExchangeQR::from_data_string(alice_payload).is_ok());
+
         let bob_data = exchange_data(&bob.run_success(&["exchange", "start"]));
-        bob.run_success(&["exchange", "complete", &alice_data]);
+        bob.run_success(&["exchange", "complete", alice_payload]);
         alice.run_success(&["exchange", "complete", &bob_data]);
 
+        assert!(
+            alice
+                .run_success(&["contacts", "list"])
+                .contains("Bob Jones")
+        );
+    }
+
+    /// A second in-person exchange refreshes the pair's channel instead of
+    /// leaving the newly-created QR exchange pending.
+    // @scenario: contact_exchange:Repeat in-person exchange of the same pair
+    #[test]
+    fn test_exchange_complete_rekeys_existing_contact() {
+        let (alice, bob) = exchanged_pair();
+
         let alice_repeat = exchange_data(&alice.run_success(&["exchange", "start"]));
         let bob_repeat = exchange_data(&bob.run_success(&["exchange", "start"]));
         let bob_output = bob.run_success(&["exchange", "complete", &alice_repeat]);
@@ -443,450 +945,1806 @@ mod contact_exchange {
     // @scenario: contact_exchange:Raw contact lists are valid JSON
     #[test]
     fn test_contacts_list_raw_is_json_only() {
-        let alice = CliTestContext::new();
-        alice.init("Alice Smith");
-        let alice_data = exchange_data(&alice.run_success(&["exchange", "start"]));
+        let (alice, _bob) = exchanged_pair();
 
-        let bob = CliTestContext::new();
-        bob.init("Bob Jones");
+        let raw = alice.run_success(&["--raw", "contacts", "list"]);
+        let contacts: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw contacts list must be valid JSON");
+        assert!(contacts.is_array());
+        assert_eq!(contacts.as_array().expect("contacts is an array").len(), 1);
+    }
+
+    /// `contacts export-keys` records the fingerprint of an exchanged contact.
+    #[test]
+    fn test_contacts_export_keys_contains_fingerprint() {
+        let (alice, _bob) = exchanged_pair();
+
+        let keys_path = alice.data_dir.path().join("keys.json");
+        alice.run_success(&["contacts", "export-keys", keys_path.to_str().unwrap()]);
+        let keys: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&keys_path).unwrap())
+                .expect("key export must be valid JSON");
+        let entry = &keys.as_array().expect("key export is an array")[0];
+        assert_eq!(entry["display_name"], "Bob Jones");
+        assert!(!entry["fingerprint"].as_str().unwrap().is_empty());
+        assert_eq!(entry["public_key"].as_str().unwrap().len(), 64);
+
+        let csv_path = alice.data_dir.path().join("keys.csv");
+        alice.run_success(&[
+            "contacts",
+            "export-keys",
+            csv_path.to_str().unwrap(),
+            "--format",
+            "csv",
+        ]);
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        assert!(csv.contains(entry["fingerprint"].as_str().unwrap()));
+    }
+
+    /// `export --split` writes identity and contacts separately, and the
+    /// contacts part alone can be imported into another identity.
+    #[test]
+    fn test_export_split_contacts_import_into_fresh_identity() {
+        let (alice, _bob) = exchanged_pair();
+
+        let base = alice.data_dir.path().join("split-backup");
+        alice.run_success(&[
+            "export",
+            "--split",
+            base.to_str().unwrap(),
+            "--password",
+            "correct-horse-battery",
+        ]);
+        let identity_path = alice.data_dir.path().join("split-backup.identity");
+        let contacts_path = alice.data_dir.path().join("split-backup.contacts");
+        assert!(identity_path.exists(), "identity part should be written");
+        assert!(contacts_path.exists(), "contacts part should be written");
+        assert!(
+            std::fs::read_to_string(&contacts_path)
+                .unwrap()
+                .contains("Bob Jones")
+        );
+
+        let carol = CliTestContext::new();
+        carol.init("Carol White");
+        carol.run_success(&["import", "--split", contacts_path.to_str().unwrap()]);
+
+        let contacts = carol.run_success(&["contacts", "list"]);
+        assert!(
+            contacts.contains("Bob Jones"),
+            "Expected imported contact, got: {}",
+            contacts
+        );
+        assert!(carol.run_success(&["card", "show"]).contains("Carol White"));
+    }
+
+    /// `contacts verify --list` shows exactly the unverified contacts.
+    #[test]
+    fn test_contacts_verify_list_shows_unverified() {
+        let (alice, _bob) = exchanged_pair();
+
+        let raw = alice.run_success(&["--raw", "contacts", "verify", "--list"]);
+        let unverified: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw unverified list must be valid JSON");
+        let unverified = unverified.as_array().expect("unverified is an array");
+        assert_eq!(unverified.len(), 1);
+        assert_eq!(unverified[0]["display_name"], "Bob Jones");
+
+        let list = alice.run_success(&["contacts", "verify", "--list"]);
+        assert!(list.contains("[ ] Bob Jones"));
+    }
+
+    /// `contacts verify --batch` verifies only the contacts whose
+    /// fingerprint matches.
+    #[test]
+    fn test_contacts_verify_batch_verifies_only_matches() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+
+        for name in ["Bob Jones", "Carol White"] {
+            let other = CliTestContext::new();
+            other.init(name);
+            exchange(&alice, &other);
+        }
+
+        let raw = alice.run_success(&["--raw", "contacts", "verify", "--list"]);
+        let unverified: serde_json::Value = serde_json::from_str(&raw).unwrap();
+        let bob_fingerprint: String = unverified
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["display_name"] == "Bob Jones")
+            .expect("Bob is unverified")["fingerprint"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        // A tab keeps the grouped fingerprint in one piece.
+        let batch = alice.data_dir.path().join("meetup.txt");
+        std::fs::write(
+            &batch,
+            format!("Bob Jones\t{bob_fingerprint}\nCarol White 00112233445566778899\n"),
+        )
+        .unwrap();
+
+        let stderr = alice.run_failure(&["contacts", "verify", "--batch", batch.to_str().unwrap()]);
+        assert!(stderr.contains("1 of 2 item(s) failed"), "got: {}", stderr);
+
+        let raw = alice.run_success(&["--raw", "contacts", "verify", "--list"]);
+        let unverified: serde_json::Value = serde_json::from_str(&raw).unwrap();
+        let unverified = unverified.as_array().unwrap();
+        assert_eq!(unverified.len(), 1);
+        assert_eq!(unverified[0]["display_name"], "Carol White");
+    }
+
+    /// `contacts show --history` lists the contact's activity under the details.
+    #[test]
+    fn test_contacts_show_history() {
+        let (alice, _bob) = exchanged_pair();
+
+        let plain = alice.run_success(&["contacts", "show", "Bob Jones"]);
+        assert!(!plain.contains("History"));
+
+        let output = alice.run_success(&["contacts", "show", "Bob Jones", "--history"]);
+        assert!(output.contains("History"), "got: {}", output);
+        assert!(output.contains("New Contact Added"), "got: {}", output);
+    }
+
+    /// `labels check` reports a field shown by a label but hidden per contact.
+    #[test]
+    fn test_labels_check_reports_contradiction() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        alice.run_success(&["card", "add", "phone", "Mobile", "+1-555-123-4567"]);
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+
+        exchange(&alice, &bob);
+
+        alice.run_success(&["labels", "create", "Friends"]);
+        alice.run_success(&["labels", "add-contact", "Friends", "Bob"]);
+        alice.run_success(&["labels", "show-field", "Friends", "Mobile"]);
+
+        let clean = alice.run_success(&["labels", "check"]);
+        assert!(clean.contains("No conflicts"), "got: {}", clean);
+
+        alice.run_success(&["contacts", "hide", "Bob", "Mobile"]);
+        let report = alice.run_success(&["labels", "check"]);
+        assert!(
+            report.contains("Bob Jones: label 'Friends' shows 'Mobile'"),
+            "got: {}",
+            report
+        );
+        assert!(report.contains("1 conflict(s)"));
+    }
+
+    /// `card add --visibility nobody` hides the new field from existing contacts.
+    #[test]
+    fn test_card_add_visibility_nobody_hides_from_contacts() {
+        let (alice, _bob) = exchanged_pair();
+
+        let output = alice.run_success(&[
+            "card",
+            "add",
+            "phone",
+            "Private",
+            "+1-555-000-1111",
+            "--visibility",
+            "nobody",
+        ]);
+        assert!(
+            output.contains("Hidden from 1 contact(s)"),
+            "got: {}",
+            output
+        );
+        alice.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
+
+        let rules = alice.run_success(&["contacts", "visibility", "Bob"]);
+        assert!(
+            rules.contains("✗ hidden Private"),
+            "Expected Private hidden, got: {}",
+            rules
+        );
+        assert!(rules.contains("✓ visible Work"), "got: {}", rules);
+    }
+
+    /// A field past its `--expires` date is flagged by `card show` and left
+    /// out of the card sent in an exchange.
+    #[test]
+    fn test_card_add_expired_field_is_not_exchanged() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        alice.run_success(&[
+            "card",
+            "add",
+            "phone",
+            "Temp",
+            "+1-555-000-2222",
+            "--expires",
+            "2000-01-01",
+        ]);
+        alice.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
+
+        let card = alice.run_success(&["card", "show"]);
+        assert!(card.contains("expired 2000-01-01"), "got: {}", card);
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        exchange(&alice, &bob);
+
+        let shown = bob.run_success(&["contacts", "show", "Alice"]);
+        assert!(shown.contains("alice@work.com"), "got: {}", shown);
+        assert!(!shown.contains("555-000-2222"), "got: {}", shown);
+
+        alice.run_success(&["card", "prune-expired"]);
+        assert!(!alice.run_success(&["card", "show"]).contains("Temp"));
+    }
+
+    /// A corrupt expiry file stops `card show` instead of forgetting the
+    /// expiries, which would share expired fields again.
+    #[test]
+    fn test_card_show_rejects_corrupt_expiry_file() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        std::fs::write(ctx.data_dir.path().join(".field_expiry.json"), "{not json").unwrap();
+
+        let stderr = ctx.run_failure(&["card", "show"]);
+        assert!(
+            stderr.contains("Corrupt field expiry file"),
+            "got: {}",
+            stderr
+        );
+    }
+
+    /// A corrupt field tags file is reported instead of read as no tags.
+    #[test]
+    fn test_card_show_rejects_corrupt_field_tags_file() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        std::fs::write(ctx.data_dir.path().join(".field_tags.json"), "[").unwrap();
+
+        let stderr = ctx.run_failure(&["card", "show"]);
+        assert!(
+            stderr.contains("Corrupt field tags file"),
+            "got: {}",
+            stderr
+        );
+    }
+
+    /// `contacts show-shared` lists every field the contact shares.
+    #[test]
+    fn test_contacts_show_shared_lists_fields() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        bob.run_success(&["card", "add", "email", "Work", "bob@work.com"]);
+        bob.run_success(&["card", "add", "phone", "Mobile", "+1-555-333-4444"]);
+
+        exchange(&alice, &bob);
+
+        let output = alice.run_success(&["contacts", "show-shared", "Bob"]);
+        assert!(output.contains("shares 2 field(s)"), "got: {}", output);
+        assert!(output.contains("bob@work.com"), "got: {}", output);
+        assert!(output.contains("+1-555-333-4444"), "got: {}", output);
+        assert!(!output.contains("No longer shared"), "got: {}", output);
+    }
+
+    /// A removed contact can be restored from the trash.
+    #[test]
+    fn test_contacts_remove_then_restore() {
+        let (alice, _bob) = exchanged_pair();
+
+        let raw = alice.run_success(&["--raw", "contacts", "list"]);
+        let contacts: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw contacts list must be valid JSON");
+        let bob_id = contacts[0]["id"].as_str().expect("contact has an id");
+
+        alice.run_success(&["contacts", "remove", bob_id]);
+        assert!(
+            !alice
+                .run_success(&["contacts", "list"])
+                .contains("Bob Jones")
+        );
+        assert_eq!(
+            alice
+                .run_success(&["contacts", "list", "--archived", "--count-only"])
+                .trim(),
+            "0"
+        );
+        assert!(
+            alice
+                .run_success(&["contacts", "trash", "list"])
+                .contains("Bob Jones")
+        );
+
+        let output = alice.run_success(&["contacts", "restore", "Bob"]);
+        assert!(
+            output.contains("Restored contact: Bob Jones"),
+            "got: {}",
+            output
+        );
+        assert!(
+            alice
+                .run_success(&["contacts", "list"])
+                .contains("Bob Jones")
+        );
+        assert!(
+            alice
+                .run_success(&["contacts", "trash", "list"])
+                .contains("The trash is empty")
+        );
+    }
+
+    /// The trash keeps no key material in the clear, and a restored contact
+    /// can be messaged again.
+    #[test]
+    fn test_contacts_restore_keeps_contact_reachable() {
+        let (alice, _bob) = exchanged_pair();
+        let queued = |ctx: &CliTestContext| -> usize {
+            ctx.run_success(&["delivery", "list", "--status", "pending", "--count-only"])
+                .trim()
+                .parse()
+                .expect("count is a number")
+        };
+
+        alice.run_success(&["contacts", "remove", "Bob"]);
+        let trash = std::fs::read_to_string(alice.data_dir.path().join(".contact_trash.json"))
+            .expect("trash file exists");
+        let trash: serde_json::Value = serde_json::from_str(&trash).unwrap();
+        let snapshot = trash[0]["contact"].as_str().unwrap();
+        assert!(
+            snapshot.chars().all(|c| c.is_ascii_hexdigit()),
+            "snapshot must be encrypted, got: {}",
+            snapshot
+        );
+
+        alice.run_success(&["contacts", "restore", "Bob"]);
+        let before = queued(&alice);
+        let output = alice.run_success(&["contacts", "resend-card", "Bob"]);
+        assert!(
+            output.contains("Queued your full card for Bob Jones"),
+            "got: {}",
+            output
+        );
+        assert_eq!(queued(&alice), before + 1);
+    }
+
+    /// A corrupt watch list is reported instead of being overwritten.
+    #[test]
+    fn test_contacts_watch_rejects_corrupt_watch_file() {
+        let (alice, _bob) = exchanged_pair();
+        let path = alice.data_dir.path().join(".watched_contacts.json");
+        std::fs::write(&path, "{not json").unwrap();
+
+        let stderr = alice.run_failure(&["contacts", "watch", "Bob"]);
+        assert!(
+            stderr.contains("Corrupt watched contacts file"),
+            "got: {}",
+            stderr
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{not json");
+    }
+
+    /// `contacts watch` and `unwatch` toggle a contact's watch flag.
+    #[test]
+    fn test_contacts_watch_and_unwatch() {
+        let (alice, _bob) = exchanged_pair();
+
+        let output = alice.run_success(&["contacts", "watch", "Bob"]);
+        assert!(output.contains("Watching Bob Jones"), "got: {}", output);
+        let output = alice.run_success(&["contacts", "watch", "Bob"]);
+        assert!(
+            output.contains("Already watching Bob Jones"),
+            "got: {}",
+            output
+        );
+
+        let output = alice.run_success(&["contacts", "unwatch", "Bob"]);
+        assert!(
+            output.contains("Stopped watching Bob Jones"),
+            "got: {}",
+            output
+        );
+        let output = alice.run_success(&["contacts", "unwatch", "Bob"]);
+        assert!(output.contains("Not watching Bob Jones"), "got: {}", output);
+    }
+
+    /// `contacts prune-unverified` removes old unverified contacts but keeps
+    /// recovery-trusted ones.
+    #[test]
+    fn test_contacts_prune_unverified_keeps_trusted() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+
+        for name in ["Bob Jones", "Carol White"] {
+            let other = CliTestContext::new();
+            other.init(name);
+            exchange(&alice, &other);
+        }
+        alice.run_success(&["contacts", "trust", "Carol"]);
+
+        let output = alice.run_success(&[
+            "contacts",
+            "prune-unverified",
+            "--older-than",
+            "0s",
+            "--yes",
+        ]);
+        assert!(
+            output.contains("Removed 1 unverified contact(s)"),
+            "got: {}",
+            output
+        );
+
+        let list = alice.run_success(&["contacts", "list"]);
+        assert!(!list.contains("Bob Jones"), "got: {}", list);
+        assert!(list.contains("Carol White"), "got: {}", list);
+    }
+
+    /// A contact whose birthday is a few days out is listed by
+    /// `contacts birthdays --within 7d`.
+    #[test]
+    fn test_contacts_birthdays_lists_upcoming() {
+        let in_three_days = chrono::Utc::now().date_naive() + chrono::Days::new(3);
+        let birthday = format!("1992-{}", in_three_days.format("%m-%d"));
+
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        bob.run_success(&["card", "add", "birthday", "Birthday", &birthday]);
+
+        exchange(&alice, &bob);
+
+        let output = alice.run_success(&["contacts", "birthdays", "--within", "7d"]);
+        assert!(
+            output.contains("Bob Jones (in 3 days"),
+            "Expected Bob's birthday, got: {}",
+            output
+        );
+
+        let output = alice.run_success(&["contacts", "birthdays", "--within", "1d"]);
+        assert!(!output.contains("Bob Jones"), "got: {}", output);
+    }
+
+    /// `labels add-contact --fail-fast` stops at a bad contact; the default
+    /// carries on and summarizes the failures.
+    #[test]
+    fn test_labels_add_contact_fail_fast_stops_at_failure() {
+        let (alice, _bob) = exchanged_pair();
+        alice.run_success(&["labels", "create", "Friends"]);
+
+        let stderr = alice.run_failure(&[
+            "labels",
+            "add-contact",
+            "Friends",
+            "Zzyzx",
+            "Bob",
+            "--fail-fast",
+        ]);
+        assert!(
+            stderr.contains("after processing 1 of 2"),
+            "got: {}",
+            stderr
+        );
+        let members = alice.run_success(&["labels", "show", "Friends"]);
+        assert!(!members.contains("Bob Jones"), "got: {}", members);
+
+        let stderr = alice.run_failure(&["labels", "add-contact", "Friends", "Zzyzx", "Bob"]);
+        assert!(stderr.contains("1 of 2 item(s) failed"), "got: {}", stderr);
+        let members = alice.run_success(&["labels", "show", "Friends"]);
+        assert!(members.contains("Bob Jones"), "got: {}", members);
+    }
+
+    /// `contacts move-label` leaves the contact only in the target label.
+    #[test]
+    fn test_contacts_move_label_moves_membership() {
+        let (alice, _bob) = exchanged_pair();
+        alice.run_success(&["labels", "create", "Work"]);
+        alice.run_success(&["labels", "create", "Friends"]);
+        alice.run_success(&["labels", "add-contact", "Work", "Bob"]);
+
+        let output = alice.run_success(&["contacts", "move-label", "Bob", "work", "friends"]);
+        assert!(output.contains("Moved 'Bob Jones'"), "got: {}", output);
+
+        let work = alice.run_success(&["labels", "show", "Work"]);
+        assert!(!work.contains("Bob Jones"), "got: {}", work);
+        let friends = alice.run_success(&["labels", "show", "Friends"]);
+        assert!(friends.contains("Bob Jones"), "got: {}", friends);
+
+        let stderr = alice.run_failure(&["contacts", "move-label", "Bob", "Work", "Friends"]);
+        assert!(stderr.contains("is not in label 'Work'"), "got: {}", stderr);
+    }
+
+    /// `labels auto-apply` adds contacts whose fields match a rule.
+    #[test]
+    fn test_labels_auto_apply_adds_matching_contact() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        bob.run_success(&["card", "add", "email", "Work", "bob@company.com"]);
+
+        exchange(&alice, &bob);
+        alice.run_success(&["labels", "create", "Work"]);
+        alice.run_success(&["labels", "create", "Friends"]);
+        alice.run_success(&[
+            "labels",
+            "rule",
+            "add",
+            "Work",
+            "--field-contains",
+            "@company.com",
+        ]);
+        alice.run_success(&[
+            "labels",
+            "rule",
+            "add",
+            "Friends",
+            "--field-contains",
+            "@home",
+        ]);
+
+        let output = alice.run_success(&["labels", "auto-apply"]);
+        assert!(
+            output.contains("Added 'Bob Jones' to label 'Work'"),
+            "got: {}",
+            output
+        );
+
+        let work = alice.run_success(&["labels", "show", "Work"]);
+        assert!(work.contains("Bob Jones"), "got: {}", work);
+        let friends = alice.run_success(&["labels", "show", "Friends"]);
+        assert!(!friends.contains("Bob Jones"), "got: {}", friends);
+
+        let again = alice.run_success(&["labels", "auto-apply"]);
+        assert!(again.contains("No contacts to add"), "got: {}", again);
+    }
+
+    /// `contacts export --since` writes recently added contacts to the file
+    /// given with `--output`.
+    #[test]
+    fn test_contacts_export_since_writes_output_file() {
+        let (alice, _bob) = exchanged_pair();
+
+        let path = alice.data_dir.path().join("recent.vcf");
+        alice.run_success(&[
+            "contacts",
+            "export",
+            "--since",
+            "1d",
+            "-o",
+            path.to_str().unwrap(),
+        ]);
+        let vcf = std::fs::read_to_string(&path).unwrap();
+        assert!(vcf.contains("Bob Jones"), "got: {}", vcf);
+
+        // Without --output the positionals name a contact and a file.
+        alice.run_failure(&["contacts", "export", "--since", "1d", "Bob", "bob.vcf"]);
+    }
+
+    /// `contacts export --group-by-label` writes one vCard file per label
+    /// plus one for contacts without a label.
+    #[test]
+    fn test_contacts_export_group_by_label_writes_file_per_label() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        for name in ["Bob Jones", "Carol White", "Dave Brown"] {
+            let other = CliTestContext::new();
+            other.init(name);
+            exchange(&alice, &other);
+        }
+        alice.run_success(&["labels", "create", "Work"]);
+        alice.run_success(&["labels", "create", "Friends"]);
+        alice.run_success(&["labels", "add-contact", "Work", "Bob"]);
+        alice.run_success(&["labels", "add-contact", "Friends", "Bob"]);
+        alice.run_success(&["labels", "add-contact", "Friends", "Carol"]);
+
+        let dir = alice.data_dir.path().join("rosters");
+        alice.run_success(&[
+            "contacts",
+            "export",
+            "--group-by-label",
+            "--output-dir",
+            dir.to_str().unwrap(),
+        ]);
+
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).unwrap();
+        let work = read("Work.vcf");
+        assert!(work.contains("Bob Jones"), "got: {}", work);
+        assert!(!work.contains("Carol White"), "got: {}", work);
+        let friends = read("Friends.vcf");
+        assert!(friends.contains("Bob Jones"), "got: {}", friends);
+        assert!(friends.contains("Carol White"), "got: {}", friends);
+        let ungrouped = read("ungrouped.vcf");
+        assert!(ungrouped.contains("Dave Brown"), "got: {}", ungrouped);
+        assert!(!ungrouped.contains("Bob Jones"), "got: {}", ungrouped);
+
+        let stderr = alice.run_failure(&[
+            "contacts",
+            "export",
+            "--group-by-label",
+            "--output-dir",
+            dir.to_str().unwrap(),
+        ]);
+        assert!(stderr.contains("--force"), "got: {}", stderr);
+    }
+
+    /// A rule added with `--auto` labels a matching contact on exchange.
+    #[test]
+    fn test_labels_auto_rule_applies_on_exchange_complete() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        alice.run_success(&["labels", "create", "Work"]);
+        alice.run_success(&["labels", "create", "Friends"]);
+        alice.run_success(&[
+            "labels",
+            "rule",
+            "add",
+            "Work",
+            "--field-contains",
+            "@company.com",
+            "--field-type",
+            "email",
+            "--auto",
+        ]);
+        alice.run_success(&[
+            "labels",
+            "rule",
+            "add",
+            "Friends",
+            "--field-contains",
+            "@company.com",
+        ]);
+        let alice_data = exchange_data(&alice.run_success(&["exchange", "start"]));
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        bob.run_success(&["card", "add", "email", "Work", "bob@company.com"]);
+        let bob_data = exchange_data(&bob.run_success(&["exchange", "start"]));
+
+        bob.run_success(&["exchange", "complete", &alice_data]);
+        let output = alice.run_success(&["exchange", "complete", &bob_data]);
+        assert!(
+            output.contains("Added 'Bob Jones' to label 'Work'"),
+            "got: {}",
+            output
+        );
+
+        let work = alice.run_success(&["labels", "show", "Work"]);
+        assert!(work.contains("Bob Jones"), "got: {}", work);
+        let friends = alice.run_success(&["labels", "show", "Friends"]);
+        assert!(!friends.contains("Bob Jones"), "got: {}", friends);
+    }
+
+    /// A corrupt rules file is reported instead of read as no rules.
+    #[test]
+    fn test_labels_rule_list_rejects_corrupt_rules_file() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        std::fs::write(ctx.data_dir.path().join(".label_rules.json"), "{not json").unwrap();
+
+        let stderr = ctx.run_failure(&["labels", "rule", "list"]);
+        assert!(stderr.contains("Corrupt label rules"), "got: {}", stderr);
+    }
+
+    /// `contacts open --copy` reports a copy instead of launching an app.
+    #[test]
+    fn test_contacts_open_copy_reports_copy() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        bob.run_success(&["card", "add", "email", "Work", "bob@work.com"]);
+
+        exchange(&alice, &bob);
+
+        let output = alice.run_success(&["contacts", "open", "Bob", "Work", "--copy"]);
+        assert!(output.contains("Copy Work"), "got: {}", output);
+        assert!(output.contains("Work: bob@work.com"), "got: {}", output);
+        assert!(!output.contains("Opened"), "got: {}", output);
+    }
+
+    /// `exchange complete --no-relay` adds the contact and leaves the card
+    /// queued instead of trying the relay.
+    #[test]
+    fn test_exchange_complete_no_relay_queues_card() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        let alice_data = exchange_data(&alice.run_success(&["exchange", "start"]));
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        let bob_data = exchange_data(&bob.run_success(&["exchange", "start"]));
+
+        bob.run_success(&["exchange", "complete", &alice_data]);
+        let output = alice.run_success(&["exchange", "complete", &bob_data, "--no-relay"]);
+        assert!(output.contains("Contact added"), "got: {}", output);
+        assert!(
+            output.contains("Queued your card without contacting the relay"),
+            "got: {}",
+            output
+        );
+        assert!(!output.contains("Could not connect"), "got: {}", output);
+
+        let list = alice.run_success(&["contacts", "list"]);
+        assert!(list.contains("Bob Jones"), "got: {}", list);
+
+        let pending =
+            alice.run_success(&["delivery", "list", "--status", "pending", "--count-only"]);
+        assert_eq!(pending.trim(), "1", "the card should wait for delivery");
+    }
+    /// `delivery explain` prints a known record's status and recipient, and
+    /// rejects an unknown ID clearly.
+    #[test]
+    fn test_delivery_explain_known_and_unknown_records() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        let alice_data = exchange_data(&alice.run_success(&["exchange", "start"]));
         let bob_data = exchange_data(&bob.run_success(&["exchange", "start"]));
+        bob.run_success(&["exchange", "complete", &alice_data]);
+        alice.run_success(&["exchange", "complete", &bob_data, "--no-relay"]);
+
+        let list = alice.run_success(&["delivery", "list"]);
+        let id = list
+            .lines()
+            .find_map(|line| line.trim().split_once(" -> ").map(|(id, _)| id.to_string()))
+            .expect("the queued card has a delivery record");
+
+        let output = alice.run_success(&["delivery", "explain", &id]);
+        assert!(output.contains("Status:     queued"), "got: {}", output);
+        assert!(output.contains("Bob Jones"), "got: {}", output);
+
+        let stderr = alice.run_failure(&["delivery", "explain", "ffffffff"]);
+        assert!(
+            stderr.contains("No delivery record with ID 'ffffffff'"),
+            "got: {}",
+            stderr
+        );
+    }
+
+    /// `contacts resend-card` queues the full card for just the named contact.
+    #[test]
+    fn test_contacts_resend_card_queues_update() {
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        alice.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+
+        exchange(&alice, &bob);
+        let queued = |ctx: &CliTestContext| -> usize {
+            ctx.run_success(&["delivery", "list", "--status", "pending", "--count-only"])
+                .trim()
+                .parse()
+                .expect("count is a number")
+        };
+        let before = queued(&alice);
+
+        let output = alice.run_success(&["contacts", "resend-card", "Bob"]);
+        assert!(
+            output.contains("Queued your full card for Bob Jones"),
+            "got: {}",
+            output
+        );
+        assert_eq!(queued(&alice), before + 1);
+
+        alice.run_failure(&["contacts", "resend-card", "Nobody"]);
+    }
+
+    /// `contacts graph` emits one DOT node per contact and marks verified ones.
+    #[test]
+    fn test_contacts_graph_dot_marks_verified() {
+        let (alice, _bob) = exchanged_pair();
+
+        let dot = alice.run_success(&["contacts", "graph"]);
+        assert!(dot.starts_with("graph vauchi {"));
+        assert!(dot.contains("label=\"Bob Jones\", verified=false"));
+
+        alice.run_success(&["contacts", "verify", "Bob Jones"]);
+        let dot = alice.run_success(&["contacts", "graph", "--format", "dot"]);
+        assert!(dot.contains("label=\"Bob Jones\", verified=true, style=solid"));
+
+        let json = alice.run_success(&["contacts", "graph", "--format", "json"]);
+        let graph: serde_json::Value =
+            serde_json::from_str(&json).expect("graph JSON must be valid");
+        assert_eq!(graph["nodes"].as_array().unwrap().len(), 2);
+        assert_eq!(graph["edges"][0]["verified"], true);
+    }
+
+    /// `contacts remove --dry-run` reports the contact but keeps it.
+    #[test]
+    fn test_contacts_remove_dry_run_keeps_contact() {
+        let (alice, _bob) = exchanged_pair();
+
+        let raw = alice.run_success(&["--raw", "contacts", "list"]);
+        let contacts: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw contacts list must be valid JSON");
+        let bob_id = contacts[0]["id"].as_str().expect("contact has an id");
+
+        alice.run_success(&["labels", "create", "Friends"]);
+        alice.run_success(&["labels", "add-contact", "Friends", "Bob"]);
+
+        let output = alice.run_success(&["contacts", "remove", bob_id, "--dry-run"]);
+        assert!(output.contains("would remove contact Bob Jones"));
+        assert!(output.contains("Friends"));
+
+        assert!(
+            alice
+                .run_success(&["contacts", "list"])
+                .contains("Bob Jones")
+        );
+    }
+
+    /// `contacts show --raw-keys` prints the full hex public key.
+    #[test]
+    fn test_contacts_show_raw_keys() {
+        let (alice, _bob) = exchanged_pair();
+
+        let output = alice.run_success(&["contacts", "show", "Bob", "--raw-keys"]);
+        let key = output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("Public Key:"))
+            .expect("output should include a public key line")
+            .trim();
+        assert_eq!(key.len(), 64, "Expected 64 hex chars, got: {}", key);
+        assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    /// Trace: contact_exchange.feature - "Handle malformed QR code"
+    // @scenario: contact_exchange:Handle malformed QR code
+    #[test]
+    fn test_exchange_complete_invalid_data() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let stderr = ctx.run_failure(&["exchange", "complete", "not-valid-exchange-data"]);
+        assert!(
+            stderr.contains("invalid")
+                || stderr.contains("Invalid")
+                || stderr.contains("failed")
+                || stderr.contains("error"),
+            "Expected error for invalid data, got: {}",
+            stderr
+        );
+    }
+}
+
+// ===========================================================================
+// Contacts Management Tests
+// Trace: features/contacts_management.feature
+// ===========================================================================
+
+mod contacts_management {
+    use super::*;
+
+    /// `--count-only` prints just the number of contacts.
+    #[test]
+    fn test_contacts_list_count_only_empty() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["contacts", "list", "--count-only"]);
+        assert_eq!(output.trim(), "0");
+        let output = ctx.run_success(&["labels", "list", "--count-only"]);
+        assert_eq!(output.trim(), "0");
+    }
+
+    /// Trace: contacts_management.feature - "List all contacts"
+    // @scenario: contacts_management:View all contacts
+    #[test]
+    fn test_contacts_list_empty() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["contacts", "list"]);
+        assert!(
+            output.contains("No contacts")
+                || output.contains("empty")
+                || output.is_empty()
+                || output.contains("0"),
+            "Expected no contacts, got: {}",
+            output
+        );
+    }
+
+    /// Trace: contacts_management.feature - "Search contacts"
+    // @scenario: contacts_management:Search contacts by name
+    #[test]
+    fn test_contacts_search() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["contacts", "search", "Bob"]);
+        // With no contacts, should return empty or "not found"
+        assert!(
+            output.contains("No")
+                || output.contains("not found")
+                || output.is_empty()
+                || output.contains("0"),
+            "Unexpected search result: {}",
+            output
+        );
+    }
+}
+
+// ===========================================================================
+// Visibility Labels Tests
+// Trace: features/visibility_labels.feature
+// ===========================================================================
+
+mod visibility_labels {
+    use super::*;
+
+    /// Trace: visibility_labels.feature - "Create a new visibility label"
+    // @scenario: visibility_control:Create a new visibility label
+    #[test]
+    fn test_labels_create() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["labels", "create", "Family"]);
+        assert!(
+            output.contains("created") || output.contains("Family"),
+            "Expected label created, got: {}",
+            output
+        );
+    }
+
+    /// Trace: visibility_labels.feature - "List labels"
+    // @scenario: visibility_control:List visibility labels
+    #[test]
+    fn test_labels_list() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["labels", "create", "Family"]);
+        ctx.run_success(&["labels", "create", "Friends"]);
+
+        let output = ctx.run_success(&["labels", "list"]);
+        assert!(output.contains("Family"));
+        assert!(output.contains("Friends"));
+    }
+
+    /// Trace: visibility_labels.feature - "Cannot create duplicate label names"
+    // @scenario: visibility_control:Cannot create duplicate label names
+    #[test]
+    fn test_labels_create_duplicate_fails() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["labels", "create", "Family"]);
+        let stderr = ctx.run_failure(&["labels", "create", "Family"]);
+
+        assert!(
+            stderr.contains("exists") || stderr.contains("duplicate") || stderr.contains("already"),
+            "Expected duplicate error, got: {}",
+            stderr
+        );
+    }
+
+    /// Trace: visibility_labels.feature - "Rename an existing label"
+    // @scenario: visibility_control:Rename an existing label
+    #[test]
+    fn test_labels_rename() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["labels", "create", "Work"]);
+        ctx.run_success(&["labels", "rename", "Work", "Colleagues"]);
+
+        let output = ctx.run_success(&["labels", "list"]);
+        assert!(output.contains("Colleagues"));
+        assert!(!output.contains("Work") || output.contains("Colleagues"));
+    }
+
+    /// Trace: visibility_labels.feature - "Delete a label"
+    // @scenario: visibility_control:Delete a label
+    #[test]
+    fn test_labels_delete() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["labels", "create", "Temporary"]);
+        ctx.run_success(&["labels", "delete", "Temporary"]);
+
+        let output = ctx.run_success(&["labels", "list"]);
+        assert!(
+            !output.contains("Temporary") || output.contains("No labels"),
+            "Label should be deleted, got: {}",
+            output
+        );
+    }
+
+    /// `labels delete --dry-run` leaves the label in place.
+    #[test]
+    fn test_labels_delete_dry_run() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["labels", "create", "Temporary"]);
+        let output = ctx.run_success(&["labels", "delete", "Temporary", "--dry-run"]);
+        assert!(output.contains("would delete label 'Temporary'"));
+
+        assert!(ctx.run_success(&["labels", "list"]).contains("Temporary"));
+    }
+
+    /// Trace: visibility_labels.feature - "Show label details"
+    // @scenario: visibility_control:Show label details
+    #[test]
+    fn test_labels_show() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["labels", "create", "Family"]);
+
+        let output = ctx.run_success(&["labels", "show", "Family"]);
+        assert!(output.contains("Family"));
+    }
 
-        bob.run_success(&["exchange", "complete", &alice_data]);
-        alice.run_success(&["exchange", "complete", &bob_data]);
+    /// Trace: visibility_labels.feature - share a label scheme between identities
+    #[test]
+    fn test_labels_export_import_recreates_visibility() {
+        fn visible_fields(ctx: &CliTestContext, label: &str) -> Vec<String> {
+            ctx.run_success(&["labels", "show", label])
+                .lines()
+                .filter_map(|line| line.strip_prefix("  - "))
+                .map(str::to_string)
+                .collect()
+        }
 
-        let raw = alice.run_success(&["--raw", "contacts", "list"]);
-        let contacts: serde_json::Value =
-            serde_json::from_str(&raw).expect("raw contacts list must be valid JSON");
-        assert!(contacts.is_array());
-        assert_eq!(contacts.as_array().expect("contacts is an array").len(), 1);
+        let alice = CliTestContext::new();
+        alice.init("Alice Smith");
+        alice.run_success(&["card", "add", "phone", "Mobile", "+1-555-123-4567"]);
+        alice.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
+        alice.run_success(&["labels", "create", "Family"]);
+        alice.run_success(&["labels", "create", "Colleagues"]);
+        alice.run_success(&["labels", "show-field", "Family", "Mobile"]);
+        alice.run_success(&["labels", "hide-field", "Family", "Work"]);
+        alice.run_success(&["labels", "show-field", "Colleagues", "Work"]);
+        alice.run_success(&["labels", "hide-field", "Colleagues", "Mobile"]);
+
+        let scheme_path = alice.data_dir.path().join("labels.json");
+        alice.run_success(&["labels", "export", scheme_path.to_str().unwrap()]);
+
+        let bob = CliTestContext::new();
+        bob.init("Bob Jones");
+        bob.run_success(&["card", "add", "phone", "Mobile", "+1-555-262-1234"]);
+        bob.run_success(&["card", "add", "email", "Work", "bob@work.com"]);
+        bob.run_success(&["labels", "import", scheme_path.to_str().unwrap()]);
+
+        assert_eq!(visible_fields(&bob, "Family"), vec!["Mobile"]);
+        assert_eq!(visible_fields(&bob, "Colleagues"), vec!["Work"]);
+
+        let again = bob.run_success(&["labels", "import", scheme_path.to_str().unwrap()]);
+        assert!(again.contains("Skipping existing label"));
     }
+}
 
-    /// Trace: contact_exchange.feature - "Handle malformed QR code"
-    // @scenario: contact_exchange:Handle malformed QR code
+// ===========================================================================
+// Device Management Tests
+// Trace: features/device_management.feature
+// ===========================================================================
+
+mod device_management {
+    use super::*;
+
+    /// Trace: device_management.feature - "List linked devices"
+    // @scenario: device_management:View linked devices
     #[test]
-    fn test_exchange_complete_invalid_data() {
+    fn test_device_list() {
         let ctx = CliTestContext::new();
         ctx.init("Alice Smith");
 
-        let stderr = ctx.run_failure(&["exchange", "complete", "not-valid-exchange-data"]);
+        let output = ctx.run_success(&["device", "list"]);
         assert!(
-            stderr.contains("invalid")
-                || stderr.contains("Invalid")
-                || stderr.contains("failed")
-                || stderr.contains("error"),
-            "Expected error for invalid data, got: {}",
-            stderr
+            output.contains("Device") || output.contains("device") || output.contains("1"),
+            "Expected device list, got: {}",
+            output
+        );
+    }
+
+    /// Trace: device_management.feature - "Generate device linking QR code"
+    // @scenario: device_management:Generate device linking QR code
+    /// M-5: Verify QR data structure, not just length.
+    #[test]
+    fn test_device_link_generates_qr() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["device", "link"]);
+        let last_line = output.lines().last().unwrap_or("").trim();
+        assert!(
+            last_line.contains("wb://") || last_line.contains("vdl://") || last_line.len() > 50,
+            "Expected device link data with protocol prefix or substantial base64, got: {}",
+            output
+        );
+    }
+
+    /// Extract the base64 payload line from QR-ish command output
+    /// (mirrors the e2e harness's `extract_qr_data`).
+    fn payload_of(output: &str) -> &str {
+        output
+            .lines()
+            .map(str::trim)
+            .find(|line| {
+                line.len() >= 20
+                    && !line.contains('█')
+                    && !line.contains('▀')
+                    && !line.contains('▄')
+                    && line
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '+' || c == '/' || c == '=')
+            })
+            .expect("output should contain a base64 payload line")
+    }
+
+    /// Links a second device to `old` and returns its device ID prefix.
+    fn link_second_device(old: &CliTestContext) -> String {
+        let new = CliTestContext::new();
+
+        let link_qr = payload_of(&old.run_success(&["device", "link"])).to_string();
+        let request = payload_of(&new.run_success(&[
+            "device",
+            "join",
+            &link_qr,
+            "--device-name",
+            "Second",
+            "--yes",
+        ]))
+        .to_string();
+        let response =
+            payload_of(&old.run_success(&["device", "complete", "--yes", &request])).to_string();
+        new.run_success(&["device", "finish", &response]);
+
+        let list = old.run_success(&["device", "list"]);
+        let id_line = list
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| !line.starts_with("2. "))
+            .find(|line| line.starts_with("ID: "))
+            .expect("second device should have an ID line");
+        id_line
+            .trim_start_matches("ID: ")
+            .trim_end_matches('.')
+            .to_string()
+    }
+
+    /// The whole link handshake can be carried through one file (sneakernet).
+    #[test]
+    fn test_device_link_via_file_transport() {
+        let old = CliTestContext::new();
+        old.init("Alice Smith");
+        let new = CliTestContext::new();
+        let carrier = TempDir::new().expect("Failed to create temp dir");
+        let blob = carrier.path().join("link.txt");
+        let blob_arg = blob.to_str().unwrap();
+        let file_args = ["--transport", "file", "--file", blob_arg];
+
+        old.run_success(&[&["device", "link"][..], &file_args[..]].concat());
+        let link_data = std::fs::read_to_string(&blob).unwrap();
+
+        new.run_success(
+            &[
+                &["device", "join", "--device-name", "Second", "--yes"][..],
+                &file_args[..],
+            ]
+            .concat(),
+        );
+        let request = std::fs::read_to_string(&blob).unwrap();
+        assert_ne!(request, link_data, "join must write its request blob");
+
+        old.run_success(&[&["device", "complete", "--yes"][..], &file_args[..]].concat());
+        new.run_success(&[&["device", "finish"][..], &file_args[..]].concat());
+
+        assert!(old.run_success(&["device", "list"]).contains("Second"));
+        assert!(new.data_dir.path().join("identity.json").exists());
+    }
+
+    /// `--file` without `--transport file` is rejected.
+    #[test]
+    fn test_device_link_file_requires_file_transport() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let stderr = ctx.run_failure(&["device", "link", "--file", "link.txt"]);
+        assert!(stderr.contains("--transport file"), "got: {}", stderr);
+    }
+
+    /// Trace: device_management.feature - "Lost device revocation"
+    // @scenario: device_management:Lost device revocation
+    /// Scripted revocation (E2E harness, release-gate device-lifecycle
+    /// matrix) needs a non-interactive confirmation like the other
+    /// destructive device commands (`complete --yes`, `decommission
+    /// --yes`); without it `device revoke` fails with "not a terminal".
+    #[test]
+    fn test_device_revoke_yes_skips_confirmation() {
+        let old = CliTestContext::new();
+        old.init("Alice Smith");
+        let prefix = link_second_device(&old);
+
+        let revoked = old.run_success(&["device", "revoke", &prefix, "--yes"]);
+        assert!(
+            revoked.contains("revoked") || revoked.contains("Revoked"),
+            "Expected revocation confirmation, got: {revoked}"
+        );
+
+        let after = old.run_success(&["device", "list", "--all"]);
+        assert!(
+            after.contains("[revoked]"),
+            "Revoked device must show revoked status, got: {after}"
+        );
+
+        let active = old.run_success(&["device", "list"]);
+        assert!(
+            !active.contains("[revoked]"),
+            "Default list must hide revoked devices, got: {active}"
+        );
+
+        let revoked_only = old.run_success(&["device", "list", "--revoked"]);
+        assert!(
+            revoked_only.contains("[revoked]") && !revoked_only.contains("[active]"),
+            "--revoked must list only revoked devices, got: {revoked_only}"
+        );
+    }
+
+    /// `device revoke --reason` is recorded and shown by `device history`.
+    #[test]
+    fn test_device_history_shows_revoke_reason() {
+        let old = CliTestContext::new();
+        old.init("Alice Smith");
+        let prefix = link_second_device(&old);
+
+        let empty = old.run_success(&["device", "history"]);
+        assert!(empty.contains("No devices have been revoked"));
+
+        old.run_success(&[
+            "device",
+            "revoke",
+            &prefix,
+            "--reason",
+            "lost on the train",
+            "--yes",
+        ]);
+
+        let history = old.run_success(&["device", "history"]);
+        assert!(
+            history.contains("lost on the train") && history.contains("Second"),
+            "History must show the revocation reason, got: {history}"
         );
     }
 }
 
 // ===========================================================================
-// Contacts Management Tests
-// Trace: features/contacts_management.feature
+// Recovery Tests
+// Trace: features/identity_management.feature (recovery scenarios)
 // ===========================================================================
 
-mod contacts_management {
+mod recovery {
     use super::*;
 
-    /// Trace: contacts_management.feature - "List all contacts"
-    // @scenario: contacts_management:View all contacts
+    /// Trace: Recovery settings
+    // @scenario: identity_management:Configure recovery settings
     #[test]
-    fn test_contacts_list_empty() {
+    fn test_recovery_settings_show() {
         let ctx = CliTestContext::new();
         ctx.init("Alice Smith");
 
-        let output = ctx.run_success(&["contacts", "list"]);
+        let output = ctx.run_success(&["recovery", "settings", "show"]);
         assert!(
-            output.contains("No contacts")
-                || output.contains("empty")
-                || output.is_empty()
-                || output.contains("0"),
-            "Expected no contacts, got: {}",
+            output.contains("recovery")
+                || output.contains("Recovery")
+                || output.contains("threshold")
+                || output.contains("voucher"),
+            "Expected recovery settings, got: {}",
+            output
+        );
+    }
+
+    /// Trace: Recovery settings can be configured
+    // @scenario: identity_management:Configure recovery settings
+    #[test]
+    fn test_recovery_settings_set() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&[
+            "recovery",
+            "settings",
+            "set",
+            "--recovery",
+            "3",
+            "--verification",
+            "2",
+        ]);
+        assert!(
+            output.contains("updated") || output.contains("set") || output.contains("3"),
+            "Expected settings update confirmation, got: {}",
+            output
+        );
+        assert!(
+            output.contains("fewer trusted contacts (0)"),
+            "Expected trusted-contact warning, got: {}",
+            output
+        );
+    }
+
+    /// Out-of-range or inconsistent thresholds are each rejected with a
+    /// specific message.
+    #[rstest]
+    #[case::recovery_zero("0", "1", "--recovery must be at least 1")]
+    #[case::recovery_too_high("11", "2", "--recovery must be at most 10")]
+    #[case::verification_zero("3", "0", "--verification must be at least 1")]
+    #[case::verification_above_recovery("2", "3", "cannot exceed --recovery")]
+    fn test_recovery_settings_set_rejects_invalid(
+        ctx: CliTestContext,
+        #[case] recovery: &str,
+        #[case] verification: &str,
+        #[case] expected: &str,
+    ) {
+        ctx.init("Alice Smith");
+
+        let stderr = ctx.run_failure(&[
+            "recovery",
+            "settings",
+            "set",
+            "--recovery",
+            recovery,
+            "--verification",
+            verification,
+        ]);
+        assert!(
+            stderr.contains(expected),
+            "Expected '{}', got: {}",
+            expected,
+            stderr
+        );
+    }
+
+    /// Trace: Recovery status shows pending state
+    // @scenario: identity_management:View recovery status
+    #[test]
+    fn test_recovery_status_no_claim() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run_success(&["recovery", "status"]);
+        assert!(
+            output.contains("No")
+                || output.contains("none")
+                || output.contains("active")
+                || output.contains("claim"),
+            "Expected no active recovery, got: {}",
             output
         );
     }
 
-    /// Trace: contacts_management.feature - "Search contacts"
-    // @scenario: contacts_management:Search contacts by name
     #[test]
-    fn test_contacts_search() {
+    fn test_recovery_status_watch_without_recovery_fails() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let stderr = ctx.run_failure(&["recovery", "status", "--watch", "--interval", "1s"]);
+        assert!(
+            stderr.contains("No recovery in progress"),
+            "Expected no recovery error, got: {}",
+            stderr
+        );
+    }
+
+    /// Without `--watch`, `recovery status` reports an active claim once and
+    /// returns.
+    #[test]
+    fn test_recovery_status_without_watch_returns_immediately() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&["recovery", "claim", &"ab".repeat(32)]);
+
+        let output = ctx.run_success(&["recovery", "status"]);
+        assert!(output.contains("Recovery claim active"), "got: {}", output);
+        assert!(!output.contains("Vouchers:"), "got: {}", output);
+    }
+
+    /// While `recovery status --watch` waits, other commands can still use
+    /// the data directory, e.g. to add vouchers.
+    #[test]
+    fn test_recovery_status_watch_releases_lock_between_polls() {
+        use std::process::Stdio;
+
         let ctx = CliTestContext::new();
         ctx.init("Alice Smith");
+        ctx.run_success(&["recovery", "claim", &"ab".repeat(32)]);
+
+        let mut watch = ctx
+            .command(&["recovery", "status", "--watch", "--interval", "1h"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to start watch");
+
+        // The first cycle syncs under the lock; once it sleeps, the lock is free.
+        let mut output = ctx.run(&["recovery", "status"]);
+        for _ in 0..50 {
+            if output.status.success() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            output = ctx.run(&["recovery", "status"]);
+        }
+        watch.kill().expect("Failed to stop watch");
+        let _ = watch.wait();
 
-        let output = ctx.run_success(&["contacts", "search", "Bob"]);
-        // With no contacts, should return empty or "not found"
         assert!(
-            output.contains("No")
-                || output.contains("not found")
-                || output.is_empty()
-                || output.contains("0"),
-            "Unexpected search result: {}",
-            output
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
         );
     }
 }
 
 // ===========================================================================
-// Visibility Labels Tests
-// Trace: features/visibility_labels.feature
+// Social Network Tests
+// Trace: features/contact_card_management.feature (social registry)
 // ===========================================================================
 
-mod visibility_labels {
+mod social {
     use super::*;
 
-    /// Trace: visibility_labels.feature - "Create a new visibility label"
-    // @scenario: visibility_control:Create a new visibility label
+    /// Trace: contact_card_management.feature - "List available social networks"
+    // @scenario: contact_card_management:List available social networks
     #[test]
-    fn test_labels_create() {
+    fn test_social_list() {
         let ctx = CliTestContext::new();
-        ctx.init("Alice Smith");
 
-        let output = ctx.run_success(&["labels", "create", "Family"]);
+        let output = ctx.run_success(&["social", "list"]);
         assert!(
-            output.contains("created") || output.contains("Family"),
-            "Expected label created, got: {}",
-            output
+            output.contains("twitter")
+                || output.contains("Twitter")
+                || output.contains("github")
+                || output.contains("GitHub")
         );
     }
 
-    /// Trace: visibility_labels.feature - "List labels"
-    // @scenario: visibility_control:List visibility labels
+    /// Trace: contact_card_management.feature - "Generate profile URL"
+    // @scenario: contact_card_management:Generate social profile URL
     #[test]
-    fn test_labels_list() {
+    fn test_social_url() {
         let ctx = CliTestContext::new();
-        ctx.init("Alice Smith");
-
-        ctx.run_success(&["labels", "create", "Family"]);
-        ctx.run_success(&["labels", "create", "Friends"]);
 
-        let output = ctx.run_success(&["labels", "list"]);
-        assert!(output.contains("Family"));
-        assert!(output.contains("Friends"));
+        let output = ctx.run_success(&["social", "url", "github", "octocat"]);
+        assert!(output.contains("github.com") && output.contains("octocat"));
     }
 
-    /// Trace: visibility_labels.feature - "Cannot create duplicate label names"
-    // @scenario: visibility_control:Cannot create duplicate label names
+    /// `--format qr` renders the profile URL as a QR code; text stays plain.
     #[test]
-    fn test_labels_create_duplicate_fails() {
+    fn test_social_url_format_qr() {
         let ctx = CliTestContext::new();
-        ctx.init("Alice Smith");
 
-        ctx.run_success(&["labels", "create", "Family"]);
-        let stderr = ctx.run_failure(&["labels", "create", "Family"]);
+        let text = ctx.run_success(&["social", "url", "github", "octocat"]);
+        assert_eq!(text.trim().lines().count(), 1, "got: {}", text);
+        assert!(text.contains("github.com") && text.contains("octocat"));
 
-        assert!(
-            stderr.contains("exists") || stderr.contains("duplicate") || stderr.contains("already"),
-            "Expected duplicate error, got: {}",
-            stderr
-        );
+        let qr = ctx.run_success(&["social", "url", "github", "octocat", "--format", "qr"]);
+        assert!(qr.contains(&format!("URL: {}", text.trim())), "got: {}", qr);
+        assert!(qr.lines().count() > 10, "expected a QR code, got: {}", qr);
     }
 
-    /// Trace: visibility_labels.feature - "Rename an existing label"
-    // @scenario: visibility_control:Rename an existing label
+    /// A well-formed GitHub handle validates and shows its profile URL.
     #[test]
-    fn test_labels_rename() {
+    fn test_social_validate_url_valid_handle() {
         let ctx = CliTestContext::new();
-        ctx.init("Alice Smith");
-
-        ctx.run_success(&["labels", "create", "Work"]);
-        ctx.run_success(&["labels", "rename", "Work", "Colleagues"]);
 
-        let output = ctx.run_success(&["labels", "list"]);
-        assert!(output.contains("Colleagues"));
-        assert!(!output.contains("Work") || output.contains("Colleagues"));
+        let output = ctx.run_success(&["social", "validate-url", "github", "octocat"]);
+        assert!(output.contains("github.com") && output.contains("octocat"));
+        assert!(output.contains("Valid"));
     }
 
-    /// Trace: visibility_labels.feature - "Delete a label"
-    // @scenario: visibility_control:Delete a label
+    /// A handle containing spaces is rejected.
     #[test]
-    fn test_labels_delete() {
+    fn test_social_validate_url_rejects_spaces() {
         let ctx = CliTestContext::new();
-        ctx.init("Alice Smith");
-
-        ctx.run_success(&["labels", "create", "Temporary"]);
-        ctx.run_success(&["labels", "delete", "Temporary"]);
 
-        let output = ctx.run_success(&["labels", "list"]);
+        let stderr = ctx.run_failure(&["social", "validate-url", "github", "octo cat"]);
         assert!(
-            !output.contains("Temporary") || output.contains("No labels"),
-            "Label should be deleted, got: {}",
-            output
+            stderr.contains("invalid character"),
+            "Expected invalid-character error, got: {}",
+            stderr
         );
     }
 
-    /// Trace: visibility_labels.feature - "Show label details"
-    // @scenario: visibility_control:Show label details
+    /// Trace: contact_card_management.feature - Search social networks
+    // @scenario: contact_card_management:Search social networks
     #[test]
-    fn test_labels_show() {
+    fn test_social_list_search() {
         let ctx = CliTestContext::new();
-        ctx.init("Alice Smith");
 
-        ctx.run_success(&["labels", "create", "Family"]);
-
-        let output = ctx.run_success(&["labels", "show", "Family"]);
-        assert!(output.contains("Family"));
+        let output = ctx.run_success(&["social", "list", "git"]);
+        assert!(
+            output.contains("GitHub")
+                || output.contains("github")
+                || output.contains("GitLab")
+                || output.contains("gitlab")
+        );
     }
 }
 
 // ===========================================================================
-// Device Management Tests
-// Trace: features/device_management.feature
+// Sync Tests
+// Trace: features/sync_updates.feature
 // ===========================================================================
 
-mod device_management {
+mod sync {
     use super::*;
 
-    /// Trace: device_management.feature - "List linked devices"
-    // @scenario: device_management:View linked devices
+    /// Trace: sync_updates.feature - Sync command runs (may fail without relay)
+    // @scenario: sync_updates:Client initiates sync with relay
+    /// M-3: Tightened assertion — must show sync-specific or relay-specific output.
     #[test]
-    fn test_device_list() {
+    fn test_sync_command_executes() {
         let ctx = CliTestContext::new();
         ctx.init("Alice Smith");
 
-        let output = ctx.run_success(&["device", "list"]);
+        // Sync will fail without a running relay, but should execute and report
+        let output = ctx.run(&["sync"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let combined_lower = format!("{}{}", stdout, stderr).to_lowercase();
+
+        // Must mention sync, relay, or connection — not just any English word
         assert!(
-            output.contains("Device") || output.contains("device") || output.contains("1"),
-            "Expected device list, got: {}",
-            output
+            combined_lower.contains("sync")
+                || combined_lower.contains("relay")
+                || combined_lower.contains("connect")
+                || combined_lower.contains("websocket"),
+            "Sync output should mention sync/relay/connection, got: stdout={}, stderr={}",
+            stdout,
+            stderr
         );
     }
 
-    /// Trace: device_management.feature - "Generate device linking QR code"
-    // @scenario: device_management:Generate device linking QR code
-    /// M-5: Verify QR data structure, not just length.
+    /// `sync --jsonl` without a reachable relay still ends with a summary
+    /// line, and every stdout line is a JSON object.
     #[test]
-    fn test_device_link_generates_qr() {
+    fn test_sync_jsonl_emits_summary() {
         let ctx = CliTestContext::new();
         ctx.init("Alice Smith");
 
-        let output = ctx.run_success(&["device", "link"]);
-        let last_line = output.lines().last().unwrap_or("").trim();
-        assert!(
-            last_line.contains("wb://") || last_line.contains("vdl://") || last_line.len() > 50,
-            "Expected device link data with protocol prefix or substantial base64, got: {}",
-            output
-        );
+        let output = ctx.run(&["sync", "--jsonl"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let events: Vec<serde_json::Value> = stdout
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line is JSON"))
+            .collect();
+
+        let summary = events.last().expect("at least one JSON line");
+        assert_eq!(summary["event"], "summary", "got: {}", stdout);
+        assert!(summary["outcome"].is_string(), "got: {}", stdout);
     }
 
-    /// Trace: device_management.feature - "Lost device revocation"
-    // @scenario: device_management:Lost device revocation
-    /// Scripted revocation (E2E harness, release-gate device-lifecycle
-    /// matrix) needs a non-interactive confirmation like the other
-    /// destructive device commands (`complete --yes`, `decommission
-    /// --yes`); without it `device revoke` fails with "not a terminal".
+    /// `sync summary` reprints the stored result of the last sync without
+    /// syncing again.
     #[test]
-    fn test_device_revoke_yes_skips_confirmation() {
-        /// Extract the base64 payload line from QR-ish command output
-        /// (mirrors the e2e harness's `extract_qr_data`).
-        fn payload_of(output: &str) -> &str {
-            output
-                .lines()
-                .map(str::trim)
-                .find(|line| {
-                    line.len() >= 20
-                        && !line.contains('█')
-                        && !line.contains('▀')
-                        && !line.contains('▄')
-                        && line
-                            .chars()
-                            .all(|c| c.is_alphanumeric() || c == '+' || c == '/' || c == '=')
-                })
-                .expect("output should contain a base64 payload line")
-        }
-
-        let old = CliTestContext::new();
-        old.init("Alice Smith");
-        let new = CliTestContext::new();
+    fn test_sync_summary_reprints_last_result() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
 
-        let link_qr = payload_of(&old.run_success(&["device", "link"])).to_string();
-        let request = payload_of(&new.run_success(&[
-            "device",
-            "join",
-            &link_qr,
-            "--device-name",
-            "Second",
-            "--yes",
-        ]))
-        .to_string();
-        let response =
-            payload_of(&old.run_success(&["device", "complete", "--yes", &request])).to_string();
-        new.run_success(&["device", "finish", &response]);
+        let before = ctx.run_success(&["sync", "summary"]);
+        assert!(before.contains("No sync has run yet"), "got: {}", before);
 
-        let list = old.run_success(&["device", "list"]);
-        let id_line = list
-            .lines()
-            .map(str::trim)
-            .skip_while(|line| !line.starts_with("2. "))
-            .find(|line| line.starts_with("ID: "))
-            .expect("second device should have an ID line");
-        let prefix = id_line
-            .trim_start_matches("ID: ")
-            .trim_end_matches('.')
-            .to_string();
+        let output = ctx.run(&["sync", "--jsonl"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let last_line = stdout.lines().last().expect("summary line");
+        let synced: serde_json::Value = serde_json::from_str(last_line).unwrap();
 
-        let revoked = old.run_success(&["device", "revoke", &prefix, "--yes"]);
+        let text = ctx.run_success(&["sync", "summary"]);
+        assert!(text.contains("Last sync:"), "got: {}", text);
         assert!(
-            revoked.contains("revoked") || revoked.contains("Revoked"),
-            "Expected revocation confirmation, got: {revoked}"
+            text.contains(synced["outcome"].as_str().unwrap()),
+            "got: {}",
+            text
         );
 
-        let after = old.run_success(&["device", "list"]);
-        assert!(
-            after.contains("[revoked]"),
-            "Revoked device must show revoked status, got: {after}"
-        );
+        let json = ctx.run_success(&["--raw", "sync", "summary"]);
+        let stored: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(stored["outcome"], synced["outcome"]);
+        assert!(stored["synced_at"].is_u64(), "got: {}", json);
     }
-}
-
-// ===========================================================================
-// Recovery Tests
-// Trace: features/identity_management.feature (recovery scenarios)
-// ===========================================================================
-
-mod recovery {
-    use super::*;
 
-    /// Trace: Recovery settings
-    // @scenario: identity_management:Configure recovery settings
+    /// `relay status` reports an unreachable relay without failing hard.
     #[test]
-    fn test_recovery_settings_show() {
+    fn test_relay_status_unreachable() {
         let ctx = CliTestContext::new();
         ctx.init("Alice Smith");
 
-        let output = ctx.run_success(&["recovery", "settings", "show"]);
+        let output = ctx.run(&["relay", "status"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
         assert!(
-            output.contains("recovery")
-                || output.contains("Recovery")
-                || output.contains("threshold")
-                || output.contains("voucher"),
-            "Expected recovery settings, got: {}",
-            output
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
         );
+        assert!(stdout.contains("unreachable"), "got: {}", stdout);
     }
 
-    /// Trace: Recovery settings can be configured
-    // @scenario: identity_management:Configure recovery settings
+    /// The relay given to `init` is reused by later commands without `--relay`.
     #[test]
-    fn test_recovery_settings_set() {
-        let ctx = CliTestContext::new();
+    fn test_init_relay_is_persisted() {
+        let mut ctx = CliTestContext::new();
+        ctx.relay_url = "ws://127.0.0.1:9".to_string();
         ctx.init("Alice Smith");
 
-        let output = ctx.run_success(&[
-            "recovery",
-            "settings",
-            "set",
-            "--recovery",
-            "3",
-            "--verification",
-            "2",
-        ]);
+        let output = Command::new(env!("CARGO_BIN_EXE_vauchi"))
+            .arg("--data-dir")
+            .arg(ctx.data_dir.path())
+            .args(["relay", "status"])
+            .env_remove("VAUCHI_RELAY_URL")
+            .output()
+            .expect("Failed to execute command");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success());
         assert!(
-            output.contains("updated") || output.contains("set") || output.contains("3"),
-            "Expected settings update confirmation, got: {}",
-            output
+            stdout.contains("Relay:       ws://127.0.0.1:9"),
+            "got: {}",
+            stdout
         );
     }
 
-    /// Trace: Recovery status shows pending state
-    // @scenario: identity_management:View recovery status
+    /// `relay set` rejects non-WebSocket URLs and saves nothing.
     #[test]
-    fn test_recovery_status_no_claim() {
+    fn test_relay_set_rejects_wrong_scheme() {
         let ctx = CliTestContext::new();
         ctx.init("Alice Smith");
 
-        let output = ctx.run_success(&["recovery", "status"]);
+        let stderr = ctx.run_failure(&["relay", "set", "http://x"]);
+        assert!(stderr.contains("ws:// or wss://"), "got: {}", stderr);
+        assert!(!ctx.data_dir.path().join(".relay_url").exists());
+    }
+
+    /// `relay set` saves the relay, and `relay show` reports it as saved.
+    #[test]
+    fn test_relay_set_then_show() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&["relay", "set", "ws://127.0.0.1:9"]);
+
+        let output = Command::new(env!("CARGO_BIN_EXE_vauchi"))
+            .arg("--data-dir")
+            .arg(ctx.data_dir.path())
+            .args(["relay", "show"])
+            .env_remove("VAUCHI_RELAY_URL")
+            .output()
+            .expect("Failed to execute command");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success());
+        assert!(stdout.contains("ws://127.0.0.1:9"), "got: {}", stdout);
+        assert!(stdout.contains("saved config"), "got: {}", stdout);
+    }
+
+    /// Piped (non-TTY) sync output carries no spinner frames or cursor control.
+    #[test]
+    fn test_sync_piped_output_has_no_progress_artifacts() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let output = ctx.run(&["sync"]);
+        let combined = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
         assert!(
-            output.contains("No")
-                || output.contains("none")
-                || output.contains("active")
-                || output.contains("claim"),
-            "Expected no active recovery, got: {}",
-            output
+            !combined.contains('\r') && !combined.contains("\x1b[2K"),
+            "Piped sync output must not contain progress redraws, got: {:?}",
+            combined
+        );
+        assert!(
+            !combined.contains("Connecting to"),
+            "Spinner message must be hidden when piped, got: {:?}",
+            combined
         );
     }
 }
 
 // ===========================================================================
-// Social Network Tests
-// Trace: features/contact_card_management.feature (social registry)
+// QR Utility Tests
 // ===========================================================================
 
-mod social {
+mod qr {
     use super::*;
 
-    /// Trace: contact_card_management.feature - "List available social networks"
-    // @scenario: contact_card_management:List available social networks
+    /// A short string renders as a terminal QR and can be saved as SVG.
     #[test]
-    fn test_social_list() {
+    fn test_qr_renders_short_text() {
         let ctx = CliTestContext::new();
+        let svg_path = ctx.data_dir.path().join("wifi.svg");
 
-        let output = ctx.run_success(&["social", "list"]);
-        assert!(
-            output.contains("twitter")
-                || output.contains("Twitter")
-                || output.contains("github")
-                || output.contains("GitHub")
-        );
+        let output = ctx.run_success(&[
+            "qr",
+            "WIFI:T:WPA;S:home;P:secret;;",
+            "--output",
+            svg_path.to_str().unwrap(),
+        ]);
+        assert!(output.contains('█') || output.contains('▀') || output.contains('▄'));
+        assert!(std::fs::read_to_string(&svg_path).unwrap().contains("<svg"));
     }
 
-    /// Trace: contact_card_management.feature - "Generate profile URL"
-    // @scenario: contact_card_management:Generate social profile URL
+    /// `whoami --qr` shows the identity and the card as a QR code.
     #[test]
-    fn test_social_url() {
+    fn test_whoami_qr_shows_identity_and_card_qr() {
         let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&["card", "add", "email", "Email", "alice@example.com"]);
 
-        let output = ctx.run_success(&["social", "url", "github", "octocat"]);
-        assert!(output.contains("github.com") && output.contains("octocat"));
+        let output = ctx.run_success(&["whoami", "--qr"]);
+        assert!(output.contains("Alice Smith"), "got: {}", output);
+        assert!(output.contains("Public ID:"), "got: {}", output);
+        assert!(output.contains('█') || output.contains('▀') || output.contains('▄'));
+
+        let vcf_path = ctx.data_dir.path().join("me.vcf");
+        ctx.run_success(&["whoami", "--export-card", vcf_path.to_str().unwrap()]);
+        assert!(
+            std::fs::read_to_string(&vcf_path)
+                .unwrap()
+                .contains("alice@example.com")
+        );
     }
 
-    /// Trace: contact_card_management.feature - Search social networks
-    // @scenario: contact_card_management:Search social networks
+    /// `whoami --export-card` leaves out expired fields, like an exchange.
     #[test]
-    fn test_social_list_search() {
+    fn test_whoami_export_card_omits_expired_fields() {
         let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&[
+            "card",
+            "add",
+            "phone",
+            "Temp",
+            "+1-555-000-2222",
+            "--expires",
+            "2000-01-01",
+        ]);
+        ctx.run_success(&["card", "add", "email", "Email", "alice@example.com"]);
 
-        let output = ctx.run_success(&["social", "list", "git"]);
-        assert!(
-            output.contains("GitHub")
-                || output.contains("github")
-                || output.contains("GitLab")
-                || output.contains("gitlab")
-        );
+        let vcf_path = ctx.data_dir.path().join("me.vcf");
+        ctx.run_success(&["whoami", "--export-card", vcf_path.to_str().unwrap()]);
+        let vcard = std::fs::read_to_string(&vcf_path).unwrap();
+        assert!(vcard.contains("alice@example.com"), "got: {}", vcard);
+        assert!(!vcard.contains("555-000-2222"), "got: {}", vcard);
     }
-}
 
-// ===========================================================================
-// Sync Tests
-// Trace: features/sync_updates.feature
-// ===========================================================================
+    /// An existing output file is only replaced with `--force`.
+    #[test]
+    fn test_qr_output_requires_force_to_overwrite() {
+        let ctx = CliTestContext::new();
+        let svg_path = ctx.data_dir.path().join("code.svg");
+        std::fs::write(&svg_path, "keep me").unwrap();
 
-mod sync {
-    use super::*;
+        let stderr = ctx.run_failure(&["qr", "hello", "-o", svg_path.to_str().unwrap()]);
+        assert!(stderr.contains("--force"), "got: {}", stderr);
+        assert_eq!(std::fs::read_to_string(&svg_path).unwrap(), "keep me");
 
-    /// Trace: sync_updates.feature - Sync command runs (may fail without relay)
-    // @scenario: sync_updates:Client initiates sync with relay
-    /// M-3: Tightened assertion — must show sync-specific or relay-specific output.
+        ctx.run_success(&["qr", "hello", "-o", svg_path.to_str().unwrap(), "--force"]);
+        assert!(std::fs::read_to_string(&svg_path).unwrap().contains("<svg"));
+    }
+
+    /// Input beyond QR capacity is rejected.
     #[test]
-    fn test_sync_command_executes() {
+    fn test_qr_rejects_oversized_text() {
         let ctx = CliTestContext::new();
-        ctx.init("Alice Smith");
-
-        // Sync will fail without a running relay, but should execute and report
-        let output = ctx.run(&["sync"]);
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let combined_lower = format!("{}{}", stdout, stderr).to_lowercase();
+        let text = "x".repeat(5000);
 
-        // Must mention sync, relay, or connection — not just any English word
-        assert!(
-            combined_lower.contains("sync")
-                || combined_lower.contains("relay")
-                || combined_lower.contains("connect")
-                || combined_lower.contains("websocket"),
-            "Sync output should mention sync/relay/connection, got: stdout={}, stderr={}",
-            stdout,
-            stderr
-        );
+        let stderr = ctx.run_failure(&["qr", &text]);
+        assert!(stderr.contains("too long"), "got: {}", stderr);
     }
 }
 
@@ -912,6 +2770,74 @@ mod completions {
         let output = ctx.run_success(&["completions", "zsh"]);
         assert!(output.contains("compdef") || output.contains("_vauchi"));
     }
+
+    /// Nushell and Elvish completions are generated for the `vauchi` binary.
+    #[rstest]
+    #[case::nushell("nushell")]
+    #[case::elvish("elvish")]
+    fn test_completions_extra_shells(ctx: CliTestContext, #[case] shell: &str) {
+        let output = ctx.run_success(&["completions", shell]);
+        assert!(!output.is_empty());
+        assert!(output.contains("vauchi"), "got: {}", output);
+    }
+}
+
+// ===========================================================================
+// Output Width Tests
+// ===========================================================================
+
+mod output_width {
+    use super::*;
+
+    /// `--max-width` wraps FAQ text to the given number of columns.
+    #[test]
+    fn test_max_width_wraps_faq_lines() {
+        let ctx = CliTestContext::new();
+        let output = ctx.run_success(&["--max-width", "30", "faq", "list"]);
+
+        let answers: Vec<&str> = output.lines().filter(|l| l.starts_with("  ")).collect();
+        assert!(!answers.is_empty(), "no FAQ answers in: {}", output);
+        for line in output.lines() {
+            // A single word longer than the width cannot be wrapped.
+            assert!(
+                line.chars().count() <= 30 || !line.trim().contains(' '),
+                "line wider than 30 columns: {:?}",
+                line
+            );
+        }
+    }
+}
+
+// ===========================================================================
+// Locale Detection Tests
+// ===========================================================================
+
+mod locale_detection {
+    use super::*;
+
+    /// Without `--locale`, the language comes from `LANG`.
+    #[test]
+    fn test_lang_selects_german_faq() {
+        let ctx = CliTestContext::new();
+
+        let detected = ctx.run_with_locale_env(&["faq", "list"], &[("LANG", "de_DE.UTF-8")]);
+        let german = ctx.run_with_locale_env(&["--locale", "de", "faq", "list"], &[]);
+        let english = ctx.run_with_locale_env(&["faq", "list"], &[]);
+
+        assert_eq!(detected, german);
+        assert_ne!(detected, english, "expected German FAQ strings");
+    }
+
+    /// An unsupported system locale falls back to English.
+    #[test]
+    fn test_unsupported_lang_falls_back_to_english() {
+        let ctx = CliTestContext::new();
+
+        let detected = ctx.run_with_locale_env(&["faq", "list"], &[("LANG", "xx_YY.UTF-8")]);
+        let english = ctx.run_with_locale_env(&["--locale", "en", "faq", "list"], &[]);
+
+        assert_eq!(detected, english);
+    }
 }
 
 // ===========================================================================
@@ -1008,6 +2934,79 @@ mod gdpr {
         );
     }
 
+    /// `consent-status --type` shows only the requested type's history.
+    #[test]
+    fn test_gdpr_consent_status_filters_by_type() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["gdpr", "grant-consent", "data_processing"]);
+        ctx.run_success(&["gdpr", "grant-consent", "contact_sharing"]);
+
+        let output = ctx.run_success(&["gdpr", "consent-status", "--type", "contact_sharing"]);
+        assert!(output.contains("ContactSharing"), "got: {}", output);
+        assert!(!output.contains("DataProcessing"), "got: {}", output);
+
+        let recent = ctx.run_success(&["gdpr", "consent-status", "--since", "1h"]);
+        assert!(recent.contains("DataProcessing") && recent.contains("ContactSharing"));
+
+        let old = ctx.run_success(&["gdpr", "consent-status", "--until", "1h"]);
+        assert!(old.contains("No consent records"), "got: {}", old);
+    }
+
+    /// `consent-status --output --format csv` writes one row per log entry.
+    #[test]
+    fn test_gdpr_consent_status_csv_output() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        ctx.run_success(&["gdpr", "grant-consent", "data_processing"]);
+        ctx.run_success(&["gdpr", "revoke-consent", "data_processing"]);
+        ctx.run_success(&["gdpr", "grant-consent", "contact_sharing"]);
+
+        let path = ctx.data_dir.path().join("consent.csv");
+        let path_arg = path.to_str().unwrap();
+        ctx.run_success(&[
+            "gdpr",
+            "consent-status",
+            "--output",
+            path_arg,
+            "--format",
+            "csv",
+        ]);
+
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("consent_type,granted,timestamp,policy_version")
+        );
+        let rows: Vec<&str> = lines.collect();
+        let count = |prefix: &str| rows.iter().filter(|r| r.starts_with(prefix)).count();
+        assert_eq!(count("DataProcessing,true,"), 1, "got: {}", csv);
+        assert_eq!(count("DataProcessing,false,"), 1, "got: {}", csv);
+        assert_eq!(count("ContactSharing,true,"), 1, "got: {}", csv);
+    }
+
+    /// `deletion-status --output` writes the state as JSON by default.
+    #[test]
+    fn test_gdpr_deletion_status_json_output() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let path = ctx.data_dir.path().join("deletion.json");
+        ctx.run_success(&[
+            "gdpr",
+            "deletion-status",
+            "--output",
+            path.to_str().unwrap(),
+        ]);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json[0]["state"], "none");
+    }
+
     /// Trace: privacy_compliance.feature - "Grant consent for data processing"
     #[test]
     fn test_gdpr_grant_and_revoke_consent() {
@@ -1064,6 +3063,41 @@ mod gdpr {
         assert!(parsed.is_object(), "Export should be a JSON object");
     }
 
+    /// Trace: privacy_compliance.feature - "Export personal data"
+    /// `--include-labels` adds the visibility labels under a `labels` key.
+    #[test]
+    fn test_gdpr_export_include_labels() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+        ctx.run_success(&["labels", "create", "Friends"]);
+
+        let export_path = ctx.data_dir.path().join("gdpr-export-labels.json");
+        let output = ctx.run_success(&[
+            "gdpr",
+            "export",
+            "--include-labels",
+            export_path.to_str().unwrap(),
+        ]);
+        assert!(
+            output.contains("labels: 1"),
+            "Summary should count exported labels, got: {}",
+            output
+        );
+
+        let contents = std::fs::read_to_string(&export_path).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        let labels = parsed
+            .get("labels")
+            .and_then(|v| v.as_array())
+            .expect("Export should contain a labels array");
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0]["name"], "Friends");
+        assert!(
+            parsed.get("devices").is_none(),
+            "Devices should only be exported when requested"
+        );
+    }
+
     /// Trace: privacy_compliance.feature - "Export encrypted personal data"
     #[test]
     fn test_gdpr_export_encrypted() {
@@ -1145,6 +3179,30 @@ mod gdpr {
             combined
         );
     }
+
+    /// Without a terminal, `schedule-deletion` fails right away instead of
+    /// waiting on its confirmation prompt.
+    #[test]
+    fn test_gdpr_schedule_deletion_requires_terminal() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let started = std::time::Instant::now();
+        let output = ctx.run_with_stdin(&["gdpr", "schedule-deletion"], "");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success());
+        assert!(
+            stderr.contains("requires an interactive terminal"),
+            "got: {}",
+            stderr
+        );
+        assert!(started.elapsed() < std::time::Duration::from_secs(30));
+        assert!(
+            ctx.run_success(&["gdpr", "deletion-status"])
+                .contains("No deletion")
+        );
+    }
 }
 
 // ===========================================================================
@@ -1170,6 +3228,19 @@ mod duress {
         );
     }
 
+    /// `--raw duress status` reports both protections as disabled by default.
+    #[test]
+    fn test_duress_status_raw() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let raw = ctx.run_success(&["--raw", "duress", "status"]);
+        let status: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw duress status must be valid JSON");
+        assert_eq!(status["password_enabled"], false);
+        assert_eq!(status["duress_enabled"], false);
+    }
+
     /// Trace: duress_mode.feature - "Disable when not enabled"
     // @scenario: duress_pin:Disable duress mode when not enabled
     #[test]
@@ -1224,6 +3295,18 @@ mod emergency {
         );
     }
 
+    /// `--raw emergency status` reports the not-configured default as JSON.
+    #[test]
+    fn test_emergency_status_raw() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice Smith");
+
+        let raw = ctx.run_success(&["--raw", "emergency", "status"]);
+        let status: serde_json::Value =
+            serde_json::from_str(&raw).expect("raw emergency status must be valid JSON");
+        assert_eq!(status["configured"], false);
+    }
+
     /// Trace: emergency_broadcast.feature - "Disable when not configured"
     // @scenario: emergency_broadcast:Disable emergency broadcast
     #[test]
@@ -1635,6 +3718,95 @@ mod export_vcard {
         );
     }
 
+    /// Tests that a QR sheet export with no contacts fails cleanly.
+    #[test]
+    fn test_export_qr_sheet_without_contacts() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice");
+
+        let sheet_path = ctx.data_dir.path().join("sheet.html");
+        let stderr = ctx.run_failure(&[
+            "contacts",
+            "export",
+            "--qr-sheet",
+            sheet_path.to_str().unwrap(),
+        ]);
+        assert!(
+            stderr.contains("No contacts to export"),
+            "Expected empty-selection message, got: {}",
+            stderr
+        );
+        assert!(!sheet_path.exists());
+    }
+
+    /// Importing contacts from a backup that is not a full backup fails
+    /// without touching the current identity.
+    #[test]
+    fn test_import_backup_rejects_invalid_file() {
+        let ctx = CliTestContext::new();
+        ctx.init("Alice");
+
+        let bogus = ctx.data_dir.path().join("bogus.backup");
+        std::fs::write(&bogus, "not a backup").unwrap();
+        let stderr = ctx.run_failure(&[
+            "contacts",
+            "import-backup",
+            bogus.to_str().unwrap(),
+            "--password",
+            "secret",
+        ]);
+        assert!(
+            stderr.contains("Failed to decrypt backup"),
+            "Expected decrypt failure, got: {}",
+            stderr
+        );
+        assert!(ctx.run_success(&["card", "show"]).contains("Alice"));
+    }
+
+    /// Contacts in a full backup can be imported into another identity,
+    /// and the scratch restore leaves nothing behind.
+    #[test]
+    fn test_import_backup_round_trips_contacts() {
+        let (alice, _bob) = exchanged_pair();
+        let backup = alice.data_dir.path().join("full.backup");
+        alice.run_success(&[
+            "export",
+            backup.to_str().unwrap(),
+            "--full",
+            "--password",
+            "secret",
+        ]);
+
+        let carol = CliTestContext::new();
+        carol.init("Carol White");
+        let output = carol.run_success(&[
+            "contacts",
+            "import-backup",
+            backup.to_str().unwrap(),
+            "--password",
+            "secret",
+        ]);
+        assert!(
+            output.contains("Imported 1 contacts from backup"),
+            "got: {}",
+            output
+        );
+        assert!(
+            carol
+                .run_success(&["contacts", "list"])
+                .contains("Bob Jones")
+        );
+        let leftover = std::fs::read_dir(carol.data_dir.path())
+            .unwrap()
+            .filter_map(Result::ok)
+            .any(|e| {
+                e.file_name()
+                    .to_string_lossy()
+                    .starts_with(".backup-import-")
+            });
+        assert!(!leftover, "scratch directory was not removed");
+    }
+
     /// Tests that the help includes export subcommand.
     #[test]
     fn test_export_in_help() {
@@ -1991,38 +4163,6 @@ mod contact_merge_duplicates_limit {
 mod contact_tags {
     use super::*;
 
-    fn exchange_data(output: &str) -> String {
-        output
-            .lines()
-            .map(str::trim)
-            .find(|line| {
-                line.len() >= 20
-                    && line
-                        .chars()
-                        .all(|c| c.is_alphanumeric() || matches!(c, '+' | '/' | '='))
-            })
-            .expect("exchange output contains QR data")
-            .to_string()
-    }
-
-    /// Two initialized contexts that have completed an exchange, so Alice
-    /// has Bob as a contact to tag.
-    fn exchanged_pair() -> (CliTestContext, CliTestContext) {
-        let alice = CliTestContext::new();
-        alice.init("Alice Smith");
-        alice.run_success(&["card", "add", "email", "Work", "alice@work.com"]);
-        let alice_data = exchange_data(&alice.run_success(&["exchange", "start"]));
-
-        let bob = CliTestContext::new();
-        bob.init("Bob Jones");
-        bob.run_success(&["card", "add", "phone", "Mobile", "+1-555-262-1234"]);
-        let bob_data = exchange_data(&bob.run_success(&["exchange", "start"]));
-
-        bob.run_success(&["exchange", "complete", &alice_data]);
-        alice.run_success(&["exchange", "complete", &bob_data]);
-        (alice, bob)
-    }
-
     /// Tests that tags create requires initialization.
     // @internal
     #[test]